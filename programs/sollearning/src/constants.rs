@@ -16,6 +16,27 @@ pub const PROPOSAL_SEED: &[u8] = b"proposal";
 pub const COURSE_HISTORY_SEED: &[u8] = b"course-history";
 pub const CONFIG_SEED: &[u8] = b"program-config";
 pub const EMERGENCY_MULTISIG_SEED: &[u8] = b"emergency-multisig";
+pub const OPERATOR_SEED: &[u8] = b"educator-operator";
+pub const EDUCATOR_KEY_ROTATION_SEED: &[u8] = b"educator-key-rotation";
+pub const EDUCATOR_APPLICATION_SEED: &[u8] = b"educator-application";
+pub const AUTHORITY_TRANSFER_SEED: &[u8] = b"authority-transfer";
+pub const VOTER_STAKE_SEED: &[u8] = b"voter-stake";
+pub const VOTE_VAULT_SEED: &[u8] = b"vote-vault";
+pub const VOTE_RECORD_SEED: &[u8] = b"vote-record";
+pub const COMMUNITY_PROPOSAL_SEED: &[u8] = b"community-proposal";
+pub const COMMUNITY_GOVERNANCE_SEED: &[u8] = b"community-governance";
+pub const PAUSE_RECORD_SEED: &[u8] = b"pause-record";
+pub const BLOCKLIST_SEED: &[u8] = b"blocklist";
+pub const DISPUTE_SEED: &[u8] = b"dispute";
+pub const COURSE_VERIFIERS_SEED: &[u8] = b"course-verifiers";
+pub const COMPLETION_VERIFICATION_SEED: &[u8] = b"completion-verification";
+pub const CO_EDUCATORS_SEED: &[u8] = b"course-co-educators";
+pub const EDUCATOR_REPUTATION_SEED: &[u8] = b"educator-reputation";
+pub const EDUCATOR_STUDENT_SEED: &[u8] = b"educator-student";
+pub const COURSE_RATING_SEED: &[u8] = b"course-rating";
+
+// Domain separator prefixed to signed completion attestations
+pub const ATTESTATION_DOMAIN: &[u8] = b"sollearning-completion-attestation";
 
 // Various constants for the program
 pub const MAX_MINT_AMOUNT: u64 = 1_000_000_000_000_000; // 1M tokens with 9 decimals
@@ -24,12 +45,36 @@ pub const MAX_COURSE_NAME_LENGTH: usize = 100;
 pub const MAX_EDUCATORS_LIMIT: u16 = 1000; // Maximum number of educators allowed
 pub const MAX_COURSES_PER_EDUCATOR: u16 = 100; // Maximum courses per educator
 pub const MAX_SIGNERS: usize = 10; // Maximum number of signers in multisig
+pub const MAX_DISPUTE_REVIEWERS: usize = 10; // Reviews a single dispute can record
+pub const MAX_COURSE_VERIFIERS: usize = 10; // Verifiers a course can list for k-of-n completions
+pub const MAX_CO_EDUCATORS: usize = 5; // Co-educators sharing a course besides its lead
+pub const MAX_CO_EDUCATOR_REWARD_PERCENT: u8 = 25; // Cap on the per-co-educator reward, as a percentage of the student reward
+pub const MIN_COURSE_RATING: u8 = 1;
+pub const MAX_COURSE_RATING: u8 = 5;
+pub const MAX_REPUTATION_TIERS: usize = 4; // Automatic mint_limit tiers governance can configure
+pub const MAX_MULTISIG_LABEL_LENGTH: usize = 32; // Multisig labels are used as a PDA seed
 pub const MAX_DESCRIPTION_LENGTH: usize = 200; // Maximum length for proposal descriptions
 pub const MAX_CHANGE_DESCRIPTION_LENGTH: usize = 200; // Maximum length for course change descriptions
-pub const PROPOSAL_EXPIRATION_TIME: i64 = 604800; // 7 days in seconds
+pub const PROPOSAL_EXPIRATION_TIME: i64 = 604800; // 7 days in seconds, default proposal expiry
+pub const MIN_PROPOSAL_EXPIRY: i64 = 86400; // 1 day in seconds, lowest expiry governance can allow
+pub const MAX_PROPOSAL_EXPIRY: i64 = 2592000; // 30 days in seconds, highest expiry governance can allow
+pub const MAX_BATCH_MINT_SIZE: usize = 10; // Maximum students per batch mint to stay within compute limits
+pub const BATCH_MINT_ACCOUNTS_PER_STUDENT: usize = 4; // StudentInfo, token account, CourseCompletion, blocklist entry
+pub const CO_EDUCATOR_MINT_ACCOUNTS: usize = 3; // EducatorAccount, token account, blocklist entry per co-educator
+pub const PROPOSAL_INSTRUCTION_KINDS: usize = 16; // Number of ProposalInstruction variants
+pub const MAX_CUSTOM_INSTRUCTION_ACCOUNTS: usize = 16; // Account metas stored in a Custom proposal
+pub const MAX_CUSTOM_INSTRUCTION_DATA: usize = 512; // Instruction data bytes stored in a Custom proposal
+pub const BPS_DENOMINATOR: u64 = 10_000; // Basis points in 100%
+pub const MAX_EDUCATOR_REWARD_BPS: u16 = 2_000; // Educator incentive is capped at 20% of the student reward
+pub const CIRCUIT_BREAKER_SUPPLY_FLOOR: u64 = 1_000_000_000_000; // 1,000 tokens, lowest supply the volume breaker measures against
 
 // Time-based constants
 pub const MINT_COOLDOWN_PERIOD: i64 = 7200; // 2 hours in seconds
+pub const EDUCATOR_KEY_ROTATION_DELAY: i64 = 172800; // 2 days in seconds
+pub const COMPLETION_DISPUTE_WINDOW: i64 = 604800; // 7 days in seconds
+pub const RATING_EDIT_WINDOW: i64 = 1209600; // 14 days in seconds after a rating is first submitted
+pub const MAX_PROPOSAL_DELAY: i64 = 259200; // 3 days in seconds, must stay below PROPOSAL_EXPIRATION_TIME
+pub const MAX_VOTING_PERIOD: i64 = 1209600; // 14 days in seconds
 
 // Function pause flags
 pub const PAUSE_FLAG_MINT: u32 = 1 << 0;
@@ -37,4 +82,33 @@ pub const PAUSE_FLAG_TRANSFER: u32 = 1 << 1;
 pub const PAUSE_FLAG_BURN: u32 = 1 << 2;
 pub const PAUSE_FLAG_REGISTER: u32 = 1 << 3;
 pub const PAUSE_FLAG_COURSE: u32 = 1 << 4;
-pub const PAUSE_FLAG_ALL: u32 = 0xFFFFFFFF;
\ No newline at end of file
+pub const PAUSE_FLAG_GOVERNANCE: u32 = 1 << 5;
+pub const PAUSE_FLAG_STUDENT_REGISTER: u32 = 1 << 6;
+pub const PAUSE_FLAG_COURSE_UPDATE: u32 = 1 << 7;
+pub const PAUSE_FLAG_RATING: u32 = 1 << 8;
+pub const PAUSE_FLAG_ALL: u32 = 0xFFFFFFFF;
+
+// Pause reason codes recorded in PauseRecord
+pub const PAUSE_REASON_UNSPECIFIED: u8 = 0;
+pub const PAUSE_REASON_SECURITY_INCIDENT: u8 = 1;
+pub const PAUSE_REASON_MAINTENANCE: u8 = 2;
+pub const PAUSE_REASON_UPGRADE: u8 = 3;
+pub const PAUSE_REASON_GOVERNANCE: u8 = 4;
+
+// Educator operator permission flags
+pub const OPERATOR_PERMISSION_CREATE_COURSE: u8 = 1 << 0;
+pub const OPERATOR_PERMISSION_UPDATE_COURSE: u8 = 1 << 1;
+pub const OPERATOR_PERMISSION_VERIFY_COMPLETION: u8 = 1 << 2;
+pub const OPERATOR_PERMISSION_ALL: u8 = OPERATOR_PERMISSION_CREATE_COURSE
+    | OPERATOR_PERMISSION_UPDATE_COURSE
+    | OPERATOR_PERMISSION_VERIFY_COMPLETION;
+
+// Governance scopes: which proposal instructions a multisig may create and execute
+pub const GOVERNANCE_SCOPE_EDUCATORS: u32 = 1 << 0; // RegisterEducator, UpdateEducatorStatus
+pub const GOVERNANCE_SCOPE_CONFIG: u32 = 1 << 1; // ChangeAuthority, TogglePause
+pub const GOVERNANCE_SCOPE_MEMBERSHIP: u32 = 1 << 2; // AddSigner, RemoveSigner, ChangeThreshold
+pub const GOVERNANCE_SCOPE_TREASURY: u32 = 1 << 3; // Custom
+pub const GOVERNANCE_SCOPE_ALL: u32 = GOVERNANCE_SCOPE_EDUCATORS
+    | GOVERNANCE_SCOPE_CONFIG
+    | GOVERNANCE_SCOPE_MEMBERSHIP
+    | GOVERNANCE_SCOPE_TREASURY;
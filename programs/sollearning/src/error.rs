@@ -127,4 +127,208 @@ pub enum SolLearningError {
     
     #[msg("Function is paused")]
     FunctionPaused,
-}
\ No newline at end of file
+
+    #[msg("Batch size must be between 1 and the maximum allowed students")]
+    InvalidBatchSize,
+
+    #[msg("Remaining accounts must be grouped as StudentInfo, token account and CourseCompletion")]
+    InvalidBatchAccounts,
+
+    #[msg("Token account does not belong to the student")]
+    InvalidTokenAccount,
+
+    #[msg("Course completion account does not match the expected address")]
+    InvalidCourseCompletion,
+
+    #[msg("Missing or malformed ed25519 signature verification instruction")]
+    InvalidSignatureInstruction,
+
+    #[msg("Attestation was not signed by the educator")]
+    InvalidAttestationSigner,
+
+    #[msg("Signed message does not match the attestation")]
+    AttestationMismatch,
+
+    #[msg("Attestation has expired")]
+    AttestationExpired,
+
+    #[msg("Invalid operator permissions")]
+    InvalidOperatorPermissions,
+
+    #[msg("Operator delegation has expired")]
+    OperatorExpired,
+
+    #[msg("Operator is not permitted to perform this action")]
+    OperatorPermissionDenied,
+
+    #[msg("Operator mint allowance exceeded")]
+    OperatorAllowanceExceeded,
+
+    #[msg("Invalid educator key rotation")]
+    InvalidKeyRotation,
+
+    #[msg("Key rotation timelock has not elapsed")]
+    KeyRotationTimelockActive,
+
+    #[msg("Educator application does not match the proposal")]
+    InvalidEducatorApplication,
+
+    #[msg("Missing or invalid accounts for proposal execution")]
+    InvalidProposalAccounts,
+
+    #[msg("Invalid authority transfer")]
+    InvalidAuthorityTransfer,
+
+    #[msg("Invalid proposal delay")]
+    InvalidProposalDelay,
+
+    #[msg("Proposal is still in its timelock")]
+    ProposalTimelockActive,
+
+    #[msg("Veto window has closed")]
+    VetoWindowClosed,
+
+    #[msg("Invalid custom instruction")]
+    InvalidCustomInstruction,
+
+    #[msg("Invalid multisig label")]
+    InvalidMultisigLabel,
+
+    #[msg("Proposal instruction is outside the multisig scope")]
+    ProposalOutOfScope,
+
+    #[msg("Invalid voting configuration")]
+    InvalidVotingConfig,
+
+    #[msg("Community voting is disabled for this instruction")]
+    CommunityVotingDisabled,
+
+    #[msg("Voter has no stake eligible for this proposal")]
+    NoVotingWeight,
+
+    #[msg("Voting period has ended")]
+    VotingClosed,
+
+    #[msg("Voting period has not ended")]
+    VotingStillOpen,
+
+    #[msg("Vote stake is locked by an open vote")]
+    VoteStakeLocked,
+
+    #[msg("Insufficient vote stake")]
+    InsufficientVoteStake,
+
+    #[msg("Multisig membership changed since this proposal was created")]
+    StaleProposal,
+
+    #[msg("Proposal has not expired yet")]
+    ProposalNotExpired,
+
+    #[msg("Invalid proposal expiry")]
+    InvalidProposalExpiry,
+
+    #[msg("Invalid proposal threshold")]
+    InvalidProposalThreshold,
+
+    #[msg("Invalid circuit breaker configuration")]
+    InvalidCircuitBreakerConfig,
+
+    #[msg("A pause reason is required when pausing")]
+    PauseReasonRequired,
+
+    #[msg("Invalid pause schedule")]
+    InvalidPauseSchedule,
+
+    #[msg("Pause is not due to end yet")]
+    PauseNotDue,
+
+    #[msg("Address is blocklisted")]
+    AddressBlocklisted,
+
+    #[msg("Thaw every frozen token account before unblocking")]
+    BlockedAccountsFrozen,
+
+    #[msg("Course completion has been revoked")]
+    CompletionRevoked,
+
+    #[msg("Revocation already requested for this completion")]
+    RevocationAlreadyRequested,
+
+    #[msg("No revocation has been requested for this completion")]
+    RevocationNotRequested,
+
+    #[msg("Completion dispute window is still open")]
+    DisputeWindowActive,
+
+    #[msg("Invalid dispute configuration")]
+    InvalidDisputeConfig,
+
+    #[msg("Dispute does not match this course, student or completion")]
+    InvalidDispute,
+
+    #[msg("Dispute is not open")]
+    DisputeNotOpen,
+
+    #[msg("Reviewer has already reviewed this dispute")]
+    DisputeAlreadyReviewed,
+
+    #[msg("Dispute has not been upheld")]
+    DisputeNotUpheld,
+
+    #[msg("Verifier set must be unique, within the maximum size and cover the threshold")]
+    InvalidVerifierSet,
+
+    #[msg("Signer is not a verifier for this course")]
+    NotCourseVerifier,
+
+    #[msg("Verifier has already signed this completion")]
+    CompletionAlreadyVerified,
+
+    #[msg("Course completions must be signed off by its verifiers")]
+    CourseRequiresVerification,
+
+    #[msg("Invalid completion verification record")]
+    InvalidVerificationRecord,
+
+    #[msg("Co-educators must be unique active educators with non-zero weights, within the maximum size and reward")]
+    InvalidCoEducators,
+
+    #[msg("Co-educator accounts must be passed in share order, as listed by the instruction")]
+    InvalidCoEducatorAccounts,
+
+    #[msg("Co-taught courses must be minted through mint_to_student, verify_completion or a dispute")]
+    CoTaughtCourseUnsupported,
+
+    #[msg("Course changes need the lead educator or a majority of co-educators")]
+    CoEducatorMajorityRequired,
+
+    #[msg("Educator reward rate exceeds the maximum or has no budget")]
+    InvalidEducatorRewardConfig,
+
+    #[msg("Educator token account is required while educator rewards are enabled")]
+    EducatorTokenAccountRequired,
+
+    #[msg("Reputation tiers must have valid mint limits in increasing order")]
+    InvalidReputationTiers,
+
+    #[msg("No reputation tiers are configured")]
+    ReputationTiersNotSet,
+
+    #[msg("Invalid educator reputation account")]
+    InvalidReputationAccount,
+
+    #[msg("Course rating must be between 1 and 5")]
+    InvalidRating,
+
+    #[msg("Course rating can no longer be edited")]
+    RatingEditWindowClosed,
+
+    #[msg("Student token account must delegate the reward to the program for clawbacks")]
+    ClawbackDelegateRequired,
+
+    #[msg("Dispute is still open or can still be settled")]
+    DisputeNotResolved,
+
+    #[msg("Verifier changes need the educator and a quorum of the course's current verifiers")]
+    VerifierQuorumRequired,
+}
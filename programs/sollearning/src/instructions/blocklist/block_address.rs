@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::states::blocklist::{AddressBlocked, BlockAppealed, AddressUnblocked};
+use crate::instructions::blocklist::block_address_struct::{BlockAddress, AppealBlock, UnblockAddress};
+use crate::utils::access::check_pauser;
+
+// Blocking is an emergency action, so it is open to the same signers that can
+// pause the program: the governance authority and the emergency multisig.
+pub fn block_address_handler(ctx: Context<BlockAddress>, reason_hash: [u8; 32]) -> Result<()> {
+    check_pauser(
+        &ctx.accounts.program_state,
+        ctx.accounts.emergency_multisig.as_deref(),
+        &ctx.accounts.authority,
+    )?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let address = ctx.accounts.address.key();
+
+    let entry = &mut ctx.accounts.blocklist_entry;
+    entry.address = address;
+    entry.reason_hash = reason_hash;
+    entry.blocked_by = ctx.accounts.authority.key();
+    entry.blocked_at = current_time;
+    entry.appeal_hash = None;
+    entry.appealed_at = None;
+    entry.frozen_accounts = 0;
+    entry.bump = ctx.bumps.blocklist_entry;
+
+    emit!(AddressBlocked {
+        address,
+        reason_hash,
+        blocked_by: ctx.accounts.authority.key(),
+        timestamp: current_time,
+    });
+
+    msg!("Address {} blocked by {}", address, ctx.accounts.authority.key());
+
+    Ok(())
+}
+
+// A blocked address can file (and later replace) an appeal for the pausers to
+// review; the appeal itself changes nothing until they unblock.
+pub fn appeal_block_handler(ctx: Context<AppealBlock>, appeal_hash: [u8; 32]) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let entry = &mut ctx.accounts.blocklist_entry;
+    entry.appeal_hash = Some(appeal_hash);
+    entry.appealed_at = Some(current_time);
+
+    emit!(BlockAppealed {
+        address: entry.address,
+        appeal_hash,
+        timestamp: current_time,
+    });
+
+    msg!("Block on {} appealed", entry.address);
+
+    Ok(())
+}
+
+pub fn unblock_address_handler(ctx: Context<UnblockAddress>) -> Result<()> {
+    check_pauser(
+        &ctx.accounts.program_state,
+        ctx.accounts.emergency_multisig.as_deref(),
+        &ctx.accounts.authority,
+    )?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let address = ctx.accounts.blocklist_entry.address;
+
+    emit!(AddressUnblocked {
+        address,
+        unblocked_by: ctx.accounts.authority.key(),
+        timestamp: current_time,
+    });
+
+    msg!("Address {} unblocked by {}", address, ctx.accounts.authority.key());
+
+    Ok(())
+}
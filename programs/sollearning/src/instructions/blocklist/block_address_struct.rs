@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::emergency::EmergencyMultisig;
+use crate::states::blocklist::BlocklistEntry;
+use crate::error::SolLearningError;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct BlockAddress<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(seeds = [EMERGENCY_MULTISIG_SEED], bump = emergency_multisig.bump)]
+    pub emergency_multisig: Option<Account<'info, EmergencyMultisig>>,
+
+    /// CHECK: any address can be blocked; it is only used as a seed
+    pub address: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<BlocklistEntry>(),
+        seeds = [BLOCKLIST_SEED, address.key().as_ref()],
+        bump,
+    )]
+    pub blocklist_entry: Account<'info, BlocklistEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AppealBlock<'info> {
+    pub appellant: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BLOCKLIST_SEED, appellant.key().as_ref()],
+        bump = blocklist_entry.bump,
+    )]
+    pub blocklist_entry: Account<'info, BlocklistEntry>,
+}
+
+#[derive(Accounts)]
+pub struct UnblockAddress<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(seeds = [EMERGENCY_MULTISIG_SEED], bump = emergency_multisig.bump)]
+    pub emergency_multisig: Option<Account<'info, EmergencyMultisig>>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [BLOCKLIST_SEED, blocklist_entry.address.as_ref()],
+        bump = blocklist_entry.bump,
+        constraint = blocklist_entry.frozen_accounts == 0 @ SolLearningError::BlockedAccountsFrozen,
+    )]
+    pub blocklist_entry: Account<'info, BlocklistEntry>,
+}
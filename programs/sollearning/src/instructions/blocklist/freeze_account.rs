@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, FreezeAccount, ThawAccount};
+use crate::states::blocklist::BlockedTokenAccountFrozen;
+use crate::error::SolLearningError;
+use crate::constants::*;
+use crate::instructions::blocklist::freeze_account_struct::SetBlockedAccountFrozen;
+use crate::utils::access::check_pauser;
+
+// Freezes or thaws a blocked address's token account through the mint's
+// freeze authority, which the ProgramState PDA holds.
+pub fn set_blocked_account_frozen_handler(ctx: Context<SetBlockedAccountFrozen>, frozen: bool) -> Result<()> {
+    check_pauser(
+        &ctx.accounts.program_state,
+        ctx.accounts.emergency_multisig.as_deref(),
+        &ctx.accounts.authority,
+    )?;
+
+    let program_state_bump = [ctx.accounts.program_state.bump];
+    let signer_seeds: &[&[u8]] = &[PROGRAM_STATE_SEED, &program_state_bump];
+    let signer = &[signer_seeds];
+
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let account = ctx.accounts.token_account.to_account_info();
+    let mint = ctx.accounts.token_mint.to_account_info();
+    let authority = ctx.accounts.program_state.to_account_info();
+
+    let entry = &mut ctx.accounts.blocklist_entry;
+    if frozen {
+        token::freeze_account(CpiContext::new_with_signer(
+            token_program,
+            FreezeAccount { account, mint, authority },
+            signer,
+        ))?;
+        entry.frozen_accounts = entry
+            .frozen_accounts
+            .checked_add(1)
+            .ok_or(SolLearningError::Overflow)?;
+    } else {
+        token::thaw_account(CpiContext::new_with_signer(
+            token_program,
+            ThawAccount { account, mint, authority },
+            signer,
+        ))?;
+        entry.frozen_accounts = entry.frozen_accounts.saturating_sub(1);
+    }
+
+    emit!(BlockedTokenAccountFrozen {
+        address: entry.address,
+        token_account: ctx.accounts.token_account.key(),
+        frozen,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Token account {} {}",
+        ctx.accounts.token_account.key(),
+        if frozen { "frozen" } else { "thawed" }
+    );
+
+    Ok(())
+}
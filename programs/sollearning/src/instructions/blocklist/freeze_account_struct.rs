@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::states::program::ProgramState;
+use crate::states::emergency::EmergencyMultisig;
+use crate::states::blocklist::BlocklistEntry;
+use crate::error::SolLearningError;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct SetBlockedAccountFrozen<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(seeds = [EMERGENCY_MULTISIG_SEED], bump = emergency_multisig.bump)]
+    pub emergency_multisig: Option<Account<'info, EmergencyMultisig>>,
+
+    #[account(
+        mut,
+        seeds = [BLOCKLIST_SEED, blocklist_entry.address.as_ref()],
+        bump = blocklist_entry.bump,
+    )]
+    pub blocklist_entry: Account<'info, BlocklistEntry>,
+
+    #[account(address = program_state.token_mint)]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = token_account.owner == blocklist_entry.address @ SolLearningError::InvalidTokenAccount,
+        constraint = token_account.mint == token_mint.key() @ SolLearningError::InvalidTokenAccount,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
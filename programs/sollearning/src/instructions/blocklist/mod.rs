@@ -0,0 +1,7 @@
+pub mod block_address;
+pub mod freeze_account;
+pub mod block_address_struct;
+pub mod freeze_account_struct;
+
+pub use block_address::{block_address_handler, appeal_block_handler, unblock_address_handler};
+pub use freeze_account::set_blocked_account_frozen_handler;
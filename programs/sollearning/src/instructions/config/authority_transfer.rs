@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::states::program::{AuthorityTransferProposed, AuthorityTransferCancelled, AuthorityTransferred};
+use crate::instructions::config::authority_transfer_struct::{
+    ProposeAuthorityTransfer, CancelAuthorityTransfer, AcceptAuthorityTransfer,
+};
+use crate::utils::access::check_admin;
+use crate::utils::authority::{validate_new_authority, initialize_authority_transfer};
+
+pub fn propose_authority_transfer_handler(
+    ctx: Context<ProposeAuthorityTransfer>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    check_admin(&ctx.accounts.program_state, &ctx.accounts.authority)?;
+    validate_new_authority(&ctx.accounts.program_state, new_authority)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let current_authority = ctx.accounts.authority.key();
+
+    initialize_authority_transfer(
+        &mut ctx.accounts.pending_transfer,
+        current_authority,
+        new_authority,
+        current_authority,
+        false,
+        current_time,
+        ctx.bumps.pending_transfer,
+    );
+
+    emit!(AuthorityTransferProposed {
+        current_authority,
+        new_authority,
+        proposed_by: current_authority,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Authority transfer from {} to {} proposed",
+        current_authority,
+        new_authority
+    );
+
+    Ok(())
+}
+
+pub fn cancel_authority_transfer_handler(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let pending_transfer = &ctx.accounts.pending_transfer;
+
+    emit!(AuthorityTransferCancelled {
+        current_authority: pending_transfer.current_authority,
+        new_authority: pending_transfer.new_authority,
+        cancelled_by: ctx.accounts.canceller.key(),
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Authority transfer to {} cancelled by {}",
+        pending_transfer.new_authority,
+        ctx.accounts.canceller.key()
+    );
+
+    Ok(())
+}
+
+// ProgramState and ProgramConfig are switched in the same instruction so the
+// two authority fields can never diverge through a handover.
+pub fn accept_authority_transfer_handler(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let previous_authority = ctx.accounts.program_state.authority;
+    let new_authority = ctx.accounts.new_authority.key();
+
+    ctx.accounts.program_state.authority = new_authority;
+    ctx.accounts.program_config.authority = new_authority;
+    ctx.accounts.program_config.last_updated_at = current_time;
+
+    emit!(AuthorityTransferred {
+        previous_authority,
+        new_authority,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Program authority transferred from {} to {}",
+        previous_authority,
+        new_authority
+    );
+
+    Ok(())
+}
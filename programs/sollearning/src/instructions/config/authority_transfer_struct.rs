@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::states::program::{ProgramState, PendingAuthorityTransfer};
+use crate::states::config::ProgramConfig;
+use crate::constants::*;
+use crate::error::SolLearningError;
+
+#[derive(Accounts)]
+pub struct ProposeAuthorityTransfer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<PendingAuthorityTransfer>(),
+        seeds = [AUTHORITY_TRANSFER_SEED],
+        bump,
+    )]
+    pub pending_transfer: Account<'info, PendingAuthorityTransfer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// The proposed key can always decline the handover. The current authority can
+// only withdraw its own proposals: a governance-initiated transfer is the
+// recovery path against that authority, and only another ChangeAuthority
+// proposal can replace it.
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    #[account(
+        constraint = canceller.key() == pending_transfer.new_authority
+            || (canceller.key() == program_state.authority && !pending_transfer.by_governance)
+            @ SolLearningError::Unauthorized
+    )]
+    pub canceller: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// CHECK: Receives the rent of the pending transfer, validated against `proposed_by`
+    #[account(mut, address = pending_transfer.proposed_by @ SolLearningError::Unauthorized)]
+    pub proposer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [AUTHORITY_TRANSFER_SEED],
+        bump = pending_transfer.bump,
+    )]
+    pub pending_transfer: Account<'info, PendingAuthorityTransfer>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthorityTransfer<'info> {
+    #[account(
+        constraint = new_authority.key() == pending_transfer.new_authority @ SolLearningError::Unauthorized
+    )]
+    pub new_authority: Signer<'info>,
+
+    #[account(mut, seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [CONFIG_SEED], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// CHECK: Receives the rent of the pending transfer, validated against `proposed_by`
+    #[account(mut, address = pending_transfer.proposed_by @ SolLearningError::Unauthorized)]
+    pub proposer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [AUTHORITY_TRANSFER_SEED],
+        bump = pending_transfer.bump,
+        constraint = pending_transfer.current_authority == program_state.authority @ SolLearningError::InvalidAuthorityTransfer,
+    )]
+    pub pending_transfer: Account<'info, PendingAuthorityTransfer>,
+}
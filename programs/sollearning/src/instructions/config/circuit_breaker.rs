@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::states::config::CircuitBreakersUpdated;
+use crate::constants::PAUSE_FLAG_GOVERNANCE;
+use crate::instructions::config::circuit_breaker_struct::UpdateCircuitBreakers;
+use crate::utils::access::check_config_admin;
+use crate::utils::pause::{check_program_running, check_function_running};
+use crate::utils::circuit_breaker::validate_circuit_breakers;
+
+// Zero disables a breaker. The volume breaker pauses minting when the tokens
+// minted within `breaker_window_slots` would exceed `breaker_supply_ratio_bps`
+// of circulating supply; the streak breaker pauses it after an educator mints
+// at least `breaker_near_cap_bps` of their mint limit `breaker_near_cap_streak`
+// times in a row.
+pub fn set_circuit_breakers_handler(
+    ctx: Context<UpdateCircuitBreakers>,
+    breaker_window_slots: u64,
+    breaker_supply_ratio_bps: u16,
+    breaker_near_cap_bps: u16,
+    breaker_near_cap_streak: u8,
+) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_GOVERNANCE)?;
+    check_config_admin(&ctx.accounts.program_config, &ctx.accounts.authority)?;
+    validate_circuit_breakers(
+        breaker_window_slots,
+        breaker_supply_ratio_bps,
+        breaker_near_cap_bps,
+        breaker_near_cap_streak,
+    )?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.program_config;
+    config.breaker_window_slots = breaker_window_slots;
+    config.breaker_supply_ratio_bps = breaker_supply_ratio_bps;
+    config.breaker_near_cap_bps = breaker_near_cap_bps;
+    config.breaker_near_cap_streak = breaker_near_cap_streak;
+    config.last_updated_at = current_time;
+
+    emit!(CircuitBreakersUpdated {
+        breaker_window_slots,
+        breaker_supply_ratio_bps,
+        breaker_near_cap_bps,
+        breaker_near_cap_streak,
+        authority: ctx.accounts.authority.key(),
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Circuit breakers set: {} bps of supply per {} slots, {} mints at {} bps of the limit",
+        breaker_supply_ratio_bps,
+        breaker_window_slots,
+        breaker_near_cap_streak,
+        breaker_near_cap_bps
+    );
+
+    Ok(())
+}
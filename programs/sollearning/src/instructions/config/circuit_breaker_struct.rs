@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::config::ProgramConfig;
+use crate::constants::{CONFIG_SEED, PROGRAM_STATE_SEED};
+
+#[derive(Accounts)]
+pub struct UpdateCircuitBreakers<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [CONFIG_SEED], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::states::config::DisputeConfigUpdated;
+use crate::error::SolLearningError;
+use crate::constants::{MAX_DISPUTE_REVIEWERS, PAUSE_FLAG_GOVERNANCE};
+use crate::instructions::config::dispute_config_struct::UpdateDisputeConfig;
+use crate::utils::access::check_config_admin;
+use crate::utils::pause::{check_program_running, check_function_running};
+
+// The default key leaves disputes to multisig panels alone. A quorum of zero
+// lets a single review resolve a dispute.
+pub fn set_dispute_config_handler(
+    ctx: Context<UpdateDisputeConfig>,
+    dispute_reviewer: Pubkey,
+    dispute_quorum: u8,
+) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_GOVERNANCE)?;
+    check_config_admin(&ctx.accounts.program_config, &ctx.accounts.authority)?;
+    require!(
+        dispute_quorum as usize <= MAX_DISPUTE_REVIEWERS,
+        SolLearningError::InvalidDisputeConfig
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.program_config;
+    config.dispute_reviewer = dispute_reviewer;
+    config.dispute_quorum = dispute_quorum;
+    config.last_updated_at = current_time;
+
+    emit!(DisputeConfigUpdated {
+        dispute_reviewer,
+        dispute_quorum,
+        authority: ctx.accounts.authority.key(),
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Dispute reviewer set to {} with a quorum of {}",
+        dispute_reviewer,
+        dispute_quorum
+    );
+
+    Ok(())
+}
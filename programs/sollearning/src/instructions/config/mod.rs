@@ -1,7 +1,21 @@
 pub mod create_program_config;
 pub mod update_program_config;
+pub mod authority_transfer;
+pub mod voting_config;
+pub mod circuit_breaker;
+pub mod dispute_config;
 pub mod create_program_config_struct;
 pub mod update_program_config_struct;
+pub mod authority_transfer_struct;
+pub mod voting_config_struct;
+pub mod circuit_breaker_struct;
+pub mod dispute_config_struct;
 
 pub use create_program_config::create_program_config_handler;
 pub use update_program_config::update_program_config_handler;
+pub use authority_transfer::{
+    propose_authority_transfer_handler, cancel_authority_transfer_handler, accept_authority_transfer_handler,
+};
+pub use voting_config::set_voting_config_handler;
+pub use circuit_breaker::set_circuit_breakers_handler;
+pub use dispute_config::set_dispute_config_handler;
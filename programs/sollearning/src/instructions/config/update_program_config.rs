@@ -3,7 +3,7 @@ use crate::states::config::ConfigUpdated;
 use crate::error::SolLearningError;
 use crate::instructions::config::update_program_config_struct::UpdateProgramConfig;
 use crate::utils::pause::check_program_running;
-use crate::utils::config::check_authority;
+use crate::utils::access::check_config_admin;
 
 pub fn update_program_config_handler(
     ctx: Context<UpdateProgramConfig>,
@@ -13,7 +13,7 @@ pub fn update_program_config_handler(
     mint_cooldown_period: Option<i64>,
 ) -> Result<()> {
     check_program_running(&ctx.accounts.program_state)?;
-    check_authority(&ctx.accounts.program_config, &ctx.accounts.authority)?;
+    check_config_admin(&ctx.accounts.program_config, &ctx.accounts.authority)?;
 
     let current_time = Clock::get()?.unix_timestamp;
     let mut updated = false;
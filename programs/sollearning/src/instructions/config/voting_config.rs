@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::states::config::VotingConfigUpdated;
+use crate::constants::PAUSE_FLAG_GOVERNANCE;
+use crate::instructions::config::voting_config_struct::UpdateVotingConfig;
+use crate::utils::access::check_config_admin;
+use crate::utils::pause::{check_program_running, check_function_running};
+use crate::utils::voting::validate_voting_config;
+
+// Settings apply to community proposals created after the change; open
+// proposals keep the voting window they were created with but are tallied
+// against the current quorum and threshold.
+pub fn set_voting_config_handler(
+    ctx: Context<UpdateVotingConfig>,
+    vote_quorum: u64,
+    vote_threshold_bps: u16,
+    voting_period: i64,
+    community_scope: u32,
+) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_GOVERNANCE)?;
+    check_config_admin(&ctx.accounts.program_config, &ctx.accounts.authority)?;
+    validate_voting_config(vote_quorum, vote_threshold_bps, voting_period, community_scope)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.program_config;
+    config.vote_quorum = vote_quorum;
+    config.vote_threshold_bps = vote_threshold_bps;
+    config.voting_period = voting_period;
+    config.community_scope = community_scope;
+    config.last_updated_at = current_time;
+
+    emit!(VotingConfigUpdated {
+        vote_quorum,
+        vote_threshold_bps,
+        voting_period,
+        community_scope,
+        authority: ctx.accounts.authority.key(),
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Voting config set: quorum {}, threshold {} bps, period {}s, scope {:#x}",
+        vote_quorum,
+        vote_threshold_bps,
+        voting_period,
+        community_scope
+    );
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use crate::states::course::{Course, CourseCoEducators, CourseCoEducatorsUpdated, CoEducatorShare};
+use crate::error::SolLearningError;
+use crate::constants::*;
+use crate::instructions::course::co_educators_struct::{CreateCourseCoEducators, UpdateCourseCoEducators};
+use crate::utils::access::{check_educator_or_operator, check_course_editor};
+use crate::utils::pause::{check_program_running, check_function_running};
+
+pub fn create_course_co_educators_handler(
+    ctx: Context<CreateCourseCoEducators>,
+    lead_weight: u16,
+    co_educators: Vec<CoEducatorShare>,
+    reward_percent: u8,
+) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_COURSE_UPDATE)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    check_educator_or_operator(
+        &ctx.accounts.educator,
+        &ctx.accounts.educator_authority,
+        &ctx.accounts.operator,
+        OPERATOR_PERMISSION_UPDATE_COURSE,
+        current_time,
+    )?;
+
+    let course_co_educators = &mut ctx.accounts.co_educators;
+    course_co_educators.course = ctx.accounts.course.key();
+    course_co_educators.bump = ctx.bumps.co_educators;
+
+    apply_co_educators(
+        &mut ctx.accounts.course,
+        course_co_educators,
+        lead_weight,
+        co_educators,
+        reward_percent,
+        ctx.accounts.educator_authority.key(),
+        current_time,
+    )
+}
+
+// An empty list makes the course single-educator again
+pub fn update_course_co_educators_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateCourseCoEducators<'info>>,
+    lead_weight: u16,
+    co_educators: Vec<CoEducatorShare>,
+    reward_percent: u8,
+) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_COURSE_UPDATE)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    check_course_editor(
+        &ctx.accounts.educator,
+        &ctx.accounts.educator_authority,
+        &ctx.accounts.operator,
+        Some(&ctx.accounts.co_educators),
+        ctx.remaining_accounts,
+        current_time,
+    )?;
+
+    apply_co_educators(
+        &mut ctx.accounts.course,
+        &mut ctx.accounts.co_educators,
+        lead_weight,
+        co_educators,
+        reward_percent,
+        ctx.accounts.educator_authority.key(),
+        current_time,
+    )
+}
+
+fn validate_co_educators(course: &Course, lead_weight: u16, co_educators: &[CoEducatorShare], reward_percent: u8) -> Result<()> {
+    require!(
+        lead_weight > 0
+            && co_educators.len() <= MAX_CO_EDUCATORS
+            && reward_percent <= MAX_CO_EDUCATOR_REWARD_PERCENT,
+        SolLearningError::InvalidCoEducators
+    );
+
+    for (index, share) in co_educators.iter().enumerate() {
+        let is_duplicate = co_educators[index + 1..].iter().any(|other| other.educator == share.educator);
+        require!(
+            share.weight > 0 && share.educator != course.educator && !is_duplicate,
+            SolLearningError::InvalidCoEducators
+        );
+    }
+    Ok(())
+}
+
+fn apply_co_educators(
+    course: &mut Account<Course>,
+    course_co_educators: &mut Account<CourseCoEducators>,
+    lead_weight: u16,
+    co_educators: Vec<CoEducatorShare>,
+    reward_percent: u8,
+    updated_by: Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    validate_co_educators(course, lead_weight, &co_educators, reward_percent)?;
+
+    course_co_educators.lead_weight = lead_weight;
+    course_co_educators.co_educators = co_educators.clone();
+    course_co_educators.reward_percent = reward_percent;
+    course_co_educators.updated_at = current_time;
+
+    course.co_educator_count = co_educators.len() as u8;
+    course.last_updated_at = current_time;
+
+    emit!(CourseCoEducatorsUpdated {
+        course: course.key(),
+        lead_weight,
+        co_educators,
+        reward_percent,
+        updated_by,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Course {} is now co-taught by {} co-educators",
+        course.course_id,
+        course.co_educator_count
+    );
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::educator::{EducatorAccount, EducatorOperator};
+use crate::states::course::{Course, CourseCoEducators};
+use crate::constants::*;
+use crate::error::SolLearningError;
+
+#[derive(Accounts)]
+pub struct CreateCourseCoEducators<'info> {
+    pub educator: Account<'info, EducatorAccount>,
+
+    #[account(mut)]
+    pub educator_authority: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        constraint = course.educator == educator.key() @ SolLearningError::CourseNotOwnedByEducator,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        init,
+        payer = educator_authority,
+        space = calculate_co_educators_space(),
+        seeds = [CO_EDUCATORS_SEED, course.key().as_ref()],
+        bump,
+    )]
+    pub co_educators: Account<'info, CourseCoEducators>,
+
+    #[account(
+        seeds = [OPERATOR_SEED, educator.key().as_ref(), educator_authority.key().as_ref()],
+        bump = operator.bump,
+    )]
+    pub operator: Option<Account<'info, EducatorOperator>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Signed by the lead educator or its operator, or by a majority of
+// co-educators passed through the remaining accounts.
+#[derive(Accounts)]
+pub struct UpdateCourseCoEducators<'info> {
+    pub educator: Account<'info, EducatorAccount>,
+
+    pub educator_authority: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        constraint = course.educator == educator.key() @ SolLearningError::CourseNotOwnedByEducator,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        mut,
+        seeds = [CO_EDUCATORS_SEED, course.key().as_ref()],
+        bump = co_educators.bump,
+    )]
+    pub co_educators: Account<'info, CourseCoEducators>,
+
+    #[account(
+        seeds = [OPERATOR_SEED, educator.key().as_ref(), educator_authority.key().as_ref()],
+        bump = operator.bump,
+    )]
+    pub operator: Option<Account<'info, EducatorOperator>>,
+}
+
+pub fn calculate_co_educators_space() -> usize {
+    8 + 32 + 2 + 4 + MAX_CO_EDUCATORS * (32 + 2) + 1 + 8 + 1
+}
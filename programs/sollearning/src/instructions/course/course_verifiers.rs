@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::states::course::{Course, CourseVerifiers, CourseVerifiersUpdated};
+use crate::states::educator::EducatorAccount;
+use crate::states::program::ProgramState;
+use crate::error::SolLearningError;
+use crate::constants::*;
+use crate::instructions::course::course_verifiers_struct::{CreateCourseVerifiers, UpdateCourseVerifiers};
+use crate::utils::access::{check_educator, check_verifier_quorum};
+use crate::utils::pause::{check_program_running, check_function_running};
+
+pub fn create_course_verifiers_handler(
+    ctx: Context<CreateCourseVerifiers>,
+    verifiers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    require!(threshold > 0, SolLearningError::InvalidVerifierSet);
+    let current_time = authorize_verifier_change(
+        &ctx.accounts.program_state,
+        &ctx.accounts.educator,
+        &ctx.accounts.educator_authority,
+    )?;
+
+    let course_verifiers = &mut ctx.accounts.course_verifiers;
+    course_verifiers.course = ctx.accounts.course.key();
+    course_verifiers.bump = ctx.bumps.course_verifiers;
+
+    apply_verifier_set(
+        &mut ctx.accounts.course,
+        course_verifiers,
+        verifiers,
+        threshold,
+        ctx.accounts.educator_authority.key(),
+        current_time,
+    )
+}
+
+// A threshold of zero hands completions back to the educator's own mint flow.
+// Any change needs the educator and a quorum of the current verifiers (passed
+// as signers in the remaining accounts), so the educator cannot swap them out
+// or drop the threshold alone.
+pub fn update_course_verifiers_handler(
+    ctx: Context<UpdateCourseVerifiers>,
+    verifiers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let current_time = authorize_verifier_change(
+        &ctx.accounts.program_state,
+        &ctx.accounts.educator,
+        &ctx.accounts.educator_authority,
+    )?;
+    check_verifier_quorum(&ctx.accounts.course_verifiers, ctx.remaining_accounts)?;
+
+    apply_verifier_set(
+        &mut ctx.accounts.course,
+        &mut ctx.accounts.course_verifiers,
+        verifiers,
+        threshold,
+        ctx.accounts.educator_authority.key(),
+        current_time,
+    )
+}
+
+// Operators cannot change verifiers, only the educator itself
+fn authorize_verifier_change(
+    program_state: &Account<ProgramState>,
+    educator: &Account<EducatorAccount>,
+    educator_authority: &Signer,
+) -> Result<i64> {
+    check_program_running(program_state)?;
+    check_function_running(program_state, PAUSE_FLAG_COURSE_UPDATE)?;
+    check_educator(educator, educator_authority)?;
+    Ok(Clock::get()?.unix_timestamp)
+}
+
+fn validate_verifier_set(verifiers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        verifiers.len() <= MAX_COURSE_VERIFIERS && threshold as usize <= verifiers.len(),
+        SolLearningError::InvalidVerifierSet
+    );
+
+    for (index, verifier) in verifiers.iter().enumerate() {
+        require!(
+            *verifier != Pubkey::default() && !verifiers[index + 1..].contains(verifier),
+            SolLearningError::InvalidVerifierSet
+        );
+    }
+    Ok(())
+}
+
+fn apply_verifier_set(
+    course: &mut Account<Course>,
+    course_verifiers: &mut Account<CourseVerifiers>,
+    verifiers: Vec<Pubkey>,
+    threshold: u8,
+    updated_by: Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    validate_verifier_set(&verifiers, threshold)?;
+
+    course_verifiers.verifiers = verifiers.clone();
+    course_verifiers.threshold = threshold;
+    course_verifiers.updated_at = current_time;
+
+    course.verifier_threshold = threshold;
+    course.last_updated_at = current_time;
+
+    emit!(CourseVerifiersUpdated {
+        course: course.key(),
+        verifiers,
+        threshold,
+        updated_by,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Course {} now requires {} of {} verifiers",
+        course.course_id,
+        threshold,
+        course_verifiers.verifiers.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::educator::EducatorAccount;
+use crate::states::course::{Course, CourseVerifiers};
+use crate::constants::*;
+use crate::error::SolLearningError;
+
+#[derive(Accounts)]
+pub struct CreateCourseVerifiers<'info> {
+    pub educator: Account<'info, EducatorAccount>,
+
+    #[account(mut)]
+    pub educator_authority: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        constraint = course.educator == educator.key() @ SolLearningError::CourseNotOwnedByEducator,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        init,
+        payer = educator_authority,
+        space = calculate_course_verifiers_space(),
+        seeds = [COURSE_VERIFIERS_SEED, course.key().as_ref()],
+        bump,
+    )]
+    pub course_verifiers: Account<'info, CourseVerifiers>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// The current verifiers co-signing the change are passed as remaining accounts
+#[derive(Accounts)]
+pub struct UpdateCourseVerifiers<'info> {
+    pub educator: Account<'info, EducatorAccount>,
+
+    pub educator_authority: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        constraint = course.educator == educator.key() @ SolLearningError::CourseNotOwnedByEducator,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        mut,
+        seeds = [COURSE_VERIFIERS_SEED, course.key().as_ref()],
+        bump = course_verifiers.bump,
+    )]
+    pub course_verifiers: Account<'info, CourseVerifiers>,
+}
+
+pub fn calculate_course_verifiers_space() -> usize {
+    8 + 32 + 4 + MAX_COURSE_VERIFIERS * 32 + 1 + 8 + 1
+}
@@ -5,6 +5,7 @@ use crate::constants::*;
 use crate::error::SolLearningError;
 use crate::instructions::course::create_course_struct::CreateCourse;
 use crate::utils::pause::{check_program_running, check_function_running};
+use crate::utils::access::check_educator_or_operator;
 
 pub fn create_course_handler(
     ctx: Context<CreateCourse>,
@@ -16,10 +17,18 @@ pub fn create_course_handler(
     check_program_running(&ctx.accounts.program_state)?;
     check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_COURSE)?;
 
-    validate_course_details(&course_id, &course_name, reward_amount, &ctx.accounts.educator)?;
-
     let current_time = Clock::get()?.unix_timestamp;
 
+    check_educator_or_operator(
+        &ctx.accounts.educator,
+        &ctx.accounts.educator_authority,
+        &ctx.accounts.operator,
+        OPERATOR_PERMISSION_CREATE_COURSE,
+        current_time,
+    )?;
+
+    validate_course_details(&course_id, &course_name, reward_amount, &ctx.accounts.educator)?;
+
     initialize_course(
         &mut ctx.accounts.course,
         &course_id,
@@ -64,6 +73,7 @@ fn validate_course_details(course_id: &str, course_name: &str, reward_amount: u6
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn initialize_course(
     course: &mut Account<Course>,
     course_id: &str,
@@ -83,6 +93,10 @@ fn initialize_course(
     course.metadata_hash = metadata_hash;
     course.created_at = current_time;
     course.last_updated_at = current_time;
+    course.verifier_threshold = 0;
+    course.co_educator_count = 0;
+    course.rating_count = 0;
+    course.rating_sum = 0;
     course.bump = bump;
     Ok(())
 }
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::states::program::ProgramState;
-use crate::states::educator::EducatorAccount;
+use crate::states::educator::{EducatorAccount, EducatorOperator};
 use crate::states::course::Course;
 use crate::constants::*;
 
@@ -25,9 +25,15 @@ pub struct CreateCourse<'info> {
     )]
     pub course: Account<'info, Course>,
 
+    #[account(
+        seeds = [OPERATOR_SEED, educator.key().as_ref(), educator_authority.key().as_ref()],
+        bump = operator.bump,
+    )]
+    pub operator: Option<Account<'info, EducatorOperator>>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn calculate_course_space(course_id: &str, course_name: &str) -> usize {
-    8 + 4 + course_id.len() + 4 + course_name.len() + 32 + 8 + 4 + 1 + 32 + 8 + 8 + 1
+    8 + 4 + course_id.len() + 4 + course_name.len() + 32 + 8 + 4 + 1 + 32 + 8 + 8 + 4 + 1 + 1 + 4 + 8 + 1
 }
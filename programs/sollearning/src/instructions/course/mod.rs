@@ -1,7 +1,24 @@
 pub mod create_course;
 pub mod update_course;
+pub mod revoke_completion;
+pub mod course_verifiers;
+pub mod verify_completion;
+pub mod co_educators;
+pub mod rate_course;
 pub mod create_course_struct;
 pub mod update_course_struct;
+pub mod revoke_completion_struct;
+pub mod course_verifiers_struct;
+pub mod verify_completion_struct;
+pub mod co_educators_struct;
+pub mod rate_course_struct;
 
 pub use create_course::create_course_handler;
 pub use update_course::update_course_handler;
+pub use revoke_completion::{
+    request_completion_revocation_handler, cancel_completion_revocation_handler, revoke_completion_handler,
+};
+pub use course_verifiers::{create_course_verifiers_handler, update_course_verifiers_handler};
+pub use verify_completion::verify_completion_handler;
+pub use co_educators::{create_course_co_educators_handler, update_course_co_educators_handler};
+pub use rate_course::{rate_course_handler, update_course_rating_handler};
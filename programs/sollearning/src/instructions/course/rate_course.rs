@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use crate::states::course::{Course, CourseRated, CourseRatingUpdated};
+use crate::error::SolLearningError;
+use crate::constants::*;
+use crate::instructions::course::rate_course_struct::{RateCourse, UpdateCourseRating};
+use crate::utils::pause::{check_program_running, check_function_running};
+use crate::utils::reputation::{load_or_create_reputation, record_rating, store_reputation};
+
+// Only students holding an unrevoked completion of the course can rate it,
+// once; the rating can then be edited for RATING_EDIT_WINDOW.
+pub fn rate_course_handler(ctx: Context<RateCourse>, rating: u8, review_hash: [u8; 32]) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_RATING)?;
+    validate_rating(rating)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let course_key = ctx.accounts.course.key();
+    let student_key = ctx.accounts.student.key();
+
+    let course_rating = &mut ctx.accounts.course_rating;
+    course_rating.course = course_key;
+    course_rating.student = student_key;
+    course_rating.rating = rating;
+    course_rating.review_hash = review_hash;
+    course_rating.created_at = current_time;
+    course_rating.updated_at = current_time;
+    course_rating.bump = ctx.bumps.course_rating;
+
+    apply_course_rating(&mut ctx.accounts.course, None, rating)?;
+
+    let reputation_account = ctx.accounts.educator_reputation.to_account_info();
+    let mut reputation = load_or_create_reputation(
+        &reputation_account,
+        &ctx.accounts.student.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.course.educator,
+        ctx.bumps.educator_reputation,
+        ctx.program_id,
+    )?;
+    record_rating(&mut reputation, None, rating)?;
+    store_reputation(&reputation_account, &mut reputation, current_time)?;
+
+    emit!(CourseRated {
+        course: course_key,
+        student: student_key,
+        rating,
+        review_hash,
+        timestamp: current_time,
+    });
+
+    msg!("Course {} rated {} by {}", ctx.accounts.course.course_id, rating, student_key);
+
+    Ok(())
+}
+
+pub fn update_course_rating_handler(ctx: Context<UpdateCourseRating>, rating: u8, review_hash: [u8; 32]) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_RATING)?;
+    validate_rating(rating)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let course_rating = &mut ctx.accounts.course_rating;
+    let editable_until = course_rating
+        .created_at
+        .checked_add(RATING_EDIT_WINDOW)
+        .ok_or(SolLearningError::Overflow)?;
+    require!(current_time <= editable_until, SolLearningError::RatingEditWindowClosed);
+
+    let previous_rating = course_rating.rating;
+    course_rating.rating = rating;
+    course_rating.review_hash = review_hash;
+    course_rating.updated_at = current_time;
+
+    apply_course_rating(&mut ctx.accounts.course, Some(previous_rating), rating)?;
+
+    let reputation_account = ctx.accounts.educator_reputation.to_account_info();
+    let mut reputation = load_or_create_reputation(
+        &reputation_account,
+        &ctx.accounts.student.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.course.educator,
+        ctx.bumps.educator_reputation,
+        ctx.program_id,
+    )?;
+    record_rating(&mut reputation, Some(previous_rating), rating)?;
+    store_reputation(&reputation_account, &mut reputation, current_time)?;
+
+    emit!(CourseRatingUpdated {
+        course: ctx.accounts.course.key(),
+        student: ctx.accounts.student.key(),
+        previous_rating,
+        rating,
+        review_hash,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Course {} rating changed from {} to {}",
+        ctx.accounts.course.course_id,
+        previous_rating,
+        rating
+    );
+
+    Ok(())
+}
+
+fn validate_rating(rating: u8) -> Result<()> {
+    require!(
+        (MIN_COURSE_RATING..=MAX_COURSE_RATING).contains(&rating),
+        SolLearningError::InvalidRating
+    );
+    Ok(())
+}
+
+fn apply_course_rating(course: &mut Course, previous: Option<u8>, rating: u8) -> Result<()> {
+    match previous {
+        Some(previous) => {
+            course.rating_sum = course.rating_sum.checked_sub(previous as u64).ok_or(SolLearningError::Overflow)?;
+        }
+        None => {
+            course.rating_count = course.rating_count.checked_add(1).ok_or(SolLearningError::Overflow)?;
+        }
+    }
+    course.rating_sum = course.rating_sum.checked_add(rating as u64).ok_or(SolLearningError::Overflow)?;
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::course::{Course, CourseCompletion, CourseRating};
+use crate::error::SolLearningError;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct RateCourse<'info> {
+    #[account(mut)]
+    pub student: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut)]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        seeds = [COURSE_COMPLETION_SEED, student.key().as_ref(), course.course_id.as_bytes()],
+        bump = course_completion.bump,
+        constraint = course_completion.verified_by == course.educator @ SolLearningError::InvalidCourseCompletion,
+        constraint = course_completion.revoked_at.is_none() @ SolLearningError::CompletionRevoked,
+    )]
+    pub course_completion: Account<'info, CourseCompletion>,
+
+    #[account(
+        init,
+        payer = student,
+        space = 8 + std::mem::size_of::<CourseRating>(),
+        seeds = [COURSE_RATING_SEED, course.key().as_ref(), student.key().as_ref()],
+        bump,
+    )]
+    pub course_rating: Account<'info, CourseRating>,
+
+    /// CHECK: EducatorReputation PDA of the course's educator, created on first use
+    #[account(mut, seeds = [EDUCATOR_REPUTATION_SEED, course.educator.as_ref()], bump)]
+    pub educator_reputation: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCourseRating<'info> {
+    #[account(mut)]
+    pub student: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut)]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        mut,
+        seeds = [COURSE_RATING_SEED, course.key().as_ref(), student.key().as_ref()],
+        bump = course_rating.bump,
+    )]
+    pub course_rating: Account<'info, CourseRating>,
+
+    /// CHECK: EducatorReputation PDA of the course's educator, created on first use
+    #[account(mut, seeds = [EDUCATOR_REPUTATION_SEED, course.educator.as_ref()], bump)]
+    pub educator_reputation: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
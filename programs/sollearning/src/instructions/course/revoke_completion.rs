@@ -0,0 +1,311 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount};
+use crate::states::course::{
+    CourseCompletion, CompletionRevocationRequested, CompletionRevocationCancelled, CompletionRevoked,
+};
+use crate::states::dispute::{Dispute, DisputeKind, DisputeStatus, DisputeSettled};
+use crate::states::student::StudentInfo;
+use crate::states::educator::EducatorAccount;
+use crate::error::SolLearningError;
+use crate::constants::*;
+use crate::instructions::course::revoke_completion_struct::{CompletionRevocationRequest, RevokeCompletion};
+use crate::utils::access::check_scoped_governance;
+
+const REVOCATION_SCOPE: u32 = GOVERNANCE_SCOPE_CONFIG | GOVERNANCE_SCOPE_EDUCATORS;
+
+// Revocation is a governed recovery action, so it runs while the program is
+// paused. Requests, cancellations and revocations without an upheld dispute
+// are signed by a member of the root multisig or of a committee holding the
+// config or educators scope. A request opens the dispute window; only after it
+// closes can the completion be revoked.
+pub fn request_completion_revocation_handler(
+    ctx: Context<CompletionRevocationRequest>,
+    reason_hash: [u8; 32],
+) -> Result<()> {
+    check_scoped_governance(&ctx.accounts.multisig, REVOCATION_SCOPE, &ctx.accounts.authority)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let course_completion = &mut ctx.accounts.course_completion;
+    require!(
+        course_completion.revocation_requested_at.is_none(),
+        SolLearningError::RevocationAlreadyRequested
+    );
+
+    course_completion.revocation_requested_at = Some(current_time);
+    course_completion.revocation_reason_hash = reason_hash;
+
+    emit!(CompletionRevocationRequested {
+        student: course_completion.student,
+        course_id: course_completion.course_id.clone(),
+        reason_hash,
+        requested_by: ctx.accounts.authority.key(),
+        revocable_at: revocable_at(current_time)?,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Revocation requested for {} completion of {}",
+        course_completion.student,
+        course_completion.course_id
+    );
+
+    Ok(())
+}
+
+pub fn cancel_completion_revocation_handler(ctx: Context<CompletionRevocationRequest>) -> Result<()> {
+    check_scoped_governance(&ctx.accounts.multisig, REVOCATION_SCOPE, &ctx.accounts.authority)?;
+
+    let course_completion = &mut ctx.accounts.course_completion;
+    require!(
+        course_completion.revocation_requested_at.is_some(),
+        SolLearningError::RevocationNotRequested
+    );
+
+    course_completion.revocation_requested_at = None;
+    course_completion.revocation_reason_hash = [0; 32];
+
+    emit!(CompletionRevocationCancelled {
+        student: course_completion.student,
+        course_id: course_completion.course_id.clone(),
+        cancelled_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Revocation cancelled for {} completion of {}",
+        course_completion.student,
+        course_completion.course_id
+    );
+
+    Ok(())
+}
+
+// Reverses the award exactly as the completion recorded it, then burns as much
+// of it as the program can reach through the clawback delegate the student
+// approved at enrollment. Co-taught completions also pass one [EducatorAccount,
+// token account] pair per credited co-educator, in the recorded order; their
+// credits are reversed and their rewards burned the same way, as is the lead
+// educator's reward.
+// Passing an upheld fraud dispute for the completion lets anyone revoke it
+// without a prior request, since the reviewers already heard the case. A
+// dispute passed to a governance revocation is settled by it as well.
+pub fn revoke_completion_handler<'info>(mut ctx: Context<'_, '_, 'info, 'info, RevokeCompletion<'info>>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let upheld = match &ctx.accounts.dispute {
+        Some(dispute) => check_fraud_dispute(dispute, ctx.accounts.course_completion.key())?,
+        None => false,
+    };
+    if !upheld {
+        let multisig = ctx.accounts.multisig.as_ref().ok_or(SolLearningError::Unauthorized)?;
+        check_scoped_governance(multisig, REVOCATION_SCOPE, &ctx.accounts.authority)?;
+        check_dispute_window_closed(&ctx.accounts.course_completion, current_time)?;
+    }
+
+    let amount = ctx.accounts.course_completion.tokens_awarded;
+    reverse_award(
+        &mut ctx.accounts.student_info,
+        &mut ctx.accounts.educator,
+        amount,
+        ctx.accounts.course_completion.lead_share,
+    )?;
+
+    let burned = clawback_amount(&ctx.accounts.student_token_account, ctx.accounts.program_state.key(), amount);
+    if burned > 0 {
+        burn_clawback(&ctx, ctx.accounts.student_token_account.to_account_info(), burned)?;
+    }
+    let co_educators_burned = reverse_co_educator_credits(&ctx)?;
+    reverse_educator_reward(&mut ctx)?;
+
+    ctx.accounts.program_state.total_burned = ctx
+        .accounts
+        .program_state
+        .total_burned
+        .checked_add(burned)
+        .and_then(|total| total.checked_add(co_educators_burned))
+        .ok_or(SolLearningError::Overflow)?;
+
+    if let Some(dispute) = ctx.accounts.dispute.as_mut() {
+        dispute.status = DisputeStatus::Settled;
+        dispute.resolved_at.get_or_insert(current_time);
+
+        emit!(DisputeSettled {
+            dispute: dispute.key(),
+            kind: DisputeKind::FraudulentCompletion,
+            amount,
+            settled_by: ctx.accounts.authority.key(),
+            timestamp: current_time,
+        });
+    }
+
+    let course_completion = &mut ctx.accounts.course_completion;
+    course_completion.revoked_at = Some(current_time);
+
+    emit!(CompletionRevoked {
+        student: course_completion.student,
+        course_id: course_completion.course_id.clone(),
+        educator: ctx.accounts.educator.key(),
+        tokens_reversed: amount,
+        tokens_burned: burned,
+        revoked_by: ctx.accounts.authority.key(),
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Revoked {} completion of {}: {} tokens reversed, {} burned",
+        course_completion.student,
+        course_completion.course_id,
+        amount,
+        burned
+    );
+
+    Ok(())
+}
+
+fn revocable_at(requested_at: i64) -> Result<i64> {
+    requested_at
+        .checked_add(COMPLETION_DISPUTE_WINDOW)
+        .ok_or(SolLearningError::Overflow.into())
+}
+
+fn check_dispute_window_closed(course_completion: &CourseCompletion, current_time: i64) -> Result<()> {
+    let requested_at = course_completion
+        .revocation_requested_at
+        .ok_or(SolLearningError::RevocationNotRequested)?;
+    require!(
+        current_time >= revocable_at(requested_at)?,
+        SolLearningError::DisputeWindowActive
+    );
+    Ok(())
+}
+
+// Returns whether the dispute was upheld. An open dispute may still be passed
+// on the governance path so that it is settled along with the revocation.
+fn check_fraud_dispute(dispute: &Dispute, course_completion: Pubkey) -> Result<bool> {
+    require!(
+        dispute.kind == DisputeKind::FraudulentCompletion && dispute.course_completion == course_completion,
+        SolLearningError::InvalidDispute
+    );
+    match dispute.status {
+        DisputeStatus::Upheld => Ok(true),
+        DisputeStatus::Open => Ok(false),
+        _ => Err(SolLearningError::DisputeNotUpheld.into()),
+    }
+}
+
+fn reverse_award(student_info: &mut StudentInfo, educator: &mut EducatorAccount, amount: u64, lead_share: u64) -> Result<()> {
+    student_info.total_earned = student_info
+        .total_earned
+        .checked_sub(amount)
+        .ok_or(SolLearningError::Overflow)?;
+    student_info.courses_completed = student_info
+        .courses_completed
+        .checked_sub(1)
+        .ok_or(SolLearningError::Overflow)?;
+
+    educator.total_minted = educator
+        .total_minted
+        .checked_sub(lead_share)
+        .ok_or(SolLearningError::Overflow)?;
+    Ok(())
+}
+
+// Returns the co-educator rewards burned
+fn reverse_co_educator_credits<'info>(ctx: &Context<'_, '_, 'info, 'info, RevokeCompletion<'info>>) -> Result<u64> {
+    let credits: Vec<_> = ctx
+        .accounts
+        .course_completion
+        .co_educator_credits
+        .iter()
+        .filter(|credit| credit.educator != Pubkey::default())
+        .collect();
+    require!(
+        ctx.remaining_accounts.len() == credits.len() * 2,
+        SolLearningError::InvalidCoEducatorAccounts
+    );
+
+    let mut burned_total: u64 = 0;
+    for (credit, pair) in credits.into_iter().zip(ctx.remaining_accounts.chunks(2)) {
+        require!(pair[0].is_writable && pair[1].is_writable, SolLearningError::InvalidCoEducatorAccounts);
+
+        let mut educator = Account::<EducatorAccount>::try_from(&pair[0])
+            .map_err(|_| SolLearningError::InvalidCoEducatorAccounts)?;
+        require!(educator.key() == credit.educator, SolLearningError::InvalidCoEducatorAccounts);
+        educator.total_minted = educator
+            .total_minted
+            .checked_sub(credit.share)
+            .ok_or(SolLearningError::Overflow)?;
+        educator.exit(ctx.program_id)?;
+
+        let token_account = Account::<TokenAccount>::try_from(&pair[1])
+            .map_err(|_| SolLearningError::InvalidTokenAccount)?;
+        require!(token_account.mint == ctx.accounts.program_state.token_mint, SolLearningError::InvalidMint);
+        require!(token_account.owner == educator.educator_address, SolLearningError::InvalidTokenAccount);
+
+        let burned = clawback_amount(&token_account, ctx.accounts.program_state.key(), credit.reward);
+        if burned > 0 {
+            burn_clawback(ctx, pair[1].clone(), burned)?;
+            burned_total = burned_total.checked_add(burned).ok_or(SolLearningError::Overflow)?;
+        }
+    }
+    Ok(burned_total)
+}
+
+// The reward is taken back out of the minted supply rather than counted as
+// burned, whether or not the clawback reaches all of it
+fn reverse_educator_reward<'info>(ctx: &mut Context<'_, '_, 'info, 'info, RevokeCompletion<'info>>) -> Result<()> {
+    let reward = ctx.accounts.course_completion.educator_reward;
+    if reward == 0 {
+        return Ok(());
+    }
+
+    let educator = &mut ctx.accounts.educator;
+    educator.rewards_earned = educator
+        .rewards_earned
+        .checked_sub(reward)
+        .ok_or(SolLearningError::Overflow)?;
+    let program_state = &mut ctx.accounts.program_state;
+    program_state.total_minted = program_state
+        .total_minted
+        .checked_sub(reward)
+        .ok_or(SolLearningError::Overflow)?;
+
+    let token_account = ctx
+        .accounts
+        .educator_token_account
+        .as_ref()
+        .ok_or(SolLearningError::EducatorTokenAccountRequired)?;
+    let burned = clawback_amount(token_account, ctx.accounts.program_state.key(), reward);
+    if burned > 0 {
+        burn_clawback(ctx, token_account.to_account_info(), burned)?;
+    }
+    Ok(())
+}
+
+// Limited by the balance and by what remains of the delegated allowance
+fn clawback_amount(token_account: &TokenAccount, program_state: Pubkey, amount: u64) -> u64 {
+    let delegated = match token_account.delegate {
+        anchor_lang::solana_program::program_option::COption::Some(delegate)
+            if delegate == program_state => token_account.delegated_amount,
+        _ => 0,
+    };
+    amount.min(token_account.amount).min(delegated)
+}
+
+fn burn_clawback<'info>(ctx: &Context<'_, '_, 'info, 'info, RevokeCompletion<'info>>, from: AccountInfo<'info>, amount: u64) -> Result<()> {
+    let signer_seeds = &[PROGRAM_STATE_SEED, &[ctx.accounts.program_state.bump]];
+    let signer = &[&signer_seeds[..]];
+
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Burn {
+                mint: ctx.accounts.token_mint.to_account_info(),
+                from,
+                authority: ctx.accounts.program_state.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::associated_token::get_associated_token_address;
+use crate::states::program::ProgramState;
+use crate::states::educator::EducatorAccount;
+use crate::states::student::StudentInfo;
+use crate::states::course::CourseCompletion;
+use crate::states::dispute::Dispute;
+use crate::states::signers::Multisig;
+use crate::error::SolLearningError;
+use crate::constants::*;
+
+// Shared by request_completion_revocation and cancel_completion_revocation
+#[derive(Accounts)]
+pub struct CompletionRevocationRequest<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(seeds = [MULTISIG_SEED, multisig.label.as_bytes()], bump = multisig.bump)]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [COURSE_COMPLETION_SEED, course_completion.student.as_ref(), course_completion.course_id.as_bytes()],
+        bump = course_completion.bump,
+        constraint = course_completion.revoked_at.is_none() @ SolLearningError::CompletionRevoked,
+    )]
+    pub course_completion: Account<'info, CourseCompletion>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeCompletion<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut, seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [COURSE_COMPLETION_SEED, course_completion.student.as_ref(), course_completion.course_id.as_bytes()],
+        bump = course_completion.bump,
+        constraint = course_completion.revoked_at.is_none() @ SolLearningError::CompletionRevoked,
+    )]
+    pub course_completion: Account<'info, CourseCompletion>,
+
+    #[account(
+        mut,
+        seeds = [STUDENT_SEED, course_completion.student.as_ref()],
+        bump = student_info.bump,
+    )]
+    pub student_info: Account<'info, StudentInfo>,
+
+    #[account(mut, address = course_completion.verified_by @ SolLearningError::InvalidEducator)]
+    pub educator: Account<'info, EducatorAccount>,
+
+    /// CHECK: checked against program_state.token_mint
+    #[account(mut, address = program_state.token_mint @ SolLearningError::InvalidMint)]
+    pub token_mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = student_token_account.mint == program_state.token_mint @ SolLearningError::InvalidMint,
+        constraint = student_token_account.owner == course_completion.student @ SolLearningError::InvalidTokenAccount,
+    )]
+    pub student_token_account: Account<'info, TokenAccount>,
+
+    // Required when the completion paid the lead educator a reward
+    #[account(
+        mut,
+        address = get_associated_token_address(&educator.educator_address, &program_state.token_mint)
+            @ SolLearningError::InvalidTokenAccount,
+    )]
+    pub educator_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [DISPUTE_SEED, dispute.course.as_ref(), dispute.student.as_ref(), dispute.opened_by.as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Option<Account<'info, Dispute>>,
+
+    #[account(seeds = [MULTISIG_SEED, multisig.label.as_bytes()], bump = multisig.bump)]
+    pub multisig: Option<Account<'info, Multisig>>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -3,15 +3,20 @@ use crate::states::course::{Course, CourseHistory, CourseUpdated};
 use crate::constants::*;
 use crate::error::SolLearningError;
 use crate::instructions::course::update_course_struct::UpdateCourse;
+use crate::utils::access::check_course_editor;
+use crate::utils::pause::{check_program_running, check_function_running};
 
-pub fn update_course_handler(
-    ctx: Context<UpdateCourse>,
+pub fn update_course_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateCourse<'info>>,
     course_name: Option<String>,
     reward_amount: Option<u64>,
     is_active: Option<bool>,
     metadata_hash: Option<[u8; 32]>,
     change_description: String,
 ) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_COURSE_UPDATE)?;
+
     require!(
         change_description.len() <= MAX_CHANGE_DESCRIPTION_LENGTH,
         SolLearningError::DescriptionTooLong
@@ -19,9 +24,19 @@ pub fn update_course_handler(
 
     let timestamp = Clock::get()?.unix_timestamp;
 
+    check_course_editor(
+        &ctx.accounts.educator,
+        &ctx.accounts.educator_authority,
+        &ctx.accounts.operator,
+        ctx.accounts.co_educators.as_deref(),
+        ctx.remaining_accounts,
+        timestamp,
+    )?;
+
     // Extract values before mutability
     let course_id = ctx.accounts.course.course_id.clone();
     let educator_key = ctx.accounts.educator.key();
+    let updated_by = ctx.accounts.educator_authority.key();
     let previous_state = capture_previous_state(&ctx.accounts.course);
 
     {
@@ -29,12 +44,12 @@ pub fn update_course_handler(
         let course_history = &mut ctx.accounts.course_history;
 
         apply_updates(course, course_name, reward_amount, is_active, metadata_hash, timestamp)?;
-        store_course_history(course_history, educator_key, &course_id, &previous_state, &change_description, timestamp)?;
+        store_course_history(course_history, educator_key, updated_by, &course_id, &previous_state, &change_description, timestamp)?;
     }
 
     // Now ctx is free for immutable use
-    emit_course_updated(educator_key, &course_id, &previous_state, timestamp)?;
-    log_update(educator_key, &course_id);
+    emit_course_updated(educator_key, updated_by, &course_id, &previous_state, timestamp)?;
+    log_update(updated_by, &course_id);
 
     Ok(())
 }
@@ -83,6 +98,7 @@ fn apply_updates(
 fn store_course_history(
     course_history: &mut Account<CourseHistory>,
     educator_key: Pubkey,
+    updated_by: Pubkey,
     course_id: &str,
     previous_state: &(String, u64, bool, [u8; 32]),
     change_description: &str,
@@ -97,7 +113,7 @@ fn store_course_history(
     course_history.previous_reward = *previous_reward;
     course_history.previous_active = *previous_active;
     course_history.previous_metadata_hash = *previous_metadata_hash;
-    course_history.updated_by = educator_key;
+    course_history.updated_by = updated_by;
     course_history.updated_at = timestamp;
     course_history.change_description = change_description.to_string();
 
@@ -106,6 +122,7 @@ fn store_course_history(
 
 fn emit_course_updated(
     educator_key: Pubkey,
+    updated_by: Pubkey,
     course_id: &str,
     previous_state: &(String, u64, bool, [u8; 32]),
     timestamp: i64,
@@ -122,17 +139,17 @@ fn emit_course_updated(
         new_reward: Some(*previous_reward), // Keeping the same reward
         previous_active: *previous_active,
         new_active: Some(*previous_active),
-        updated_by: educator_key,
+        updated_by,
         timestamp,
     });
 
     Ok(())
 }
 
-fn log_update(educator_key: Pubkey, course_id: &str) {
+fn log_update(updated_by: Pubkey, course_id: &str) {
     msg!(
         "Course {} updated by {}",
         course_id,
-        educator_key
+        updated_by
     );
 }
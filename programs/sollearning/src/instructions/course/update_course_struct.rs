@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
 use crate::states::program::ProgramState;
-use crate::states::educator::EducatorAccount;
-use crate::states::course::{Course, CourseHistory};
+use crate::states::educator::{EducatorAccount, EducatorOperator};
+use crate::states::course::{Course, CourseHistory, CourseCoEducators};
 use crate::constants::*;
+use crate::error::SolLearningError;
 
+// Co-educators signing instead of the lead are passed through the remaining
+// accounts, see check_course_editor.
 #[derive(Accounts)]
 #[instruction(course_id: String)]
 pub struct UpdateCourse<'info> {
@@ -16,7 +19,10 @@ pub struct UpdateCourse<'info> {
     #[account(mut)]
     pub program_state: Account<'info, ProgramState>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = course.educator == educator.key() @ SolLearningError::CourseNotOwnedByEducator,
+    )]
     pub course: Account<'info, Course>,
 
     #[account(
@@ -28,6 +34,18 @@ pub struct UpdateCourse<'info> {
     )]
     pub course_history: Account<'info, CourseHistory>,
 
+    #[account(
+        seeds = [OPERATOR_SEED, educator.key().as_ref(), educator_authority.key().as_ref()],
+        bump = operator.bump,
+    )]
+    pub operator: Option<Account<'info, EducatorOperator>>,
+
+    #[account(
+        seeds = [CO_EDUCATORS_SEED, course.key().as_ref()],
+        bump = co_educators.bump,
+    )]
+    pub co_educators: Option<Account<'info, CourseCoEducators>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use crate::states::educator::EducatorOperator;
+use crate::states::course::{CompletionVerification, CompletionVerified, CourseCompletion, CourseCompleted, TokensMinted};
+use crate::error::SolLearningError;
+use crate::constants::*;
+use crate::instructions::course::verify_completion_struct::{VerifyCompletion, calculate_completion_verification_space};
+use crate::utils::pause::{check_program_running, check_function_running};
+use crate::utils::blocklist::check_not_blocklisted;
+use crate::utils::accounts::{create_program_account, write_account};
+use crate::utils::operator::charge_operator_allowance;
+use crate::utils::circuit_breaker::{record_mint_for_circuit_breakers, trip_circuit_breaker};
+use crate::utils::minting::{
+    validate_mint_cooldown, update_educator_stats, update_minted_supply, update_student_info, mint_reward, distribute_co_educator_shares,
+    co_educator_rewards_total, validate_clawback_delegate,
+};
+
+// Each listed verifier signs once. Signatures from verifiers that have since been
+// removed from the course are dropped, so the reward is minted by whichever
+// verification brings the current verifier count up to the threshold.
+pub fn verify_completion_handler<'info>(mut ctx: Context<'_, '_, 'info, 'info, VerifyCompletion<'info>>) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_MINT)?;
+    check_not_blocklisted(&ctx.accounts.student_blocklist, ctx.program_id)?;
+    check_not_blocklisted(&ctx.accounts.verifier_blocklist, ctx.program_id)?;
+    check_not_blocklisted(&ctx.accounts.educator_blocklist, ctx.program_id)?;
+
+    let verifier_key = ctx.accounts.verifier.key();
+    let listed = &ctx.accounts.course_verifiers.verifiers;
+    require!(listed.contains(&verifier_key), SolLearningError::NotCourseVerifier);
+    require!(ctx.accounts.course_completion.data_is_empty(), SolLearningError::CourseAlreadyCompleted);
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let mut verification = load_or_create_verification(&ctx, current_time)?;
+
+    require!(
+        !verification.verified_by.contains(&verifier_key),
+        SolLearningError::CompletionAlreadyVerified
+    );
+    verification.verified_by.retain(|key| listed.contains(key));
+    verification.verified_by.push(verifier_key);
+
+    let verifications = verification.verified_by.len() as u8;
+    let threshold = ctx.accounts.course_verifiers.threshold;
+
+    emit!(CompletionVerified {
+        course: ctx.accounts.course.key(),
+        student: ctx.accounts.student.key(),
+        verifier: verifier_key,
+        verifications,
+        threshold,
+        timestamp: current_time,
+    });
+
+    if verifications >= threshold {
+        if award_completion(&mut ctx, current_time)? {
+            verification.completed_at = Some(current_time);
+        } else {
+            // A circuit breaker paused minting; the signature is dropped so
+            // it can be given again once minting resumes
+            verification.verified_by.pop();
+        }
+    }
+
+    write_account(&ctx.accounts.verification, &verification)?;
+
+    msg!(
+        "Completion of {} for {} verified by {} ({}/{})",
+        ctx.accounts.course.course_id,
+        ctx.accounts.student.key(),
+        verifier_key,
+        verifications,
+        threshold
+    );
+
+    Ok(())
+}
+
+fn load_or_create_verification(ctx: &Context<VerifyCompletion>, current_time: i64) -> Result<CompletionVerification> {
+    let verification = &ctx.accounts.verification;
+
+    if !verification.data_is_empty() {
+        require!(verification.owner == ctx.program_id, SolLearningError::InvalidVerificationRecord);
+        let data = verification.try_borrow_data()?;
+        return CompletionVerification::try_deserialize(&mut &data[..]);
+    }
+
+    let course_key = ctx.accounts.course.key();
+    let student_key = ctx.accounts.student.key();
+    let bump = ctx.bumps.verification;
+    create_program_account(
+        ctx.accounts.verifier.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        verification.to_account_info(),
+        ctx.program_id,
+        calculate_completion_verification_space(),
+        &[COMPLETION_VERIFICATION_SEED, course_key.as_ref(), student_key.as_ref(), &[bump]],
+    )?;
+
+    Ok(CompletionVerification {
+        course: course_key,
+        student: student_key,
+        verified_by: Vec::new(),
+        first_verified_at: current_time,
+        completed_at: None,
+        bump,
+    })
+}
+
+// Awards go through the same gates as mint_to_student: the educator's mint
+// cooldown, the circuit breakers and, when the verifier is also one of the
+// educator's operators, its mint allowance. Returns false without awarding
+// anything when a circuit breaker trips.
+fn award_completion<'info>(ctx: &mut Context<'_, '_, 'info, 'info, VerifyCompletion<'info>>, current_time: i64) -> Result<bool> {
+    let amount = ctx.accounts.course.reward_amount;
+    require!(
+        amount > 0 && amount <= ctx.accounts.educator.mint_limit,
+        SolLearningError::InvalidAmount
+    );
+    validate_clawback_delegate(&ctx.accounts.student_token_account, ctx.accounts.program_state.key(), amount)?;
+    validate_mint_cooldown(&ctx.accounts.educator, current_time)?;
+
+    let student_key = ctx.accounts.student.key();
+    let educator_key = ctx.accounts.educator.key();
+    let course_id = ctx.accounts.course.course_id.clone();
+    let bump = ctx.bumps.course_completion;
+
+    let minted = amount
+        .saturating_add(co_educator_rewards_total(&ctx.accounts.course, ctx.accounts.co_educators.as_deref(), amount));
+    if let Some(breaker) = record_mint_for_circuit_breakers(
+        &mut ctx.accounts.program_state,
+        &ctx.accounts.program_config,
+        &mut ctx.accounts.educator,
+        amount,
+        minted,
+        Clock::get()?.slot,
+    )? {
+        trip_circuit_breaker(&mut ctx.accounts.program_state, &mut ctx.accounts.educator, breaker, amount, current_time);
+        return Ok(false);
+    }
+
+    charge_verifier_operator(ctx, amount)?;
+
+    create_program_account(
+        ctx.accounts.verifier.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.course_completion.to_account_info(),
+        ctx.program_id,
+        8 + std::mem::size_of::<CourseCompletion>(),
+        &[COURSE_COMPLETION_SEED, student_key.as_ref(), course_id.as_bytes(), &[bump]],
+    )?;
+
+    let (lead_share, co_educator_credits) = distribute_co_educator_shares(
+        &ctx.accounts.course,
+        ctx.accounts.co_educators.as_deref(),
+        ctx.remaining_accounts,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        &mut ctx.accounts.program_state,
+        amount,
+        current_time,
+        ctx.program_id,
+    )?;
+    write_account(
+        &ctx.accounts.course_completion,
+        &CourseCompletion {
+            student: student_key,
+            course_id: course_id.clone(),
+            verified_by: educator_key,
+            completion_time: current_time,
+            tokens_awarded: amount,
+            revocation_requested_at: None,
+            revocation_reason_hash: [0; 32],
+            revoked_at: None,
+            lead_share,
+            co_educator_credits,
+            educator_reward: 0,
+            bump,
+        },
+    )?;
+
+    update_educator_stats(&mut ctx.accounts.educator, lead_share, current_time)?;
+    update_minted_supply(&mut ctx.accounts.program_state, amount)?;
+    update_student_info(&mut ctx.accounts.student_info, amount, current_time)?;
+
+    mint_reward(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.student_token_account.to_account_info(),
+        &ctx.accounts.program_state,
+        amount,
+    )?;
+
+    emit!(CourseCompleted {
+        student: student_key,
+        course_id,
+        educator: educator_key,
+        tokens_awarded: amount,
+        timestamp: current_time,
+    });
+
+    emit!(TokensMinted {
+        recipient: student_key,
+        amount,
+        minted_by: ctx.accounts.verification.key(),
+        timestamp: current_time,
+    });
+
+    Ok(true)
+}
+
+fn charge_verifier_operator(ctx: &Context<VerifyCompletion>, amount: u64) -> Result<()> {
+    let operator_info = &ctx.accounts.verifier_operator;
+    if operator_info.data_is_empty() {
+        return Ok(());
+    }
+    require!(operator_info.owner == ctx.program_id, SolLearningError::Unauthorized);
+
+    let mut operator = {
+        let data = operator_info.try_borrow_data()?;
+        EducatorOperator::try_deserialize(&mut &data[..])?
+    };
+    charge_operator_allowance(&mut operator, amount)?;
+    write_account(operator_info, &operator)
+}
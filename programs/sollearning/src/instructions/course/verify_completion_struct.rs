@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::states::program::ProgramState;
+use crate::states::config::ProgramConfig;
+use crate::states::educator::EducatorAccount;
+use crate::states::student::StudentInfo;
+use crate::states::course::{Course, CourseVerifiers, CourseCoEducators};
+use crate::error::SolLearningError;
+use crate::constants::*;
+
+// The verification record and the completion are created by the handler: the
+// first verifier opens the record and the k-th one creates the completion.
+// Co-taught courses also take the co-educator triples in the remaining accounts.
+#[derive(Accounts)]
+pub struct VerifyCompletion<'info> {
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    #[account(mut, seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(seeds = [CONFIG_SEED], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        constraint = course.is_active @ SolLearningError::CourseInactive,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        seeds = [COURSE_VERIFIERS_SEED, course.key().as_ref()],
+        bump = course_verifiers.bump,
+        constraint = course_verifiers.threshold > 0 @ SolLearningError::InvalidVerifierSet,
+    )]
+    pub course_verifiers: Account<'info, CourseVerifiers>,
+
+    #[account(mut, address = course.educator @ SolLearningError::InvalidEducator)]
+    pub educator: Account<'info, EducatorAccount>,
+
+    /// CHECK: the student whose completion is being verified
+    pub student: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [STUDENT_SEED, student.key().as_ref()],
+        bump = student_info.bump,
+    )]
+    pub student_info: Account<'info, StudentInfo>,
+
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, student.key().as_ref()], bump)]
+    pub student_blocklist: UncheckedAccount<'info>,
+
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, verifier.key().as_ref()], bump)]
+    pub verifier_blocklist: UncheckedAccount<'info>,
+
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, educator.educator_address.as_ref()], bump)]
+    pub educator_blocklist: UncheckedAccount<'info>,
+
+    /// CHECK: EducatorOperator PDA of the verifier; only used when the educator granted one
+    #[account(mut, seeds = [OPERATOR_SEED, educator.key().as_ref(), verifier.key().as_ref()], bump)]
+    pub verifier_operator: UncheckedAccount<'info>,
+
+    /// CHECK: CompletionVerification PDA, created by the first verifier
+    #[account(
+        mut,
+        seeds = [COMPLETION_VERIFICATION_SEED, course.key().as_ref(), student.key().as_ref()],
+        bump,
+    )]
+    pub verification: UncheckedAccount<'info>,
+
+    /// CHECK: CourseCompletion PDA, created once the threshold is reached
+    #[account(
+        mut,
+        seeds = [COURSE_COMPLETION_SEED, student.key().as_ref(), course.course_id.as_bytes()],
+        bump,
+    )]
+    pub course_completion: UncheckedAccount<'info>,
+
+    /// CHECK: checked against program_state.token_mint
+    #[account(mut, address = program_state.token_mint @ SolLearningError::InvalidMint)]
+    pub token_mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = student_token_account.mint == program_state.token_mint @ SolLearningError::InvalidMint,
+        constraint = student_token_account.owner == student.key() @ SolLearningError::InvalidTokenAccount,
+    )]
+    pub student_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [CO_EDUCATORS_SEED, course.key().as_ref()],
+        bump = co_educators.bump,
+    )]
+    pub co_educators: Option<Account<'info, CourseCoEducators>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn calculate_completion_verification_space() -> usize {
+    8 + 32 + 32 + 4 + MAX_COURSE_VERIFIERS * 32 + 8 + 9 + 1
+}
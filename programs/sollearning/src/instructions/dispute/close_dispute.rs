@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::states::course::CourseCompletion;
+use crate::states::dispute::{DisputeKind, DisputeStatus};
+use crate::error::SolLearningError;
+use crate::instructions::dispute::close_dispute_struct::CloseDispute;
+use crate::utils::pause::check_program_running;
+
+// Dismissed and settled disputes are closed by their opener, who gets the rent
+// back and can open a new dispute for the same course and student. Upheld
+// disputes stay open until they are settled, unless settling can no longer
+// succeed: the missing completion has since been awarded, or the fraudulent
+// one has already been revoked.
+pub fn close_dispute_handler(ctx: Context<CloseDispute>) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+
+    let dispute = &ctx.accounts.dispute;
+    let closable = match dispute.status {
+        DisputeStatus::Dismissed | DisputeStatus::Settled => true,
+        DisputeStatus::Upheld => is_settlement_moot(dispute.kind, &ctx.accounts.course_completion, ctx.program_id)?,
+        DisputeStatus::Open => false,
+    };
+    require!(closable, SolLearningError::DisputeNotResolved);
+
+    msg!(
+        "Dispute {} closed as {:?}",
+        dispute.key(),
+        dispute.status
+    );
+    Ok(())
+}
+
+fn is_settlement_moot(kind: DisputeKind, completion_info: &AccountInfo, program_id: &Pubkey) -> Result<bool> {
+    let completion_exists = completion_info.owner == program_id && !completion_info.data_is_empty();
+    match kind {
+        DisputeKind::MissingCompletion => Ok(completion_exists),
+        DisputeKind::FraudulentCompletion => {
+            if !completion_exists {
+                return Ok(false);
+            }
+            let completion = CourseCompletion::try_deserialize(&mut &completion_info.data.borrow()[..])?;
+            Ok(completion.revoked_at.is_some())
+        }
+    }
+}
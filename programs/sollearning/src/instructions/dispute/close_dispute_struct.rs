@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::course::Course;
+use crate::states::dispute::Dispute;
+use crate::error::SolLearningError;
+use crate::constants::{DISPUTE_SEED, PROGRAM_STATE_SEED, COURSE_COMPLETION_SEED};
+
+#[derive(Accounts)]
+pub struct CloseDispute<'info> {
+    #[account(mut)]
+    pub opener: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        close = opener,
+        seeds = [DISPUTE_SEED, dispute.course.as_ref(), dispute.student.as_ref(), opener.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(address = dispute.course @ SolLearningError::InvalidDispute)]
+    pub course: Account<'info, Course>,
+
+    /// CHECK: completion PDA; decides whether an upheld dispute can still be settled
+    #[account(
+        seeds = [COURSE_COMPLETION_SEED, dispute.student.as_ref(), course.course_id.as_bytes()],
+        bump,
+    )]
+    pub course_completion: UncheckedAccount<'info>,
+}
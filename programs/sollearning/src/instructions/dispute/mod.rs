@@ -0,0 +1,13 @@
+pub mod open_dispute;
+pub mod review_dispute;
+pub mod settle_dispute;
+pub mod close_dispute;
+pub mod open_dispute_struct;
+pub mod review_dispute_struct;
+pub mod settle_dispute_struct;
+pub mod close_dispute_struct;
+
+pub use open_dispute::open_dispute_handler;
+pub use review_dispute::review_dispute_handler;
+pub use settle_dispute::settle_dispute_mint_handler;
+pub use close_dispute::close_dispute_handler;
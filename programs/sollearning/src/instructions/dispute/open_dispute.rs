@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::states::course::CourseCompletion;
+use crate::states::dispute::{DisputeKind, DisputeStatus, DisputeOpened};
+use crate::error::SolLearningError;
+use crate::instructions::dispute::open_dispute_struct::OpenDispute;
+use crate::utils::pause::check_program_running;
+
+// Only the student can dispute a completion they were never awarded; anyone
+// can dispute an awarded completion as fraudulent.
+pub fn open_dispute_handler(
+    ctx: Context<OpenDispute>,
+    kind: DisputeKind,
+    evidence_hash: [u8; 32],
+) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+
+    let student = ctx.accounts.student_info.student_address;
+    let completion_info = &ctx.accounts.course_completion;
+    let course_completion = match kind {
+        DisputeKind::MissingCompletion => {
+            require!(ctx.accounts.opener.key() == student, SolLearningError::InvalidStudent);
+            require!(completion_info.data_is_empty(), SolLearningError::CourseAlreadyCompleted);
+            Pubkey::default()
+        }
+        DisputeKind::FraudulentCompletion => {
+            require!(
+                completion_info.owner == ctx.program_id && !completion_info.data_is_empty(),
+                SolLearningError::InvalidCourseCompletion
+            );
+            let completion = CourseCompletion::try_deserialize(&mut &completion_info.data.borrow()[..])?;
+            require!(completion.revoked_at.is_none(), SolLearningError::CompletionRevoked);
+            completion_info.key()
+        }
+    };
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.course = ctx.accounts.course.key();
+    dispute.student = student;
+    dispute.course_completion = course_completion;
+    dispute.opened_by = ctx.accounts.opener.key();
+    dispute.kind = kind;
+    dispute.evidence_hash = evidence_hash;
+    dispute.status = DisputeStatus::Open;
+    dispute.reviewers = Vec::new();
+    dispute.upheld_votes = 0;
+    dispute.dismissed_votes = 0;
+    dispute.opened_at = current_time;
+    dispute.resolved_at = None;
+    dispute.bump = ctx.bumps.dispute;
+
+    emit!(DisputeOpened {
+        dispute: dispute.key(),
+        course: dispute.course,
+        student,
+        kind,
+        evidence_hash,
+        opened_by: dispute.opened_by,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Dispute opened by {} for student {} on course {}",
+        dispute.opened_by,
+        student,
+        ctx.accounts.course.course_id
+    );
+
+    Ok(())
+}
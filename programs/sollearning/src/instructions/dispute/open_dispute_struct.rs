@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::student::StudentInfo;
+use crate::states::course::Course;
+use crate::states::dispute::Dispute;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    #[account(mut)]
+    pub opener: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [COURSE_SEED, course.educator.as_ref(), course.course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        seeds = [STUDENT_SEED, student_info.student_address.as_ref()],
+        bump = student_info.bump,
+    )]
+    pub student_info: Account<'info, StudentInfo>,
+
+    /// CHECK: completion PDA; must exist for fraud disputes and must not for missing ones
+    #[account(
+        seeds = [COURSE_COMPLETION_SEED, student_info.student_address.as_ref(), course.course_id.as_bytes()],
+        bump,
+    )]
+    pub course_completion: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = opener,
+        space = calculate_dispute_space(),
+        seeds = [DISPUTE_SEED, course.key().as_ref(), student_info.student_address.as_ref(), opener.key().as_ref()],
+        bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn calculate_dispute_space() -> usize {
+    8 + 32 + 32 + 32 + 32 + 1 + 32 + 1 + 4 + MAX_DISPUTE_REVIEWERS * 32 + 1 + 1 + 8 + 9 + 1
+}
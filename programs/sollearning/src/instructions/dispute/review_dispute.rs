@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::states::dispute::{Dispute, DisputeStatus, DisputeReviewed, DisputeResolved};
+use crate::error::SolLearningError;
+use crate::constants::MAX_DISPUTE_REVIEWERS;
+use crate::instructions::dispute::review_dispute_struct::ReviewDispute;
+use crate::utils::access::check_dispute_reviewer;
+use crate::utils::pause::check_program_running;
+use crate::utils::reputation::{load_or_create_reputation, record_dispute_lost, store_reputation};
+
+// Each reviewer votes once, and neither party to the dispute nor the course's
+// educator can review it. The first side to reach the configured quorum
+// resolves the dispute; one that runs out of reviewer slots is dismissed. An
+// upheld dispute counts against the course educator's reputation.
+pub fn review_dispute_handler(ctx: Context<ReviewDispute>, uphold: bool) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_dispute_reviewer(
+        &ctx.accounts.program_config,
+        ctx.accounts.multisig.as_deref(),
+        &ctx.accounts.reviewer,
+    )?;
+
+    let reviewer = ctx.accounts.reviewer.key();
+    let quorum = ctx.accounts.program_config.dispute_quorum.max(1);
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let dispute = &mut ctx.accounts.dispute;
+    require!(
+        reviewer != dispute.opened_by
+            && reviewer != dispute.student
+            && reviewer != ctx.accounts.educator.educator_address,
+        SolLearningError::Unauthorized
+    );
+    require!(!dispute.reviewers.contains(&reviewer), SolLearningError::DisputeAlreadyReviewed);
+
+    record_review(dispute, reviewer, uphold)?;
+
+    emit!(DisputeReviewed {
+        dispute: dispute.key(),
+        reviewer,
+        uphold,
+        timestamp: current_time,
+    });
+
+    if let Some(status) = resolve_status(dispute, quorum) {
+        dispute.status = status;
+        dispute.resolved_at = Some(current_time);
+
+        emit!(DisputeResolved {
+            dispute: dispute.key(),
+            status,
+            upheld_votes: dispute.upheld_votes,
+            dismissed_votes: dispute.dismissed_votes,
+            timestamp: current_time,
+        });
+
+        msg!("Dispute {} resolved as {:?}", dispute.key(), status);
+
+        if status == DisputeStatus::Upheld {
+            record_lost_dispute(&ctx, current_time)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn record_lost_dispute(ctx: &Context<ReviewDispute>, current_time: i64) -> Result<()> {
+    let reputation_account = ctx.accounts.educator_reputation.to_account_info();
+    let mut reputation = load_or_create_reputation(
+        &reputation_account,
+        &ctx.accounts.reviewer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.course.educator,
+        ctx.bumps.educator_reputation,
+        ctx.program_id,
+    )?;
+    record_dispute_lost(&mut reputation)?;
+    store_reputation(&reputation_account, &mut reputation, current_time)
+}
+
+fn record_review(dispute: &mut Dispute, reviewer: Pubkey, uphold: bool) -> Result<()> {
+    dispute.reviewers.push(reviewer);
+    if uphold {
+        dispute.upheld_votes = dispute.upheld_votes.checked_add(1).ok_or(SolLearningError::Overflow)?;
+    } else {
+        dispute.dismissed_votes = dispute.dismissed_votes.checked_add(1).ok_or(SolLearningError::Overflow)?;
+    }
+    Ok(())
+}
+
+fn resolve_status(dispute: &Dispute, quorum: u8) -> Option<DisputeStatus> {
+    if dispute.upheld_votes >= quorum {
+        Some(DisputeStatus::Upheld)
+    } else if dispute.dismissed_votes >= quorum || dispute.reviewers.len() >= MAX_DISPUTE_REVIEWERS {
+        Some(DisputeStatus::Dismissed)
+    } else {
+        None
+    }
+}
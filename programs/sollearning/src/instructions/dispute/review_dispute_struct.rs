@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::config::ProgramConfig;
+use crate::states::signers::Multisig;
+use crate::states::course::Course;
+use crate::states::educator::EducatorAccount;
+use crate::states::dispute::{Dispute, DisputeStatus};
+use crate::error::SolLearningError;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct ReviewDispute<'info> {
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(seeds = [CONFIG_SEED], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(seeds = [MULTISIG_SEED, multisig.label.as_bytes()], bump = multisig.bump)]
+    pub multisig: Option<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        seeds = [DISPUTE_SEED, dispute.course.as_ref(), dispute.student.as_ref(), dispute.opened_by.as_ref()],
+        bump = dispute.bump,
+        constraint = dispute.status == DisputeStatus::Open @ SolLearningError::DisputeNotOpen,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(address = dispute.course @ SolLearningError::InvalidDispute)]
+    pub course: Account<'info, Course>,
+
+    #[account(address = course.educator @ SolLearningError::InvalidEducator)]
+    pub educator: Account<'info, EducatorAccount>,
+
+    /// CHECK: EducatorReputation PDA of the course's educator, created on first use
+    #[account(mut, seeds = [EDUCATOR_REPUTATION_SEED, course.educator.as_ref()], bump)]
+    pub educator_reputation: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
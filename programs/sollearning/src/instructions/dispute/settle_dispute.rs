@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use crate::states::course::{CourseCompleted, TokensMinted};
+use crate::states::dispute::{DisputeKind, DisputeStatus, DisputeSettled};
+use crate::error::SolLearningError;
+use crate::constants::PAUSE_FLAG_MINT;
+use crate::instructions::dispute::settle_dispute_struct::SettleDisputeMint;
+use crate::utils::pause::{check_program_running, check_function_running};
+use crate::utils::blocklist::check_not_blocklisted;
+use crate::utils::circuit_breaker::{record_mint_for_circuit_breakers, trip_circuit_breaker};
+use crate::utils::reputation::{
+    load_or_create_reputation, mark_student_taught, record_completion, apply_reputation_tier, store_reputation,
+};
+use crate::utils::minting::{
+    validate_mint_cooldown, update_educator_stats, update_minted_supply, update_student_info,
+    initialize_course_completion, mint_reward, distribute_co_educator_shares, co_educator_rewards_total,
+    validate_clawback_delegate,
+};
+
+// Anyone can settle an upheld missing-completion dispute. The course reward is
+// awarded through the same checks and accounting as mint_to_student,
+// attributed to the course's educator, who must be active and out of their
+// mint cooldown. Fraud disputes are settled through revoke_completion.
+pub fn settle_dispute_mint_handler<'info>(ctx: Context<'_, '_, 'info, 'info, SettleDisputeMint<'info>>) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_MINT)?;
+    check_not_blocklisted(&ctx.accounts.student_blocklist, ctx.program_id)?;
+    check_not_blocklisted(&ctx.accounts.educator_blocklist, ctx.program_id)?;
+
+    let amount = ctx.accounts.course.reward_amount;
+    require!(ctx.accounts.educator.is_active, SolLearningError::InactiveEducator);
+    require!(
+        amount > 0 && amount <= ctx.accounts.educator.mint_limit,
+        SolLearningError::InvalidAmount
+    );
+    validate_clawback_delegate(&ctx.accounts.student_token_account, ctx.accounts.program_state.key(), amount)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    validate_mint_cooldown(&ctx.accounts.educator, current_time)?;
+    let student = ctx.accounts.dispute.student;
+    let educator_key = ctx.accounts.educator.key();
+    let course_id = ctx.accounts.course.course_id.clone();
+
+    let minted = amount
+        .saturating_add(co_educator_rewards_total(&ctx.accounts.course, ctx.accounts.co_educators.as_deref(), amount));
+    if let Some(breaker) = record_mint_for_circuit_breakers(
+        &mut ctx.accounts.program_state,
+        &ctx.accounts.program_config,
+        &mut ctx.accounts.educator,
+        amount,
+        minted,
+        Clock::get()?.slot,
+    )? {
+        trip_circuit_breaker(&mut ctx.accounts.program_state, &mut ctx.accounts.educator, breaker, amount, current_time);
+        // The dispute stays upheld and can be settled once minting resumes
+        return ctx.accounts.course_completion.close(ctx.accounts.caller.to_account_info());
+    }
+
+    let (lead_share, co_educator_credits) = distribute_co_educator_shares(
+        &ctx.accounts.course,
+        ctx.accounts.co_educators.as_deref(),
+        ctx.remaining_accounts,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        &mut ctx.accounts.program_state,
+        amount,
+        current_time,
+        ctx.program_id,
+    )?;
+
+    update_educator_stats(&mut ctx.accounts.educator, lead_share, current_time)?;
+    update_minted_supply(&mut ctx.accounts.program_state, amount)?;
+    update_student_info(&mut ctx.accounts.student_info, amount, current_time)?;
+    initialize_course_completion(
+        &mut ctx.accounts.course_completion,
+        student,
+        &course_id,
+        educator_key,
+        amount,
+        current_time,
+        ctx.bumps.course_completion,
+    )?;
+    ctx.accounts.course_completion.lead_share = lead_share;
+    ctx.accounts.course_completion.co_educator_credits = co_educator_credits;
+
+    mint_reward(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.student_token_account.to_account_info(),
+        &ctx.accounts.program_state,
+        amount,
+    )?;
+
+    let payer = ctx.accounts.caller.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let reputation_account = ctx.accounts.educator_reputation.to_account_info();
+    let mut reputation = load_or_create_reputation(
+        &reputation_account,
+        &payer,
+        &system_program,
+        educator_key,
+        ctx.bumps.educator_reputation,
+        ctx.program_id,
+    )?;
+    let new_student = mark_student_taught(
+        &ctx.accounts.educator_student.to_account_info(),
+        &payer,
+        &system_program,
+        educator_key,
+        student,
+        ctx.bumps.educator_student,
+        ctx.program_id,
+        current_time,
+    )?;
+    record_completion(&mut reputation, new_student)?;
+    apply_reputation_tier(
+        &ctx.accounts.program_config,
+        &mut reputation,
+        &mut ctx.accounts.educator,
+        educator_key,
+        current_time,
+    );
+    store_reputation(&reputation_account, &mut reputation, current_time)?;
+
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.status = DisputeStatus::Settled;
+
+    emit!(CourseCompleted {
+        student,
+        course_id: course_id.clone(),
+        educator: educator_key,
+        tokens_awarded: amount,
+        timestamp: current_time,
+    });
+
+    emit!(TokensMinted {
+        recipient: student,
+        amount,
+        minted_by: dispute.key(),
+        timestamp: current_time,
+    });
+
+    emit!(DisputeSettled {
+        dispute: dispute.key(),
+        kind: DisputeKind::MissingCompletion,
+        amount,
+        settled_by: ctx.accounts.caller.key(),
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Dispute settled: {} tokens minted to {} for course {}",
+        amount,
+        student,
+        course_id
+    );
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::states::program::ProgramState;
+use crate::states::config::ProgramConfig;
+use crate::states::educator::EducatorAccount;
+use crate::states::student::StudentInfo;
+use crate::states::course::{Course, CourseCompletion, CourseCoEducators};
+use crate::states::dispute::{Dispute, DisputeKind, DisputeStatus};
+use crate::error::SolLearningError;
+use crate::constants::*;
+
+// Co-taught courses also take the co-educator triples in the remaining accounts
+#[derive(Accounts)]
+pub struct SettleDisputeMint<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(mut, seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(seeds = [CONFIG_SEED], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [DISPUTE_SEED, dispute.course.as_ref(), dispute.student.as_ref(), dispute.opened_by.as_ref()],
+        bump = dispute.bump,
+        constraint = dispute.status == DisputeStatus::Upheld @ SolLearningError::DisputeNotUpheld,
+        constraint = dispute.kind == DisputeKind::MissingCompletion @ SolLearningError::InvalidDispute,
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        address = dispute.course @ SolLearningError::InvalidDispute,
+        constraint = course.is_active @ SolLearningError::CourseInactive,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(mut, address = course.educator @ SolLearningError::InvalidEducator)]
+    pub educator: Account<'info, EducatorAccount>,
+
+    #[account(
+        mut,
+        seeds = [STUDENT_SEED, dispute.student.as_ref()],
+        bump = student_info.bump,
+    )]
+    pub student_info: Account<'info, StudentInfo>,
+
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, dispute.student.as_ref()], bump)]
+    pub student_blocklist: UncheckedAccount<'info>,
+
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, educator.educator_address.as_ref()], bump)]
+    pub educator_blocklist: UncheckedAccount<'info>,
+
+    /// CHECK: checked against program_state.token_mint
+    #[account(mut, address = program_state.token_mint @ SolLearningError::InvalidMint)]
+    pub token_mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = student_token_account.mint == program_state.token_mint @ SolLearningError::InvalidMint,
+        constraint = student_token_account.owner == dispute.student @ SolLearningError::InvalidTokenAccount,
+    )]
+    pub student_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = caller,
+        space = 8 + std::mem::size_of::<CourseCompletion>(),
+        seeds = [COURSE_COMPLETION_SEED, dispute.student.as_ref(), course.course_id.as_bytes()],
+        bump,
+    )]
+    pub course_completion: Account<'info, CourseCompletion>,
+
+    #[account(
+        seeds = [CO_EDUCATORS_SEED, course.key().as_ref()],
+        bump = co_educators.bump,
+    )]
+    pub co_educators: Option<Account<'info, CourseCoEducators>>,
+
+    /// CHECK: EducatorReputation PDA, created on first use
+    #[account(mut, seeds = [EDUCATOR_REPUTATION_SEED, educator.key().as_ref()], bump)]
+    pub educator_reputation: UncheckedAccount<'info>,
+
+    /// CHECK: EducatorStudent PDA, created the first time the educator awards the student
+    #[account(mut, seeds = [EDUCATOR_STUDENT_SEED, educator.key().as_ref(), dispute.student.as_ref()], bump)]
+    pub educator_student: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use crate::states::educator::{
+    EducatorApplicationSubmitted, EducatorApplicationApproved, EducatorApplicationRejected, EducatorRegistered,
+};
+use crate::constants::*;
+use crate::instructions::educator::educator_application_struct::{
+    ApplyAsEducator, ApproveEducatorApplication, RejectEducatorApplication,
+};
+use crate::utils::pause::{check_program_running, check_function_running};
+use crate::utils::educator::{validate_mint_limit, initialize_educator_account, increment_educator_count};
+use crate::utils::blocklist::check_not_blocklisted;
+
+pub fn apply_as_educator_handler(
+    ctx: Context<ApplyAsEducator>,
+    profile_hash: [u8; 32],
+    requested_mint_limit: u64,
+) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_REGISTER)?;
+
+    validate_mint_limit(requested_mint_limit)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    {
+        let application = &mut ctx.accounts.application;
+        application.applicant = ctx.accounts.applicant.key();
+        application.profile_hash = profile_hash;
+        application.requested_mint_limit = requested_mint_limit;
+        application.submitted_at = current_time;
+        application.bump = ctx.bumps.application;
+    }
+
+    emit!(EducatorApplicationSubmitted {
+        applicant: ctx.accounts.applicant.key(),
+        profile_hash,
+        requested_mint_limit,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Educator application submitted by {} requesting mint limit of {}",
+        ctx.accounts.applicant.key(),
+        requested_mint_limit
+    );
+
+    Ok(())
+}
+
+pub fn approve_educator_application_handler(
+    ctx: Context<ApproveEducatorApplication>,
+    mint_limit: Option<u64>,
+) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_REGISTER)?;
+    check_not_blocklisted(&ctx.accounts.applicant_blocklist, ctx.program_id)?;
+
+    let mint_limit = mint_limit.unwrap_or(ctx.accounts.application.requested_mint_limit);
+    validate_mint_limit(mint_limit)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let applicant = ctx.accounts.applicant.key();
+    let authority = ctx.accounts.authority.key();
+
+    initialize_educator_account(
+        &mut ctx.accounts.educator_account,
+        applicant,
+        authority,
+        mint_limit,
+        current_time,
+        ctx.bumps.educator_account,
+    );
+    increment_educator_count(&mut ctx.accounts.program_state)?;
+
+    emit!(EducatorApplicationApproved {
+        applicant,
+        educator_account: ctx.accounts.educator_account.key(),
+        mint_limit,
+        approved_by: authority,
+        timestamp: current_time,
+    });
+
+    emit!(EducatorRegistered {
+        educator: applicant,
+        authority,
+        mint_limit,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Educator application of {} approved with mint limit of {}",
+        applicant,
+        mint_limit
+    );
+
+    Ok(())
+}
+
+pub fn reject_educator_application_handler(ctx: Context<RejectEducatorApplication>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    emit!(EducatorApplicationRejected {
+        applicant: ctx.accounts.applicant.key(),
+        rejected_by: ctx.accounts.authority.key(),
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Educator application of {} rejected by {}",
+        ctx.accounts.applicant.key(),
+        ctx.accounts.authority.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::educator::{EducatorAccount, EducatorApplication};
+use crate::constants::{EDUCATOR_SEED, EDUCATOR_APPLICATION_SEED, BLOCKLIST_SEED};
+use crate::error::SolLearningError;
+
+#[derive(Accounts)]
+pub struct ApplyAsEducator<'info> {
+    #[account(mut)]
+    pub applicant: Signer<'info>,
+
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        init,
+        payer = applicant,
+        space = 8 + std::mem::size_of::<EducatorApplication>(),
+        seeds = [EDUCATOR_APPLICATION_SEED, applicant.key().as_ref()],
+        bump,
+    )]
+    pub application: Account<'info, EducatorApplication>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveEducatorApplication<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == program_state.authority @ SolLearningError::UnauthorizedAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// CHECK: Applicant key, receives the application rent back
+    #[account(mut, address = application.applicant @ SolLearningError::InvalidEducatorApplication)]
+    pub applicant: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = applicant,
+        seeds = [EDUCATOR_APPLICATION_SEED, applicant.key().as_ref()],
+        bump = application.bump,
+    )]
+    pub application: Account<'info, EducatorApplication>,
+
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, applicant.key().as_ref()], bump)]
+    pub applicant_blocklist: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<EducatorAccount>(),
+        seeds = [EDUCATOR_SEED, applicant.key().as_ref()],
+        bump,
+    )]
+    pub educator_account: Account<'info, EducatorAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RejectEducatorApplication<'info> {
+    #[account(
+        constraint = authority.key() == program_state.authority @ SolLearningError::UnauthorizedAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    pub program_state: Account<'info, ProgramState>,
+
+    /// CHECK: Applicant key, receives the application rent back
+    #[account(mut, address = application.applicant @ SolLearningError::InvalidEducatorApplication)]
+    pub applicant: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = applicant,
+        seeds = [EDUCATOR_APPLICATION_SEED, applicant.key().as_ref()],
+        bump = application.bump,
+    )]
+    pub application: Account<'info, EducatorApplication>,
+}
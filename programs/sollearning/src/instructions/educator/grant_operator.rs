@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::states::educator::{EducatorOperator, OperatorGranted};
+use crate::constants::*;
+use crate::error::SolLearningError;
+use crate::instructions::educator::grant_operator_struct::GrantOperator;
+use crate::utils::pause::check_program_running;
+
+pub fn grant_operator_handler(
+    ctx: Context<GrantOperator>,
+    operator: Pubkey,
+    permissions: u8,
+    mint_allowance: u64,
+    expires_at: i64,
+) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    validate_grant(operator, ctx.accounts.educator.educator_address, permissions, mint_allowance, expires_at, current_time)?;
+
+    initialize_operator(
+        &mut ctx.accounts.operator_account,
+        ctx.accounts.educator.key(),
+        operator,
+        permissions,
+        mint_allowance,
+        expires_at,
+        current_time,
+        ctx.bumps.operator_account,
+    );
+
+    emit!(OperatorGranted {
+        educator: ctx.accounts.educator.key(),
+        operator,
+        permissions,
+        mint_allowance,
+        expires_at,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Educator {} granted operator {} permissions 0x{:02X} until {}",
+        ctx.accounts.educator.key(),
+        operator,
+        permissions,
+        expires_at
+    );
+
+    Ok(())
+}
+
+fn validate_grant(
+    operator: Pubkey,
+    educator_address: Pubkey,
+    permissions: u8,
+    mint_allowance: u64,
+    expires_at: i64,
+    current_time: i64,
+) -> Result<()> {
+    require!(operator != educator_address, SolLearningError::InvalidOperatorPermissions);
+    require!(
+        permissions != 0 && permissions & !OPERATOR_PERMISSION_ALL == 0,
+        SolLearningError::InvalidOperatorPermissions
+    );
+    require!(
+        permissions & OPERATOR_PERMISSION_VERIFY_COMPLETION == 0 || mint_allowance > 0,
+        SolLearningError::InvalidAmount
+    );
+    require!(expires_at > current_time, SolLearningError::OperatorExpired);
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn initialize_operator(
+    operator_account: &mut Account<EducatorOperator>,
+    educator: Pubkey,
+    operator: Pubkey,
+    permissions: u8,
+    mint_allowance: u64,
+    expires_at: i64,
+    current_time: i64,
+    bump: u8,
+) {
+    operator_account.educator = educator;
+    operator_account.operator = operator;
+    operator_account.permissions = permissions;
+    operator_account.mint_allowance = mint_allowance;
+    operator_account.total_minted = 0;
+    operator_account.expires_at = expires_at;
+    operator_account.granted_at = current_time;
+    operator_account.bump = bump;
+}
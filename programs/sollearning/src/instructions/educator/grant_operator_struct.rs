@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::educator::{EducatorAccount, EducatorOperator};
+use crate::constants::OPERATOR_SEED;
+use crate::error::SolLearningError;
+
+#[derive(Accounts)]
+#[instruction(operator: Pubkey)]
+pub struct GrantOperator<'info> {
+    #[account(mut)]
+    pub educator_authority: Signer<'info>,
+
+    #[account(
+        constraint = educator.educator_address == educator_authority.key() @ SolLearningError::Unauthorized,
+    )]
+    pub educator: Account<'info, EducatorAccount>,
+
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        init,
+        payer = educator_authority,
+        space = 8 + std::mem::size_of::<EducatorOperator>(),
+        seeds = [OPERATOR_SEED, educator.key().as_ref(), operator.as_ref()],
+        bump,
+    )]
+    pub operator_account: Account<'info, EducatorOperator>,
+
+    pub system_program: Program<'info, System>,
+}
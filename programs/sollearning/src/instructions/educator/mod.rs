@@ -1,7 +1,26 @@
 pub mod register_educator;
 pub mod set_educator_status;
+pub mod grant_operator;
+pub mod revoke_operator;
+pub mod rotate_educator_key;
+pub mod educator_application;
+pub mod reputation_tier;
 pub mod register_educator_struct;
 pub mod set_educator_status_struct;
+pub mod grant_operator_struct;
+pub mod revoke_operator_struct;
+pub mod rotate_educator_key_struct;
+pub mod educator_application_struct;
+pub mod reputation_tier_struct;
 
 pub use register_educator::register_educator_handler;
 pub use set_educator_status::set_educator_status_handler;
+pub use grant_operator::grant_operator_handler;
+pub use revoke_operator::revoke_operator_handler;
+pub use rotate_educator_key::{
+    request_educator_key_rotation_handler, cancel_educator_key_rotation_handler, rotate_educator_key_handler,
+};
+pub use educator_application::{
+    apply_as_educator_handler, approve_educator_application_handler, reject_educator_application_handler,
+};
+pub use reputation_tier::refresh_educator_tier_handler;
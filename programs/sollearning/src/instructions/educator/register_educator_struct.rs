@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::states::program::ProgramState;
 use crate::states::educator::EducatorAccount;
-use crate::constants::EDUCATOR_SEED;
+use crate::constants::{EDUCATOR_SEED, BLOCKLIST_SEED};
 
 #[derive(Accounts)]
 #[instruction(mint_limit: u64)]
@@ -15,6 +15,10 @@ pub struct RegisterEducator<'info> {
     /// CHECK: This is the token account of the student that will receive the tokens
     pub educator: AccountInfo<'info>,
 
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, educator.key().as_ref()], bump)]
+    pub educator_blocklist: UncheckedAccount<'info>,
+
     #[account(
         init,
         payer = authority,
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::error::SolLearningError;
+use crate::constants::PAUSE_FLAG_REGISTER;
+use crate::instructions::educator::reputation_tier_struct::RefreshEducatorTier;
+use crate::utils::pause::{check_program_running, check_function_running};
+use crate::utils::reputation::apply_reputation_tier;
+
+// Permissionless, so tier changes from disputes, ratings or new tier settings
+// apply without waiting for the educator's next mint.
+pub fn refresh_educator_tier_handler(ctx: Context<RefreshEducatorTier>) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_REGISTER)?;
+    require!(
+        ctx.accounts.program_config.reputation_tier_count > 0,
+        SolLearningError::ReputationTiersNotSet
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let educator_key = ctx.accounts.educator.key();
+    apply_reputation_tier(
+        &ctx.accounts.program_config,
+        &mut ctx.accounts.educator_reputation,
+        &mut ctx.accounts.educator,
+        educator_key,
+        current_time,
+    );
+
+    msg!(
+        "Educator {} is in reputation tier {} with a mint limit of {}",
+        educator_key,
+        ctx.accounts.educator_reputation.tier,
+        ctx.accounts.educator.mint_limit
+    );
+
+    Ok(())
+}
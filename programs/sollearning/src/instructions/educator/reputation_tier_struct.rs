@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::config::ProgramConfig;
+use crate::states::educator::EducatorAccount;
+use crate::states::reputation::EducatorReputation;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct RefreshEducatorTier<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(seeds = [CONFIG_SEED], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub educator: Account<'info, EducatorAccount>,
+
+    #[account(
+        mut,
+        seeds = [EDUCATOR_REPUTATION_SEED, educator.key().as_ref()],
+        bump = educator_reputation.bump,
+    )]
+    pub educator_reputation: Account<'info, EducatorReputation>,
+}
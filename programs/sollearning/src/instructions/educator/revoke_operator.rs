@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use crate::states::educator::OperatorRevoked;
+use crate::instructions::educator::revoke_operator_struct::RevokeOperator;
+
+pub fn revoke_operator_handler(ctx: Context<RevokeOperator>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let operator = ctx.accounts.operator_account.operator;
+
+    emit!(OperatorRevoked {
+        educator: ctx.accounts.educator.key(),
+        operator,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Educator {} revoked operator {}",
+        ctx.accounts.educator.key(),
+        operator
+    );
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::states::educator::{EducatorAccount, EducatorOperator};
+use crate::constants::OPERATOR_SEED;
+use crate::error::SolLearningError;
+
+#[derive(Accounts)]
+pub struct RevokeOperator<'info> {
+    #[account(mut)]
+    pub educator_authority: Signer<'info>,
+
+    #[account(
+        constraint = educator.educator_address == educator_authority.key() @ SolLearningError::Unauthorized,
+    )]
+    pub educator: Account<'info, EducatorAccount>,
+
+    #[account(
+        mut,
+        close = educator_authority,
+        seeds = [OPERATOR_SEED, educator.key().as_ref(), operator_account.operator.as_ref()],
+        bump = operator_account.bump,
+        constraint = operator_account.educator == educator.key() @ SolLearningError::Unauthorized,
+    )]
+    pub operator_account: Account<'info, EducatorOperator>,
+}
@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use crate::states::educator::{
+    EducatorAccount, EducatorKeyRotation, EducatorKeyRotationRequested, EducatorKeyRotationCancelled, EducatorKeyRotated,
+};
+use crate::constants::*;
+use crate::error::SolLearningError;
+use crate::instructions::educator::rotate_educator_key_struct::{
+    RequestEducatorKeyRotation, CancelEducatorKeyRotation, RotateEducatorKey,
+};
+use crate::utils::pause::check_program_running;
+
+// The EducatorAccount PDA keeps the seeds it was registered with, so courses,
+// operators and history seeded by its address survive the rotation untouched.
+pub fn request_educator_key_rotation_handler(
+    ctx: Context<RequestEducatorKeyRotation>,
+    new_educator_address: Pubkey,
+    new_authority: Option<Pubkey>,
+) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+
+    let educator_account = &ctx.accounts.educator_account;
+    require!(
+        new_educator_address != educator_account.educator_address,
+        SolLearningError::InvalidKeyRotation
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let executable_at = current_time
+        .checked_add(EDUCATOR_KEY_ROTATION_DELAY)
+        .ok_or(SolLearningError::Overflow)?;
+    let new_authority = new_authority.unwrap_or(educator_account.authority);
+    let current_address = educator_account.educator_address;
+
+    initialize_key_rotation(
+        &mut ctx.accounts.key_rotation,
+        ctx.accounts.educator_account.key(),
+        new_educator_address,
+        new_authority,
+        ctx.accounts.authority.key(),
+        current_time,
+        executable_at,
+        ctx.bumps.key_rotation,
+    );
+
+    emit!(EducatorKeyRotationRequested {
+        educator: ctx.accounts.educator_account.key(),
+        current_address,
+        new_educator_address,
+        new_authority,
+        requested_by: ctx.accounts.authority.key(),
+        executable_at,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Key rotation requested for educator {}: {} -> {}, executable at {}",
+        ctx.accounts.educator_account.key(),
+        current_address,
+        new_educator_address,
+        executable_at
+    );
+
+    Ok(())
+}
+
+pub fn cancel_educator_key_rotation_handler(ctx: Context<CancelEducatorKeyRotation>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    emit!(EducatorKeyRotationCancelled {
+        educator: ctx.accounts.educator_account.key(),
+        cancelled_by: ctx.accounts.canceller.key(),
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Key rotation for educator {} cancelled by {}",
+        ctx.accounts.educator_account.key(),
+        ctx.accounts.canceller.key()
+    );
+
+    Ok(())
+}
+
+pub fn rotate_educator_key_handler(ctx: Context<RotateEducatorKey>) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time >= ctx.accounts.key_rotation.executable_at,
+        SolLearningError::KeyRotationTimelockActive
+    );
+
+    let previous_address = ctx.accounts.educator_account.educator_address;
+    let previous_authority = ctx.accounts.educator_account.authority;
+    let new_educator_address = ctx.accounts.key_rotation.new_educator_address;
+    let new_authority = ctx.accounts.key_rotation.new_authority;
+
+    apply_key_rotation(
+        &mut ctx.accounts.educator_account,
+        new_educator_address,
+        new_authority,
+        current_time,
+    );
+
+    emit!(EducatorKeyRotated {
+        educator: ctx.accounts.educator_account.key(),
+        previous_address,
+        new_educator_address,
+        previous_authority,
+        new_authority,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Educator {} key rotated from {} to {}",
+        ctx.accounts.educator_account.key(),
+        previous_address,
+        new_educator_address
+    );
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn initialize_key_rotation(
+    key_rotation: &mut Account<EducatorKeyRotation>,
+    educator: Pubkey,
+    new_educator_address: Pubkey,
+    new_authority: Pubkey,
+    requested_by: Pubkey,
+    requested_at: i64,
+    executable_at: i64,
+    bump: u8,
+) {
+    key_rotation.educator = educator;
+    key_rotation.new_educator_address = new_educator_address;
+    key_rotation.new_authority = new_authority;
+    key_rotation.requested_by = requested_by;
+    key_rotation.requested_at = requested_at;
+    key_rotation.executable_at = executable_at;
+    key_rotation.bump = bump;
+}
+
+fn apply_key_rotation(
+    educator_account: &mut Account<EducatorAccount>,
+    new_educator_address: Pubkey,
+    new_authority: Pubkey,
+    current_time: i64,
+) {
+    educator_account.educator_address = new_educator_address;
+    educator_account.authority = new_authority;
+    educator_account.last_updated_at = current_time;
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::educator::{EducatorAccount, EducatorKeyRotation};
+use crate::constants::EDUCATOR_KEY_ROTATION_SEED;
+use crate::error::SolLearningError;
+
+#[derive(Accounts)]
+pub struct RequestEducatorKeyRotation<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == program_state.authority @ SolLearningError::UnauthorizedAuthority
+    )]
+    pub authority: Signer<'info>,
+
+    pub program_state: Account<'info, ProgramState>,
+
+    pub educator_account: Account<'info, EducatorAccount>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<EducatorKeyRotation>(),
+        seeds = [EDUCATOR_KEY_ROTATION_SEED, educator_account.key().as_ref()],
+        bump,
+    )]
+    pub key_rotation: Account<'info, EducatorKeyRotation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelEducatorKeyRotation<'info> {
+    #[account(
+        constraint = canceller.key() == program_state.authority
+            || canceller.key() == educator_account.educator_address @ SolLearningError::Unauthorized
+    )]
+    pub canceller: Signer<'info>,
+
+    pub program_state: Account<'info, ProgramState>,
+
+    pub educator_account: Account<'info, EducatorAccount>,
+
+    #[account(
+        mut,
+        close = requester,
+        seeds = [EDUCATOR_KEY_ROTATION_SEED, educator_account.key().as_ref()],
+        bump = key_rotation.bump,
+    )]
+    pub key_rotation: Account<'info, EducatorKeyRotation>,
+
+    /// CHECK: Receives the rent of the rotation request, must be its original payer
+    #[account(mut, address = key_rotation.requested_by @ SolLearningError::Unauthorized)]
+    pub requester: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RotateEducatorKey<'info> {
+    #[account(
+        constraint = new_educator.key() == key_rotation.new_educator_address @ SolLearningError::InvalidKeyRotation
+    )]
+    pub new_educator: Signer<'info>,
+
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut)]
+    pub educator_account: Account<'info, EducatorAccount>,
+
+    #[account(
+        mut,
+        close = requester,
+        seeds = [EDUCATOR_KEY_ROTATION_SEED, educator_account.key().as_ref()],
+        bump = key_rotation.bump,
+    )]
+    pub key_rotation: Account<'info, EducatorKeyRotation>,
+
+    /// CHECK: Receives the rent of the rotation request, must be its original payer
+    #[account(mut, address = key_rotation.requested_by @ SolLearningError::Unauthorized)]
+    pub requester: AccountInfo<'info>,
+}
@@ -1,14 +1,19 @@
 use anchor_lang::prelude::*;
-use crate::states::educator::EducatorAccount;
 use crate::constants::*;
-use crate::error::SolLearningError;
 use crate::instructions::educator::set_educator_status_struct::SetEducatorStatus;
+use crate::utils::access::check_admin;
+use crate::utils::pause::{check_program_running, check_function_running};
+use crate::utils::educator::update_educator_status;
 
 pub fn set_educator_status_handler(
     ctx: Context<SetEducatorStatus>,
     is_active: bool,
     new_mint_limit: Option<u64>,
 ) -> Result<()> {
+    check_admin(&ctx.accounts.program_state, &ctx.accounts.authority)?;
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_REGISTER)?;
+
     let current_time = Clock::get()?.unix_timestamp;
 
     update_educator_status(&mut ctx.accounts.educator_account, is_active, new_mint_limit, current_time)?;
@@ -18,23 +23,6 @@ pub fn set_educator_status_handler(
     Ok(())
 }
 
-fn update_educator_status(
-    educator_account: &mut Account<EducatorAccount>,
-    is_active: bool,
-    new_mint_limit: Option<u64>,
-    current_time: i64,
-) -> Result<()> {
-    educator_account.is_active = is_active;
-
-    if let Some(mint_limit) = new_mint_limit {
-        require!(mint_limit > 0 && mint_limit <= MAX_MINT_AMOUNT, SolLearningError::InvalidAmount);
-        educator_account.mint_limit = mint_limit;
-    }
-
-    educator_account.last_updated_at = current_time;
-    Ok(())
-}
-
 fn log_status_update(ctx: &Context<SetEducatorStatus>, is_active: bool, new_mint_limit: Option<u64>) {
     let mint_limit_msg = match new_mint_limit {
         Some(limit) => format!(", new mint limit: {}", limit),
@@ -1,16 +1,30 @@
 use anchor_lang::prelude::*;
 use crate::states::program::{ProgramState, ProgramStatusChanged, ProgramGranularPauseChanged};
+use crate::states::emergency::{PauseRecord, PauseReason, ProgramPaused, ProgramResumed};
+use crate::error::SolLearningError;
 use crate::instructions::emergency::emergency_toggle_struct::{EmergencyToggle, EmergencyToggleGranular, EmergencyMultisigToggle};
-use crate::utils::pause::check_authority;
-use crate::constants::*;
+use crate::utils::access::check_pauser;
+use crate::utils::pause::{set_program_paused, record_pause};
 
-pub fn emergency_toggle_handler(ctx: Context<EmergencyToggle>, paused: bool) -> Result<()> {
+// Pausing requires a reason, which is kept in the pause record until the next
+// pause; it is ignored when resuming.
+pub fn emergency_toggle_handler(
+    ctx: Context<EmergencyToggle>,
+    paused: bool,
+    reason: Option<PauseReason>,
+) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp;
     
-    // Explicit authority check
-    check_authority(&ctx.accounts.program_state, &ctx.accounts.authority)?;
+    check_pauser(&ctx.accounts.program_state, None, &ctx.accounts.authority)?;
     
-    update_program_status(&mut ctx.accounts.program_state, paused)?;
+    update_program_status(
+        &mut ctx.accounts.program_state,
+        &mut ctx.accounts.pause_record,
+        paused,
+        reason,
+        ctx.accounts.authority.key(),
+        current_time,
+    )?;
     
     emit_status_change(&ctx, paused, current_time)?;
     
@@ -30,8 +44,7 @@ pub fn emergency_toggle_granular_handler(
 ) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp;
     
-    // Explicit authority check
-    check_authority(&ctx.accounts.program_state, &ctx.accounts.authority)?;
+    check_pauser(&ctx.accounts.program_state, None, &ctx.accounts.authority)?;
     
     let new_flags = update_pause_flags(
         &mut ctx.accounts.program_state,
@@ -53,11 +66,25 @@ pub fn emergency_toggle_granular_handler(
 
 pub fn emergency_multisig_toggle_handler(
     ctx: Context<EmergencyMultisigToggle>,
-    paused: bool
+    paused: bool,
+    reason: Option<PauseReason>,
 ) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp;
+
+    check_pauser(
+        &ctx.accounts.program_state,
+        Some(&ctx.accounts.emergency_multisig),
+        &ctx.accounts.multisig_authority,
+    )?;
     
-    update_program_status(&mut ctx.accounts.program_state, paused)?;
+    update_program_status(
+        &mut ctx.accounts.program_state,
+        &mut ctx.accounts.pause_record,
+        paused,
+        reason,
+        ctx.accounts.multisig_authority.key(),
+        current_time,
+    )?;
     
     emit!(ProgramStatusChanged {
         paused,
@@ -74,16 +101,36 @@ pub fn emergency_multisig_toggle_handler(
     Ok(())
 }
 
-fn update_program_status(program_state: &mut Account<ProgramState>, paused: bool) -> Result<()> {
-    program_state.paused = paused;
-    
-    // If pausing the program, also set all function flags
+fn update_program_status(
+    program_state: &mut Account<ProgramState>,
+    pause_record: &mut Account<PauseRecord>,
+    paused: bool,
+    reason: Option<PauseReason>,
+    authority: Pubkey,
+    current_time: i64,
+) -> Result<()> {
     if paused {
-        program_state.pause_flags = PAUSE_FLAG_ALL;
+        let reason = reason.ok_or(SolLearningError::PauseReasonRequired)?;
+        record_pause(pause_record, &reason, authority, program_state.paused, current_time)?;
+
+        emit!(ProgramPaused {
+            reason_code: reason.code,
+            reason_hash: reason.text_hash,
+            resume_after: pause_record.resume_after,
+            paused_by: authority,
+            timestamp: current_time,
+        });
     } else {
-        program_state.pause_flags = 0;
+        pause_record.resumed_at = Some(current_time);
+
+        emit!(ProgramResumed {
+            resumed_by: authority,
+            scheduled: false,
+            timestamp: current_time,
+        });
     }
-    
+
+    set_program_paused(program_state, pause_record, paused);
     Ok(())
 }
 
@@ -1,25 +1,23 @@
 use anchor_lang::prelude::*;
 use crate::states::program::ProgramState;
-use crate::error::SolLearningError;
+use crate::states::emergency::PauseRecord;
+use crate::constants::PAUSE_RECORD_SEED;
 
 #[derive(Accounts)]
 pub struct EmergencyToggle<'info> {
-    #[account(
-        mut,
-        constraint = authority.key() == program_state.authority @ SolLearningError::UnauthorizedAuthority
-    )]
+    #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(mut)]
     pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [PAUSE_RECORD_SEED], bump = pause_record.bump)]
+    pub pause_record: Account<'info, PauseRecord>,
 }
 
 #[derive(Accounts)]
 pub struct EmergencyToggleGranular<'info> {
-    #[account(
-        mut,
-        constraint = authority.key() == program_state.authority @ SolLearningError::UnauthorizedAuthority
-    )]
+    #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(mut)]
@@ -33,9 +31,9 @@ pub struct EmergencyMultisigToggle<'info> {
 
     #[account(mut)]
     pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [PAUSE_RECORD_SEED], bump = pause_record.bump)]
+    pub pause_record: Account<'info, PauseRecord>,
     
-    #[account(
-        constraint = emergency_multisig.signers.contains(&multisig_authority.key()) @ SolLearningError::Unauthorized
-    )]
     pub emergency_multisig: Account<'info, crate::states::emergency::EmergencyMultisig>,
 }
\ No newline at end of file
@@ -1,4 +1,7 @@
 pub mod emergency_toggle;
+pub mod pause_schedule;
 pub mod emergency_toggle_struct;
+pub mod pause_schedule_struct;
 
 pub use emergency_toggle::emergency_toggle_handler;
+pub use pause_schedule::resume_if_due_handler;
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::states::emergency::ProgramResumed;
+use crate::error::SolLearningError;
+use crate::instructions::emergency::pause_schedule_struct::ResumeIfDue;
+use crate::utils::pause::set_program_paused;
+
+// Anyone may lift a pause once its scheduled resume time has passed. A pause
+// without a resume time, or one extended indefinitely, is never due. Only
+// governance can extend a pause (ProposalInstruction::ExtendPause).
+pub fn resume_if_due_handler(ctx: Context<ResumeIfDue>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(ctx.accounts.program_state.paused, SolLearningError::PauseNotDue);
+
+    let pause_record = &mut ctx.accounts.pause_record;
+    let is_due = pause_record
+        .resume_after
+        .map(|resume_after| current_time >= resume_after)
+        .unwrap_or(false);
+    require!(is_due, SolLearningError::PauseNotDue);
+
+    set_program_paused(&mut ctx.accounts.program_state, pause_record, false);
+    pause_record.resumed_at = Some(current_time);
+
+    emit!(ProgramResumed {
+        resumed_by: ctx.accounts.caller.key(),
+        scheduled: true,
+        timestamp: current_time,
+    });
+
+    msg!("Program resumed on schedule by {}", ctx.accounts.caller.key());
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::emergency::PauseRecord;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct ResumeIfDue<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(mut, seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [PAUSE_RECORD_SEED], bump = pause_record.bump)]
+    pub pause_record: Account<'info, PauseRecord>,
+}
@@ -25,6 +25,8 @@ pub fn initialize_handler(ctx: Context<InitializeAccounts>) -> Result<()> {
         config_bump
     )?;
 
+    ctx.accounts.pause_record.bump = ctx.bumps.pause_record;
+
     create_authority_token_account(&ctx)?;
 
     mint_initial_supply(&ctx, program_bump)?;
@@ -51,6 +53,8 @@ fn initialize_program_state(
     program_state.educator_count = 0;
     program_state.paused = false;
     program_state.pause_flags = 0;
+    program_state.mint_window_start_slot = 0;
+    program_state.mint_window_minted = 0;
     program_state.bump = bump;
     Ok(())
 }
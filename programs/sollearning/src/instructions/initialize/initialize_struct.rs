@@ -3,6 +3,7 @@ use anchor_spl::token::{Mint, Token};
 use anchor_spl::associated_token::AssociatedToken;
 use crate::states::program::ProgramState;
 use crate::states::config::ProgramConfig;
+use crate::states::emergency::PauseRecord;
 use crate::constants::*;
 
 #[derive(Accounts)]
@@ -31,7 +32,18 @@ pub struct InitializeAccounts<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + 82,
+        space = 8 + std::mem::size_of::<PauseRecord>(),
+        seeds = [PAUSE_RECORD_SEED],
+        bump,
+    )]
+    pub pause_record: Account<'info, PauseRecord>,
+
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = TOKEN_DECIMALS,
+        mint::authority = program_state,
+        mint::freeze_authority = program_state,
     )]
     pub token_mint: Account<'info, Mint>,
 
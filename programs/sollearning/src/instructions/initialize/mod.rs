@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod initialize;
 pub mod initialize_struct;
 
@@ -7,6 +7,9 @@ pub mod emergency;
 pub mod token;
 pub mod config;
 pub mod initialize;
+pub mod voting;
+pub mod blocklist;
+pub mod dispute;
 
 pub use educator::*;
 pub use student::*;
@@ -17,3 +20,6 @@ pub use emergency::*;
 pub use token::*;
 pub use config::*;
 pub use initialize::*;
+pub use voting::*;
+pub use blocklist::*;
+pub use dispute::*;
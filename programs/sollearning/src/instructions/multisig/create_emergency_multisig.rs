@@ -4,7 +4,8 @@ use crate::error::SolLearningError;
 use crate::constants::*;
 use std::collections::HashSet;
 use crate::instructions::multisig::create_emergency_multisig_struct::CreateEmergencyMultisig;
-use crate::utils::pause::{check_program_running, check_authority};
+use crate::utils::pause::check_program_running;
+use crate::utils::access::check_admin;
 
 pub fn create_emergency_multisig_handler(
     ctx: Context<CreateEmergencyMultisig>,
@@ -12,7 +13,7 @@ pub fn create_emergency_multisig_handler(
     threshold: u8,
 ) -> Result<()> {
     check_program_running(&ctx.accounts.program_state)?;
-    check_authority(&ctx.accounts.program_state, &ctx.accounts.authority)?;
+    check_admin(&ctx.accounts.program_state, &ctx.accounts.authority)?;
     
     validate_multisig(&signers, threshold)?;
     validate_unique_signers(&signers)?;
@@ -2,15 +2,11 @@ use anchor_lang::prelude::*;
 use crate::states::program::ProgramState;
 use crate::states::emergency::EmergencyMultisig;
 use crate::constants::*;
-use crate::error::SolLearningError;
 
 #[derive(Accounts)]
 #[instruction(signers: Vec<Pubkey>, threshold: u8)]
 pub struct CreateEmergencyMultisig<'info> {
-    #[account(
-        mut,
-        constraint = authority.key() == program_state.authority @ SolLearningError::UnauthorizedAuthority
-    )]
+    #[account(mut)]
     pub authority: Signer<'info>,
 
     pub program_state: Account<'info, ProgramState>,
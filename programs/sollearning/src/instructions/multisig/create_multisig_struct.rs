@@ -1,17 +1,22 @@
 use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
 use crate::states::signers::Multisig;
 use crate::constants::*;
 
+// The root governance multisig: empty label, full scope.
 #[derive(Accounts)]
 #[instruction(signers: Vec<Pubkey>, threshold: u8)]
 pub struct CreateMultisig<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
     #[account(
         init,
         payer = authority,
-        space = calculate_multisig_space(&signers), 
+        space = calculate_multisig_space(&signers, ""), 
         seeds = [MULTISIG_SEED],
         bump,
     )]
@@ -20,6 +25,6 @@ pub struct CreateMultisig<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn calculate_multisig_space(signers: &[Pubkey]) -> usize {
-    8 + 4 + (signers.len() * 32) + 1 + 8 + 32 + 1
+pub fn calculate_multisig_space(signers: &[Pubkey], label: &str) -> usize {
+    8 + 4 + (signers.len() * 32) + 1 + 8 + 32 + 4 + label.len() + 4 + 8 + 1
 }
@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
 use crate::states::signers::Multisig;
-use crate::states::proposal::{Proposal, ProposalStatus, ProposalApproved};
+use crate::states::proposal::{Proposal, ProposalApproved};
 use crate::error::SolLearningError;
-use crate::constants::*;
 use crate::instructions::proposal::approve_proposal_struct::ApproveProposal;
+use crate::utils::access::check_governance;
+use crate::utils::pause::check_governance_running;
+use crate::utils::governance::{check_proposal_membership, check_proposal_not_expired};
 
 pub fn approve_proposal_handler(ctx: Context<ApproveProposal>) -> Result<()> {
     let proposal: &mut Account<'_, Proposal> = &mut ctx.accounts.proposal;
@@ -12,7 +14,10 @@ pub fn approve_proposal_handler(ctx: Context<ApproveProposal>) -> Result<()> {
 
     let current_time: i64 = Clock::get()?.unix_timestamp;
 
-    check_proposal_expiration(proposal, current_time)?;
+    check_governance(multisig, &ctx.accounts.signer)?;
+    check_governance_running(&ctx.accounts.program_state, &proposal.instruction)?;
+    check_proposal_membership(multisig, proposal)?;
+    check_proposal_not_expired(proposal, current_time)?;
     let signer_index: usize = find_signer_index(multisig, signer_key)?;
 
     validate_approval(proposal, signer_index)?;
@@ -30,15 +35,6 @@ pub fn approve_proposal_handler(ctx: Context<ApproveProposal>) -> Result<()> {
     Ok(())
 }
 
-fn check_proposal_expiration(proposal: &mut Account<Proposal>, current_time: i64) -> Result<()> {
-    if current_time - proposal.created_at > PROPOSAL_EXPIRATION_TIME {
-        proposal.status = ProposalStatus::Expired;
-        proposal.closed_at = Some(current_time);
-        return Err(SolLearningError::ProposalExpired.into());
-    }
-    Ok(())
-}
-
 fn find_signer_index(multisig: &Account<Multisig>, signer_key: Pubkey) -> Result<usize> {
     multisig
         .signers
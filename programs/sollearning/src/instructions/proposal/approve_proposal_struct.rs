@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::states::signers::Multisig;
 use crate::states::proposal::{Proposal, ProposalStatus};
+use crate::states::program::ProgramState;
 use crate::error::SolLearningError;
 use crate::constants::*;
 
@@ -10,7 +11,7 @@ pub struct ApproveProposal<'info> {
     pub signer: Signer<'info>,
 
     #[account(
-        seeds = [MULTISIG_SEED],
+        seeds = [MULTISIG_SEED, multisig.label.as_bytes()],
         bump = multisig.bump,
         constraint = multisig.signers.contains(&signer.key()) @ SolLearningError::Unauthorized,
     )]
@@ -24,4 +25,7 @@ pub struct ApproveProposal<'info> {
         constraint = proposal.status == ProposalStatus::Active @ SolLearningError::InvalidProposalStatus,
     )]
     pub proposal: Account<'info, Proposal>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
 }
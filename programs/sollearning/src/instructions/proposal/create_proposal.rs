@@ -4,18 +4,37 @@ use crate::states::proposal::{Proposal, ProposalStatus, ProposalInstruction, Pro
 use crate::error::SolLearningError;
 use crate::constants::*;
 use crate::instructions::proposal::create_proposal_struct::CreateProposal;
+use crate::utils::access::check_governance;
+use crate::utils::pause::check_governance_running;
+use crate::utils::governance::{
+    check_proposal_scope, validate_custom_instruction, resolve_proposal_expiry, resolve_proposal_threshold,
+};
 
 pub fn create_proposal_handler(
     ctx: Context<CreateProposal>,
     instruction: ProposalInstruction,
     description: String,
+    expiry: Option<i64>,
 ) -> Result<()> {
+    check_governance(&ctx.accounts.multisig, &ctx.accounts.proposer)?;
+    check_governance_running(&ctx.accounts.program_state, &instruction)?;
     validate_description(&description)?;
+    check_proposal_scope(&ctx.accounts.multisig, &instruction)?;
+    validate_custom_instruction(&instruction, &ctx.accounts.multisig.key(), ctx.program_id)?;
 
     let multisig: &mut Account<'_, Multisig> = &mut ctx.accounts.multisig;
     let proposal_index: u64 = increment_proposal_count(multisig)?;
     let current_time: i64 = Clock::get()?.unix_timestamp;
     let bump = get_proposal_bump(ctx.program_id, multisig.key(), proposal_index)?;
+    let config = &ctx.accounts.program_config;
+    let delay = config.proposal_delays[instruction.kind()];
+    let executable_at = current_time
+        .checked_add(delay)
+        .ok_or(SolLearningError::Overflow)?;
+    let expires_at = current_time
+        .checked_add(resolve_proposal_expiry(config, expiry, delay)?)
+        .ok_or(SolLearningError::Overflow)?;
+    let threshold = resolve_proposal_threshold(config, multisig, &instruction);
 
     initialize_proposal(
         &mut ctx.accounts.proposal,
@@ -26,7 +45,11 @@ pub fn create_proposal_handler(
         &description,
         bump,
         current_time,
+        executable_at,
+        expires_at,
+        threshold,
         &multisig.signers,
+        multisig.membership_version,
     )?;
 
     emit_proposal_created(
@@ -35,6 +58,9 @@ pub fn create_proposal_handler(
         ctx.accounts.proposer.key(),
         instruction,
         &description,
+        executable_at,
+        expires_at,
+        threshold,
         current_time,
     )?;
 
@@ -77,6 +103,7 @@ fn get_proposal_bump(program_id: &Pubkey, multisig_key: Pubkey, proposal_index:
 }
 
 // Initializes the proposal account
+#[allow(clippy::too_many_arguments)]
 fn initialize_proposal(
     proposal: &mut Account<Proposal>,
     multisig_key: Pubkey,
@@ -86,27 +113,39 @@ fn initialize_proposal(
     description: &str,
     bump: u8,
     timestamp: i64,
+    executable_at: i64,
+    expires_at: i64,
+    threshold: u8,
     signers: &[Pubkey],
+    membership_version: u64,
 ) -> Result<()> {
     proposal.multisig = multisig_key;
     proposal.index = index;
     proposal.instruction = instruction;
     proposal.status = ProposalStatus::Active;
     proposal.created_at = timestamp;
+    proposal.executable_at = executable_at;
+    proposal.expires_at = expires_at;
+    proposal.threshold = threshold;
     proposal.closed_at = None;
     proposal.description = description.to_string();
     proposal.bump = bump;
     proposal.signers = signers.iter().map(|&signer| signer == proposer_key).collect();
+    proposal.membership_version = membership_version;
     Ok(())
 }
 
 // Emits the event for proposal creation
+#[allow(clippy::too_many_arguments)]
 fn emit_proposal_created(
     multisig_key: Pubkey,
     proposal_key: Pubkey,
     proposer_key: Pubkey,
     instruction: ProposalInstruction,
     description: &str,
+    executable_at: i64,
+    expires_at: i64,
+    threshold: u8,
     timestamp: i64,
 ) -> Result<()> {
     emit!(ProposalCreated {
@@ -115,6 +154,9 @@ fn emit_proposal_created(
         proposer: proposer_key,
         instruction,
         description: description.to_string(),
+        executable_at,
+        expires_at,
+        threshold,
         timestamp,
     });
     Ok(())
@@ -2,29 +2,54 @@ use anchor_lang::prelude::*;
 use crate::states::signers::Multisig;
 use crate::states::proposal::Proposal;
 use crate::states::proposal::ProposalInstruction;
-use crate::constants::PROPOSAL_SEED;
+use crate::states::config::ProgramConfig;
+use crate::states::program::ProgramState;
+use crate::constants::{PROPOSAL_SEED, CONFIG_SEED, MULTISIG_SEED, PROGRAM_STATE_SEED};
 
 #[derive(Accounts)]
-#[instruction(instruction: ProposalInstruction, description: String)]
+#[instruction(instruction: ProposalInstruction, description: String, expiry: Option<i64>)]
 pub struct CreateProposal<'info> {
     #[account(mut)]
     pub proposer: Signer<'info>,
 
-    #[account(mut)]
+    #[account(mut, seeds = [MULTISIG_SEED, multisig.label.as_bytes()], bump = multisig.bump)]
     pub multisig: Account<'info, Multisig>,
 
     #[account(
         init,
         payer = proposer,
-        space = calculate_proposal_space(&description, multisig.signers.len()), 
+        space = calculate_proposal_space(&instruction, &description, multisig.signers.len()), 
         seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &multisig.proposal_count.to_le_bytes()],
         bump,
     )]
     pub proposal: Account<'info, Proposal>,
 
+    #[account(seeds = [CONFIG_SEED], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn calculate_proposal_space(description: &str, signers_count: usize) -> usize {
-    8 + 32 + 8 + 100 + 4 + signers_count + 1 + 8 + 8 + 4 + description.len() + 1
+pub fn calculate_proposal_space(instruction: &ProposalInstruction, description: &str, signers_count: usize) -> usize {
+    8 + 32 + 8 + calculate_instruction_space(instruction) + 4 + signers_count + 8 + 1 + 8 + 8 + 8 + 1 + 9 + 4 + description.len() + 1
+}
+
+// Fixed variants fit in 100 bytes; custom instructions, reputation tiers and
+// committees are sized to their payload
+pub fn calculate_instruction_space(instruction: &ProposalInstruction) -> usize {
+    match instruction {
+        ProposalInstruction::Custom { accounts, data, .. } => {
+            1 + 32 + 4 + accounts.len() * (32 + 1 + 1) + 4 + data.len()
+        }
+        ProposalInstruction::SetReputationTiers { tiers } => {
+            1 + 4 + tiers.len() * (8 + 8 + 2 + 4 + 8)
+        }
+        ProposalInstruction::CreateCommittee { label, signers, .. } => {
+            1 + 4 + label.len() + 4 + signers.len() * 32 + 1 + 4
+        }
+        _ => 100,
+    }
 }
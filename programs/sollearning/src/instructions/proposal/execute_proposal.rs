@@ -1,12 +1,36 @@
 use anchor_lang::prelude::*;
 use crate::states::proposal::{Proposal, ProposalStatus, ProposalExecuted};
+use crate::constants::*;
 use crate::error::SolLearningError;
 use crate::instructions::proposal::execute_proposal_struct::ExecuteProposal;
+use crate::utils::access::check_governance;
+use crate::utils::governance::{check_proposal_scope, check_proposal_membership, check_proposal_not_expired};
+use crate::utils::execution::{ProposalExecution, execute_proposal_instruction};
 
-pub fn execute_proposal_handler(ctx: Context<ExecuteProposal>) -> Result<()> {
+pub fn execute_proposal_handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>) -> Result<()> {
     validate_execution(&ctx)?;
 
-    update_proposal_status(&mut ctx.accounts.proposal);
+    let current_time = Clock::get()?.unix_timestamp;
+    let instruction = ctx.accounts.proposal.instruction.clone();
+
+    let multisig_label = ctx.accounts.multisig.label.clone();
+    let multisig_bump = [ctx.accounts.multisig.bump];
+    let multisig_seeds: &[&[u8]] = &[MULTISIG_SEED, multisig_label.as_bytes(), &multisig_bump];
+
+    let mut execution = ProposalExecution {
+        program_id: ctx.program_id,
+        payer: ctx.accounts.executor.to_account_info(),
+        governance: ctx.accounts.multisig.to_account_info(),
+        governance_seeds: multisig_seeds,
+        program_state: &mut ctx.accounts.program_state,
+        program_config: &mut ctx.accounts.program_config,
+        multisig: Some(&mut ctx.accounts.multisig),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        remaining_accounts: ctx.remaining_accounts,
+    };
+    execute_proposal_instruction(&mut execution, instruction, current_time)?;
+
+    update_proposal_status(&mut ctx.accounts.proposal, current_time);
 
     emit_proposal_executed(&ctx)?;
 
@@ -16,16 +40,26 @@ pub fn execute_proposal_handler(ctx: Context<ExecuteProposal>) -> Result<()> {
 }
 
 fn validate_execution(ctx: &Context<ExecuteProposal>) -> Result<()> {
+    check_governance(&ctx.accounts.multisig, &ctx.accounts.executor)?;
+    check_proposal_scope(&ctx.accounts.multisig, &ctx.accounts.proposal.instruction)?;
+    check_proposal_membership(&ctx.accounts.multisig, &ctx.accounts.proposal)?;
+    check_proposal_not_expired(&ctx.accounts.proposal, Clock::get()?.unix_timestamp)?;
+
     let approvals = ctx.accounts.proposal.signers.iter().filter(|&&s| s).count();
     require!(
-        approvals >= ctx.accounts.multisig.threshold as usize,
+        approvals >= ctx.accounts.proposal.threshold as usize,
         SolLearningError::NotEnoughSigners
     );
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.proposal.executable_at,
+        SolLearningError::ProposalTimelockActive
+    );
     Ok(())
 }
 
-fn update_proposal_status(proposal: &mut Account<Proposal>) {
+fn update_proposal_status(proposal: &mut Account<Proposal>, current_time: i64) {
     proposal.status = ProposalStatus::Executed;
+    proposal.closed_at = Some(current_time);
 }
 
 fn emit_proposal_executed(ctx: &Context<ExecuteProposal>) -> Result<()> {
@@ -44,4 +78,4 @@ fn log_execution(ctx: &Context<ExecuteProposal>) {
         ctx.accounts.proposal.index,
         ctx.accounts.executor.key()
     );
-}
\ No newline at end of file
+}
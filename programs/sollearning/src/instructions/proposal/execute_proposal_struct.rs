@@ -1,19 +1,33 @@
 use anchor_lang::prelude::*;
 use crate::states::program::ProgramState;
+use crate::states::config::ProgramConfig;
 use crate::states::signers::Multisig;
-use crate::states::proposal::Proposal;
+use crate::states::proposal::{Proposal, ProposalStatus};
+use crate::error::SolLearningError;
+use crate::constants::{MULTISIG_SEED, PROGRAM_STATE_SEED, CONFIG_SEED};
 
+// Instruction-specific accounts are passed through remaining accounts, see
+// utils::execution::ProposalExecution.
 #[derive(Accounts)]
 pub struct ExecuteProposal<'info> {
     #[account(mut)]
     pub executor: Signer<'info>,
 
-    #[account(mut)]
+    #[account(mut, seeds = [MULTISIG_SEED, multisig.label.as_bytes()], bump = multisig.bump)]
     pub multisig: Account<'info, Multisig>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = proposal.multisig == multisig.key() @ SolLearningError::InvalidMultisigConfig,
+        constraint = proposal.status == ProposalStatus::Active @ SolLearningError::InvalidProposalStatus,
+    )]
     pub proposal: Account<'info, Proposal>,
 
-    #[account(mut)]
+    #[account(mut, seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
     pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [CONFIG_SEED], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::states::proposal::{ProposalStatus, ProposalExpired};
+use crate::error::SolLearningError;
+use crate::instructions::proposal::expire_proposal_struct::ExpireProposal;
+use crate::utils::governance::is_proposal_expired;
+
+pub fn expire_proposal_handler(ctx: Context<ExpireProposal>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        is_proposal_expired(&ctx.accounts.proposal, current_time),
+        SolLearningError::ProposalNotExpired
+    );
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.status = ProposalStatus::Expired;
+    proposal.closed_at = Some(current_time);
+
+    emit!(ProposalExpired {
+        multisig: ctx.accounts.multisig.key(),
+        proposal: proposal.key(),
+        expired_by: ctx.accounts.caller.key(),
+        timestamp: current_time,
+    });
+
+    msg!("Proposal #{} marked as expired", proposal.index);
+
+    Ok(())
+}
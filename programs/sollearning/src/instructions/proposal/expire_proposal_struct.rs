@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::states::signers::Multisig;
+use crate::states::proposal::{Proposal, ProposalStatus};
+use crate::error::SolLearningError;
+use crate::constants::*;
+
+// Permissionless: anyone may close out a proposal once it has expired.
+#[derive(Accounts)]
+pub struct ExpireProposal<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(seeds = [MULTISIG_SEED, multisig.label.as_bytes()], bump = multisig.bump)]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        constraint = proposal.multisig == multisig.key() @ SolLearningError::InvalidMultisigConfig,
+        constraint = proposal.status == ProposalStatus::Active @ SolLearningError::InvalidProposalStatus,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
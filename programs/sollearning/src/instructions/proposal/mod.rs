@@ -1,10 +1,16 @@
 pub mod create_proposal;
 pub mod approve_proposal;
 pub mod execute_proposal;
+pub mod veto_proposal;
+pub mod expire_proposal;
 pub mod create_proposal_struct;
 pub mod approve_proposal_struct;
 pub mod execute_proposal_struct;
+pub mod veto_proposal_struct;
+pub mod expire_proposal_struct;
 
 pub use create_proposal::create_proposal_handler;
 pub use approve_proposal::approve_proposal_handler;
 pub use execute_proposal::execute_proposal_handler;
+pub use veto_proposal::veto_proposal_handler;
+pub use expire_proposal::expire_proposal_handler;
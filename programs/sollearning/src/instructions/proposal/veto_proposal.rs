@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::states::proposal::{ProposalStatus, ProposalVetoed};
+use crate::error::SolLearningError;
+use crate::instructions::proposal::veto_proposal_struct::VetoProposal;
+use crate::utils::access::check_guardian;
+
+// A proposal can only be vetoed while its timelock is running; once it is
+// executable the veto window is closed.
+pub fn veto_proposal_handler(ctx: Context<VetoProposal>) -> Result<()> {
+    check_guardian(
+        &ctx.accounts.program_config,
+        ctx.accounts.emergency_multisig.as_deref(),
+        &ctx.accounts.vetoer,
+    )?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time < ctx.accounts.proposal.executable_at,
+        SolLearningError::VetoWindowClosed
+    );
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.status = ProposalStatus::Cancelled;
+    proposal.closed_at = Some(current_time);
+
+    emit!(ProposalVetoed {
+        multisig: ctx.accounts.multisig.key(),
+        proposal: proposal.key(),
+        vetoed_by: ctx.accounts.vetoer.key(),
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Proposal #{} vetoed by {}",
+        proposal.index,
+        ctx.accounts.vetoer.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::states::config::ProgramConfig;
+use crate::states::emergency::EmergencyMultisig;
+use crate::states::signers::Multisig;
+use crate::states::proposal::{Proposal, ProposalStatus};
+use crate::error::SolLearningError;
+use crate::constants::*;
+
+#[derive(Accounts)]
+pub struct VetoProposal<'info> {
+    pub vetoer: Signer<'info>,
+
+    #[account(seeds = [CONFIG_SEED], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(seeds = [EMERGENCY_MULTISIG_SEED], bump = emergency_multisig.bump)]
+    pub emergency_multisig: Option<Account<'info, EmergencyMultisig>>,
+
+    #[account(seeds = [MULTISIG_SEED, multisig.label.as_bytes()], bump = multisig.bump)]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, multisig.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        constraint = proposal.multisig == multisig.key() @ SolLearningError::InvalidMultisigConfig,
+        constraint = proposal.status == ProposalStatus::Active @ SolLearningError::InvalidProposalStatus,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Approve};
+use crate::constants::PAUSE_FLAG_STUDENT_REGISTER;
+use crate::instructions::student::approve_clawback_delegate_struct::ApproveClawbackDelegate;
+use crate::utils::pause::{check_program_running, check_function_running};
+
+// Restores the clawback delegate on a token account that was created outside
+// create_student_token_account or whose delegate was revoked; rewards are only
+// minted to accounts delegating to the ProgramState PDA.
+pub fn approve_clawback_delegate_handler(ctx: Context<ApproveClawbackDelegate>) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_STUDENT_REGISTER)?;
+
+    approve_program_delegate(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.student_token_account.to_account_info(),
+        ctx.accounts.program_state.to_account_info(),
+        ctx.accounts.student.to_account_info(),
+    )?;
+
+    msg!(
+        "Clawback delegate approved for student {}",
+        ctx.accounts.student.key()
+    );
+
+    Ok(())
+}
+
+// The ProgramState PDA becomes delegate of the student's token account so
+// rewards from a revoked completion can be burned back
+pub fn approve_program_delegate<'info>(
+    token_program: AccountInfo<'info>,
+    student_token_account: AccountInfo<'info>,
+    program_state: AccountInfo<'info>,
+    student: AccountInfo<'info>,
+) -> Result<()> {
+    token::approve(
+        CpiContext::new(
+            token_program,
+            Approve {
+                to: student_token_account,
+                delegate: program_state,
+                authority: student,
+            },
+        ),
+        u64::MAX,
+    )
+}
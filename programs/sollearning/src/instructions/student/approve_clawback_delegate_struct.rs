@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::states::program::ProgramState;
+use crate::error::SolLearningError;
+use crate::constants::PROGRAM_STATE_SEED;
+
+#[derive(Accounts)]
+pub struct ApproveClawbackDelegate<'info> {
+    pub student: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        constraint = student_token_account.mint == program_state.token_mint @ SolLearningError::InvalidMint,
+        constraint = student_token_account.owner == student.key() @ SolLearningError::InvalidTokenAccount,
+    )]
+    pub student_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
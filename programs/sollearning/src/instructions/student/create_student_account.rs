@@ -1,8 +1,20 @@
 use anchor_lang::prelude::*;
+use crate::constants::PAUSE_FLAG_STUDENT_REGISTER;
 use crate::instructions::student::create_student_account_struct::CreateStudentTokenAccount;
+use crate::utils::pause::{check_program_running, check_function_running};
+use crate::instructions::student::approve_clawback_delegate::approve_program_delegate;
 
 pub fn create_student_token_account_handler(ctx: Context<CreateStudentTokenAccount>) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_STUDENT_REGISTER)?;
+
     create_associated_token_account(&ctx)?;
+    approve_program_delegate(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.student_token_account.to_account_info(),
+        ctx.accounts.program_state.to_account_info(),
+        ctx.accounts.student.to_account_info(),
+    )?;
     log_token_creation(&ctx); 
     Ok(())
 }
@@ -2,21 +2,22 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::Token;
 use anchor_spl::associated_token::AssociatedToken;
 use crate::states::program::ProgramState;
+use crate::constants::PROGRAM_STATE_SEED;
 
 #[derive(Accounts)]
 pub struct CreateStudentTokenAccount<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    #[account(mut)]
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
     pub program_state: Account<'info, ProgramState>,
 
     /// CHECK: `token_mint` is mutable
     #[account(address = program_state.token_mint)]
     pub token_mint: AccountInfo<'info>,
 
-    /// CHECK: `student` is checked
-    pub student: UncheckedAccount<'info>,
+    // Signs so the clawback delegate can be approved with the account
+    pub student: Signer<'info>,
 
     /// CHECK: `student_token_account` is checked
     #[account(mut)]
@@ -1,7 +1,10 @@
 pub mod register_student;
 pub mod create_student_account;
+pub mod approve_clawback_delegate;
 pub mod register_student_struct;
 pub mod create_student_account_struct;
+pub mod approve_clawback_delegate_struct;
 
 pub use register_student::register_student_handler;
 pub use create_student_account::create_student_token_account_handler;
+pub use approve_clawback_delegate::approve_clawback_delegate_handler;
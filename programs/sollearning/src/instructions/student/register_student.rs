@@ -1,12 +1,18 @@
 use anchor_lang::prelude::*;
 use crate::states::student::{StudentInfo, StudentRegistered};
+use crate::constants::PAUSE_FLAG_STUDENT_REGISTER;
 use crate::instructions::student::register_student_struct::RegisterStudent;
-
+use crate::utils::pause::{check_program_running, check_function_running};
+use crate::utils::blocklist::check_not_blocklisted;
 
 pub fn register_student_handler(ctx: Context<RegisterStudent>) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_STUDENT_REGISTER)?;
+    check_not_blocklisted(&ctx.accounts.student_blocklist, ctx.program_id)?;
+
     let current_time = Clock::get()?.unix_timestamp;
 
-    _ = initialize_student_info(&mut ctx.accounts.student_info, &ctx.accounts.student, current_time);
+    initialize_student_info(&mut ctx.accounts.student_info, &ctx.accounts.student, current_time, ctx.bumps.student_info);
 
     emit_registration_event(&ctx, current_time);
 
@@ -19,12 +25,13 @@ fn initialize_student_info(
     student_info: &mut Account<StudentInfo>,
     student: &Signer,
     current_time: i64,
+    bump: u8,
 ) {
     student_info.student_address = student.key();
     student_info.total_earned = 0;
     student_info.courses_completed = 0;
     student_info.last_activity = current_time;
-    student_info.bump = student_info.bump;
+    student_info.bump = bump;
 }
 
 fn emit_registration_event(ctx: &Context<RegisterStudent>, timestamp: i64) {
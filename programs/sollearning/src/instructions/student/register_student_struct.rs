@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::constants::STUDENT_SEED;
+use crate::constants::{STUDENT_SEED, BLOCKLIST_SEED};
 use crate::states::program::ProgramState;
 use crate::states::student::StudentInfo;
 
@@ -14,6 +14,10 @@ pub struct RegisterStudent<'info> {
     #[account(mut)]
     pub student: Signer<'info>,
 
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, student.key().as_ref()], bump)]
+    pub student_blocklist: UncheckedAccount<'info>,
+
     #[account(
         init,
         payer = payer,
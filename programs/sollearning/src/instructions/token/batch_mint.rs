@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::states::program::ProgramState;
+use crate::states::educator::EducatorAccount;
+use crate::states::student::StudentInfo;
+use crate::states::course::{CourseCompletion, CourseCompleted, CompletionSkipped, BatchMintCompleted, TokensMinted};
+use crate::error::SolLearningError;
+use crate::constants::*;
+use crate::instructions::token::batch_mint_struct::BatchMintCompletions;
+use crate::utils::pause::{check_program_running, check_function_running};
+use crate::utils::access::check_educator;
+use crate::utils::accounts::{create_program_account, write_account};
+use crate::utils::blocklist::check_not_blocklisted;
+use crate::utils::circuit_breaker::{record_mint_for_circuit_breakers, trip_circuit_breaker};
+use crate::utils::minting::{
+    validate_mint_cooldown, update_educator_stats, update_minted_supply, update_student_info, initialize_course_completion,
+    mint_reward, validate_clawback_delegate,
+};
+
+// Partial-failure semantics: students that already hold a completion for the
+// course are skipped and reported through `CompletionSkipped`. Any other invalid
+// entry, including a blocklisted student, aborts the whole batch so no student is minted to from a malformed request.
+// A tripped circuit breaker pauses minting and ends the batch; students minted
+// before it keep their completions.
+pub fn batch_mint_completions_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchMintCompletions<'info>>,
+    amount: u64,
+    course_id: String,
+) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_MINT)?;
+
+    check_not_blocklisted(&ctx.accounts.educator_blocklist, ctx.program_id)?;
+    check_educator(&ctx.accounts.educator, &ctx.accounts.educator_authority)?;
+    validate_mint_amount(amount, &ctx.accounts.educator)?;
+    let student_count = validate_batch_size(ctx.remaining_accounts)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    validate_mint_cooldown(&ctx.accounts.educator, current_time)?;
+
+    let educator_key = ctx.accounts.educator.key();
+    let mut minted_count: u32 = 0;
+    let mut skipped_count: u32 = 0;
+
+    for entry in ctx.remaining_accounts.chunks(BATCH_MINT_ACCOUNTS_PER_STUDENT) {
+        let (student_info_account, student_token_account, course_completion, student_blocklist) =
+            (&entry[0], &entry[1], &entry[2], &entry[3]);
+
+        let mut student_info = load_student_info(student_info_account)?;
+        let student_key = student_info.student_address;
+
+        validate_student_blocklist(student_blocklist, student_key, ctx.program_id)?;
+        validate_student_token_account(student_token_account, student_key, &ctx.accounts.program_state, amount)?;
+        let completion_bump = validate_completion_address(course_completion, student_key, &course_id, ctx.program_id)?;
+
+        if completion_exists(course_completion) {
+            emit!(CompletionSkipped {
+                student: student_key,
+                course_id: course_id.clone(),
+                educator: educator_key,
+                timestamp: current_time,
+            });
+            skipped_count = skipped_count.checked_add(1).ok_or(SolLearningError::Overflow)?;
+            continue;
+        }
+
+        if let Some(breaker) = record_mint_for_circuit_breakers(
+            &mut ctx.accounts.program_state,
+            &ctx.accounts.program_config,
+            &mut ctx.accounts.educator,
+            amount,
+            amount,
+            Clock::get()?.slot,
+        )? {
+            trip_circuit_breaker(&mut ctx.accounts.program_state, &mut ctx.accounts.educator, breaker, amount, current_time);
+            break;
+        }
+
+        create_program_account(
+            ctx.accounts.educator_authority.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            course_completion.clone(),
+            ctx.program_id,
+            8 + std::mem::size_of::<CourseCompletion>(),
+            &[COURSE_COMPLETION_SEED, student_key.as_ref(), course_id.as_bytes(), &[completion_bump]],
+        )?;
+        let mut completion = CourseCompletion::default();
+        initialize_course_completion(&mut completion, student_key, &course_id, educator_key, amount, current_time, completion_bump)?;
+        write_account(course_completion, &completion)?;
+
+        update_student_info(&mut student_info, amount, current_time)?;
+        student_info.exit(ctx.program_id)?;
+
+        mint_reward(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.token_mint.to_account_info(),
+            student_token_account.clone(),
+            &ctx.accounts.program_state,
+            amount,
+        )?;
+        emit_student_events(&ctx, student_key, &course_id, amount, current_time)?;
+
+        minted_count = minted_count.checked_add(1).ok_or(SolLearningError::Overflow)?;
+    }
+
+    let total_amount = amount
+        .checked_mul(minted_count as u64)
+        .ok_or(SolLearningError::Overflow)?;
+
+    if minted_count > 0 {
+        update_educator_stats(&mut ctx.accounts.educator, total_amount, current_time)?;
+        update_minted_supply(&mut ctx.accounts.program_state, total_amount)?;
+    }
+
+    emit!(BatchMintCompleted {
+        course_id: course_id.clone(),
+        educator: educator_key,
+        minted_count,
+        skipped_count,
+        total_amount,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Batch minted {} tokens to {} of {} students for course {} ({} skipped)",
+        total_amount,
+        minted_count,
+        student_count,
+        course_id,
+        skipped_count
+    );
+
+    Ok(())
+}
+
+fn validate_mint_amount(amount: u64, educator: &Account<EducatorAccount>) -> Result<()> {
+    require!(amount > 0 && amount <= educator.mint_limit, SolLearningError::InvalidAmount);
+    Ok(())
+}
+
+fn validate_batch_size(remaining_accounts: &[AccountInfo]) -> Result<usize> {
+    require!(
+        remaining_accounts.len().is_multiple_of(BATCH_MINT_ACCOUNTS_PER_STUDENT),
+        SolLearningError::InvalidBatchAccounts
+    );
+
+    let student_count = remaining_accounts.len() / BATCH_MINT_ACCOUNTS_PER_STUDENT;
+    require!(
+        student_count > 0 && student_count <= MAX_BATCH_MINT_SIZE,
+        SolLearningError::InvalidBatchSize
+    );
+
+    Ok(student_count)
+}
+
+fn load_student_info<'info>(student_info_account: &'info AccountInfo<'info>) -> Result<Account<'info, StudentInfo>> {
+    require!(student_info_account.is_writable, SolLearningError::InvalidBatchAccounts);
+    Account::<StudentInfo>::try_from(student_info_account).map_err(|_| SolLearningError::InvalidStudent.into())
+}
+
+fn validate_student_blocklist(student_blocklist: &AccountInfo, student: Pubkey, program_id: &Pubkey) -> Result<()> {
+    let (expected_address, _) = Pubkey::find_program_address(&[BLOCKLIST_SEED, student.as_ref()], program_id);
+    require!(student_blocklist.key() == expected_address, SolLearningError::InvalidBatchAccounts);
+    check_not_blocklisted(student_blocklist, program_id)
+}
+
+fn validate_student_token_account<'info>(
+    student_token_account: &'info AccountInfo<'info>,
+    student: Pubkey,
+    program_state: &Account<ProgramState>,
+    amount: u64,
+) -> Result<()> {
+    require!(student_token_account.is_writable, SolLearningError::InvalidBatchAccounts);
+
+    let token_account = Account::<TokenAccount>::try_from(student_token_account)
+        .map_err(|_| SolLearningError::InvalidTokenAccount)?;
+    require!(token_account.mint == program_state.token_mint, SolLearningError::InvalidMint);
+    require!(token_account.owner == student, SolLearningError::InvalidTokenAccount);
+    validate_clawback_delegate(&token_account, program_state.key(), amount)?;
+    Ok(())
+}
+
+fn validate_completion_address(course_completion: &AccountInfo, student: Pubkey, course_id: &str, program_id: &Pubkey) -> Result<u8> {
+    require!(course_completion.is_writable, SolLearningError::InvalidBatchAccounts);
+
+    let (expected_address, bump) = Pubkey::find_program_address(
+        &[COURSE_COMPLETION_SEED, student.as_ref(), course_id.as_bytes()],
+        program_id,
+    );
+    require!(course_completion.key() == expected_address, SolLearningError::InvalidCourseCompletion);
+    Ok(bump)
+}
+
+fn completion_exists(course_completion: &AccountInfo) -> bool {
+    !course_completion.data_is_empty()
+}
+
+fn emit_student_events(
+    ctx: &Context<'_, '_, '_, '_, BatchMintCompletions>,
+    student: Pubkey,
+    course_id: &str,
+    amount: u64,
+    timestamp: i64,
+) -> Result<()> {
+    emit!(CourseCompleted {
+        student,
+        course_id: course_id.to_string(),
+        educator: ctx.accounts.educator.key(),
+        tokens_awarded: amount,
+        timestamp,
+    });
+
+    emit!(TokensMinted {
+        recipient: student,
+        amount,
+        minted_by: ctx.accounts.educator_authority.key(),
+        timestamp,
+    });
+
+    Ok(())
+}
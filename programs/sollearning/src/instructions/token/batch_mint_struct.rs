@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use crate::states::program::ProgramState;
+use crate::states::config::ProgramConfig;
+use crate::states::educator::EducatorAccount;
+use crate::states::course::Course;
+use crate::constants::*;
+use crate::error::SolLearningError;
+
+// Each student is passed through remaining accounts as the group
+// [StudentInfo, student token account, CourseCompletion PDA, blocklist entry PDA].
+#[derive(Accounts)]
+#[instruction(amount: u64, course_id: String)]
+pub struct BatchMintCompletions<'info> {
+    #[account(mut)]
+    pub educator: Account<'info, EducatorAccount>,
+
+    #[account(mut)]
+    pub educator_authority: Signer<'info>,
+
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, educator.educator_address.as_ref()], bump)]
+    pub educator_blocklist: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(seeds = [CONFIG_SEED], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// CHECK: This is the token mint that will be used to mint tokens to the students
+    #[account(mut, address = program_state.token_mint @ SolLearningError::InvalidMint)]
+    pub token_mint: AccountInfo<'info>,
+
+    #[account(
+        seeds = [COURSE_SEED, educator.key().as_ref(), course_id.as_bytes()],
+        bump = course.bump,
+        constraint = course.educator == educator.key() @ SolLearningError::CourseNotOwnedByEducator,
+        constraint = course.is_active @ SolLearningError::CourseInactive,
+        constraint = course.verifier_threshold == 0 @ SolLearningError::CourseRequiresVerification,
+        constraint = course.co_educator_count == 0 @ SolLearningError::CoTaughtCourseUnsupported,
+    )]
+    pub course: Account<'info, Course>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
@@ -5,11 +5,13 @@ use crate::states::course::TokensBurned;
 use crate::error::SolLearningError;
 use crate::instructions::token::burn_struct::BurnInstruction;
 use crate::utils::pause::{check_program_running, check_function_running};
+use crate::utils::blocklist::check_not_blocklisted;
 use crate::constants::PAUSE_FLAG_BURN;
 
 pub fn burn_handler(ctx: Context<BurnInstruction>, amount: u64) -> Result<()> {
     check_program_running(&ctx.accounts.program_state)?;
     check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_BURN)?;
+    check_not_blocklisted(&ctx.accounts.owner_blocklist, ctx.program_id)?;
     
     validate_burn_amount(amount)?;
 
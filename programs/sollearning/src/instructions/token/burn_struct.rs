@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Token;
 use crate::states::program::ProgramState;
+use crate::constants::BLOCKLIST_SEED;
 #[derive(Accounts)]
 #[instruction(amount: u64)]
 pub struct BurnInstruction<'info> {
@@ -18,5 +19,9 @@ pub struct BurnInstruction<'info> {
     #[account(mut)]
     pub program_state: Account<'info, ProgramState>,
 
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, owner.key().as_ref()], bump)]
+    pub owner_blocklist: UncheckedAccount<'info>,
+
     pub token_program: Program<'info, Token>,
 }
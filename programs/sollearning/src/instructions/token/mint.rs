@@ -1,43 +1,111 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self};
-use crate::states::program::ProgramState;
-use crate::states::educator::EducatorAccount;
-use crate::states::student::StudentInfo;
-use crate::states::course::{CourseCompletion, CourseCompleted, TokensMinted};
+use crate::states::educator::{EducatorAccount, EducatorRewarded};
+use crate::states::course::{CourseCompleted, TokensMinted};
 use crate::error::SolLearningError;
 use crate::constants::*;
 use crate::instructions::token::mint_struct::MintToStudent;
 use crate::utils::pause::{check_program_running, check_function_running};
-
-pub fn mint_to_student_handler(ctx: Context<MintToStudent>, amount: u64, course_id: String) -> Result<()> {
+use crate::utils::operator::consume_operator_allowance;
+use crate::utils::access::{check_educator_or_operator, Role};
+use crate::utils::circuit_breaker::{record_mint_for_circuit_breakers, trip_circuit_breaker};
+use crate::utils::blocklist::check_not_blocklisted;
+use crate::utils::reputation::{
+    load_or_create_reputation, mark_student_taught, record_completion, apply_reputation_tier, store_reputation,
+};
+use crate::utils::minting::{
+    validate_mint_cooldown, update_educator_stats, update_minted_supply, update_student_info, initialize_course_completion, mint_reward,
+    distribute_co_educator_shares, co_educator_rewards_total, educator_reward_amount, validate_clawback_delegate,
+};
+
+pub fn mint_to_student_handler<'info>(
+    mut ctx: Context<'_, '_, 'info, 'info, MintToStudent<'info>>,
+    amount: u64,
+    course_id: String,
+) -> Result<()> {
     check_program_running(&ctx.accounts.program_state)?;
     check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_MINT)?;
+    check_not_blocklisted(&ctx.accounts.student_blocklist, ctx.program_id)?;
+    check_not_blocklisted(&ctx.accounts.educator_blocklist, ctx.program_id)?;
     
     validate_mint_amount(amount, &ctx.accounts.educator)?;
+    validate_clawback_delegate(&ctx.accounts.student_token_account, ctx.accounts.program_state.key(), amount)?;
 
     let current_time = Clock::get()?.unix_timestamp;
-    
+
+    let role = check_educator_or_operator(
+        &ctx.accounts.educator,
+        &ctx.accounts.educator_authority,
+        &ctx.accounts.operator,
+        OPERATOR_PERMISSION_VERIFY_COMPLETION,
+        current_time,
+    )?;
+
     validate_mint_cooldown(&ctx.accounts.educator, current_time)?;
-    
-    let student_previous_balance = token::accessor::amount(&ctx.accounts.student_token_account)?;
+
+    let minted = amount
+        .saturating_add(co_educator_rewards_total(&ctx.accounts.course, ctx.accounts.co_educators.as_deref(), amount))
+        .saturating_add(educator_reward_amount(&ctx.accounts.program_config, &ctx.accounts.educator, amount));
+    if let Some(breaker) = record_mint_for_circuit_breakers(
+        &mut ctx.accounts.program_state,
+        &ctx.accounts.program_config,
+        &mut ctx.accounts.educator,
+        amount,
+        minted,
+        Clock::get()?.slot,
+    )? {
+        trip_circuit_breaker(&mut ctx.accounts.program_state, &mut ctx.accounts.educator, breaker, amount, current_time);
+        // The completion record is closed so it can be minted again once
+        // minting resumes
+        return ctx.accounts.course_completion.close(ctx.accounts.educator_authority.to_account_info());
+    }
+
+    if role == Role::Operator {
+        consume_operator_allowance(&mut ctx.accounts.operator, amount)?;
+    }
+
+    let student_previous_balance = ctx.accounts.student_token_account.amount;
 
     let student_key = ctx.accounts.student.key();
     let educator_key = ctx.accounts.educator.key();
     let course_name = ctx.accounts.course.course_name.clone();
 
+    let (lead_share, co_educator_credits) = distribute_co_educator_shares(
+        &ctx.accounts.course,
+        ctx.accounts.co_educators.as_deref(),
+        ctx.remaining_accounts,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        &mut ctx.accounts.program_state,
+        amount,
+        current_time,
+        ctx.program_id,
+    )?;
+
     {
         let educator = &mut ctx.accounts.educator;
         let program_state = &mut ctx.accounts.program_state;
         let student_info = &mut ctx.accounts.student_info;
         let course_completion = &mut ctx.accounts.course_completion;
 
-        update_educator_stats(educator, amount, current_time)?;
-        update_program_state(program_state, amount)?;
+        update_educator_stats(educator, lead_share, current_time)?;
+        update_minted_supply(program_state, amount)?;
         update_student_info(student_info, amount, current_time)?;
-        initialize_course_completion(course_completion, student_key, &course_id, educator_key, amount, current_time)?;
+        initialize_course_completion(
+            course_completion,
+            student_key,
+            &course_id,
+            educator_key,
+            amount,
+            current_time,
+            ctx.bumps.course_completion,
+        )?;
+        course_completion.lead_share = lead_share;
+        course_completion.co_educator_credits = co_educator_credits;
     }
 
     mint_tokens_to_student(&ctx, amount)?;
+    mint_educator_reward(&mut ctx, amount, &course_id, current_time)?;
+    update_reputation(&mut ctx, current_time)?;
     emit_events(&ctx, amount, &course_id, current_time)?;
     log_minting(&ctx, amount, &course_id, student_previous_balance, &course_name)?;
 
@@ -49,66 +117,90 @@ fn validate_mint_amount(amount: u64, educator: &Account<EducatorAccount>) -> Res
     Ok(())
 }
 
-fn validate_mint_cooldown(educator: &Account<EducatorAccount>, current_time: i64) -> Result<()> {
-    let time_since_last_mint = current_time - educator.last_mint_time;
-    require!(
-        time_since_last_mint >= MINT_COOLDOWN_PERIOD || educator.last_mint_time == 0,
-        SolLearningError::MintingTooFrequent
-    );
-    Ok(())
+fn mint_tokens_to_student(ctx: &Context<MintToStudent>, amount: u64) -> Result<()> {
+    mint_reward(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.student_token_account.to_account_info(),
+        &ctx.accounts.program_state,
+        amount,
+    )
 }
 
-fn update_educator_stats(educator: &mut Account<EducatorAccount>, amount: u64, current_time: i64) -> Result<()> {
-    educator.total_minted = educator.total_minted.checked_add(amount).ok_or(SolLearningError::Overflow)?;
-    educator.last_mint_time = current_time;
-    Ok(())
-}
+// Incentive on top of the student reward, see educator_reward_amount
+fn mint_educator_reward(ctx: &mut Context<MintToStudent>, amount: u64, course_id: &str, timestamp: i64) -> Result<()> {
+    let reward = educator_reward_amount(&ctx.accounts.program_config, &ctx.accounts.educator, amount);
+    if reward == 0 {
+        return Ok(());
+    }
 
-fn update_program_state(program_state: &mut Account<ProgramState>, amount: u64) -> Result<()> {
-    program_state.total_minted = program_state.total_minted.checked_add(amount).ok_or(SolLearningError::Overflow)?;
-    Ok(())
-}
+    let educator_token_account = ctx
+        .accounts
+        .educator_token_account
+        .as_ref()
+        .ok_or(SolLearningError::EducatorTokenAccountRequired)?;
+    validate_clawback_delegate(educator_token_account, ctx.accounts.program_state.key(), reward)?;
+
+    mint_reward(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        educator_token_account.to_account_info(),
+        &ctx.accounts.program_state,
+        reward,
+    )?;
+    update_minted_supply(&mut ctx.accounts.program_state, reward)?;
+    ctx.accounts.course_completion.educator_reward = reward;
 
-fn update_student_info(student_info: &mut Account<StudentInfo>, amount: u64, current_time: i64) -> Result<()> {
-    student_info.total_earned = student_info.total_earned.checked_add(amount).ok_or(SolLearningError::Overflow)?;
-    student_info.courses_completed = student_info.courses_completed.checked_add(1).ok_or(SolLearningError::Overflow)?;
-    student_info.last_activity = current_time;
-    Ok(())
-}
+    let educator = &mut ctx.accounts.educator;
+    educator.rewards_earned = educator.rewards_earned.checked_add(reward).ok_or(SolLearningError::Overflow)?;
+
+    emit!(EducatorRewarded {
+        educator: educator.key(),
+        student: ctx.accounts.student.key(),
+        course_id: course_id.to_string(),
+        amount: reward,
+        rewards_earned: educator.rewards_earned,
+        timestamp,
+    });
 
-fn initialize_course_completion(
-    course_completion: &mut Account<CourseCompletion>,
-    student: Pubkey,
-    course_id: &str,
-    educator: Pubkey,
-    amount: u64,
-    current_time: i64,
-) -> Result<()> {
-    course_completion.student = student;
-    course_completion.course_id = course_id.to_string();
-    course_completion.verified_by = educator;
-    course_completion.completion_time = current_time;
-    course_completion.tokens_awarded = amount;
     Ok(())
 }
 
-fn mint_tokens_to_student(ctx: &Context<MintToStudent>, amount: u64) -> Result<()> {
-    let signer_seeds = &[PROGRAM_STATE_SEED, &[ctx.accounts.program_state.bump]];
-    let signer = &[&signer_seeds[..]];
-
-    token::mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            token::MintTo {
-                mint: ctx.accounts.token_mint.to_account_info(),
-                to: ctx.accounts.student_token_account.to_account_info(),
-                authority: ctx.accounts.program_state.to_account_info(),
-            },
-            signer,
-        ),
-        amount,
+// Counts the completion and re-applies the educator's mint_limit tier
+fn update_reputation(ctx: &mut Context<MintToStudent>, current_time: i64) -> Result<()> {
+    let payer = ctx.accounts.educator_authority.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let reputation_account = ctx.accounts.educator_reputation.to_account_info();
+    let educator_key = ctx.accounts.educator.key();
+
+    let mut reputation = load_or_create_reputation(
+        &reputation_account,
+        &payer,
+        &system_program,
+        educator_key,
+        ctx.bumps.educator_reputation,
+        ctx.program_id,
     )?;
-    Ok(())
+    let new_student = mark_student_taught(
+        &ctx.accounts.educator_student.to_account_info(),
+        &payer,
+        &system_program,
+        educator_key,
+        ctx.accounts.student.key(),
+        ctx.bumps.educator_student,
+        ctx.program_id,
+        current_time,
+    )?;
+
+    record_completion(&mut reputation, new_student)?;
+    apply_reputation_tier(
+        &ctx.accounts.program_config,
+        &mut reputation,
+        &mut ctx.accounts.educator,
+        educator_key,
+        current_time,
+    );
+    store_reputation(&reputation_account, &mut reputation, current_time)
 }
 
 fn emit_events(ctx: &Context<MintToStudent>, amount: u64, course_id: &str, timestamp: i64) -> Result<()> {
@@ -1,12 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::Token;
+use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::associated_token::get_associated_token_address;
 use crate::states::program::ProgramState;
-use crate::states::educator::EducatorAccount;
+use crate::states::config::ProgramConfig;
+use crate::states::educator::{EducatorAccount, EducatorOperator};
 use crate::states::student::StudentInfo;
-use crate::states::course::{Course, CourseCompletion};
+use crate::states::course::{Course, CourseCompletion, CourseCoEducators};
 use crate::constants::*;
 use crate::error::SolLearningError;
 
+// Co-taught courses also take one [EducatorAccount, token account, blocklist
+// entry] triple per co-educator through the remaining accounts, see
+// distribute_co_educator_shares.
 #[derive(Accounts)]
 #[instruction(amount: u64, course_id: String)]
 pub struct MintToStudent<'info> {
@@ -19,6 +24,9 @@ pub struct MintToStudent<'info> {
     #[account(mut)]
     pub program_state: Account<'info, ProgramState>,
 
+    #[account(seeds = [CONFIG_SEED], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+
     /// CHECK: This is the token mint that will be used to mint tokens to the student
     #[account(mut)]
     pub token_mint: AccountInfo<'info>,
@@ -29,14 +37,26 @@ pub struct MintToStudent<'info> {
     /// CHECK: This is the token account of the student that will receive the tokens
     pub student: AccountInfo<'info>,
 
-    /// CHECK: This is the token account of the student that will receive the tokens
-    #[account(mut)]
-    pub student_token_account: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = student_token_account.mint == program_state.token_mint @ SolLearningError::InvalidMint,
+        constraint = student_token_account.owner == student.key() @ SolLearningError::InvalidTokenAccount,
+    )]
+    pub student_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, student.key().as_ref()], bump)]
+    pub student_blocklist: UncheckedAccount<'info>,
+
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, educator.educator_address.as_ref()], bump)]
+    pub educator_blocklist: UncheckedAccount<'info>,
 
     #[account(
         seeds = [COURSE_SEED, educator.key().as_ref(), course_id.as_bytes()],
         bump = course.bump,
         constraint = course.educator == educator.key() @ SolLearningError::CourseNotOwnedByEducator,
+        constraint = course.verifier_threshold == 0 @ SolLearningError::CourseRequiresVerification,
     )]
     pub course: Account<'info, Course>, 
     
@@ -50,6 +70,35 @@ pub struct MintToStudent<'info> {
     )]
     pub course_completion: Account<'info, CourseCompletion>,
 
+    #[account(
+        mut,
+        seeds = [OPERATOR_SEED, educator.key().as_ref(), educator_authority.key().as_ref()],
+        bump = operator.bump,
+    )]
+    pub operator: Option<Account<'info, EducatorOperator>>,
+
+    #[account(
+        seeds = [CO_EDUCATORS_SEED, course.key().as_ref()],
+        bump = co_educators.bump,
+    )]
+    pub co_educators: Option<Account<'info, CourseCoEducators>>,
+
+    // Receives the educator reward; required while the reward rate is non-zero
+    #[account(
+        mut,
+        address = get_associated_token_address(&educator.educator_address, &program_state.token_mint)
+            @ SolLearningError::InvalidTokenAccount,
+    )]
+    pub educator_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: EducatorReputation PDA, created on first use
+    #[account(mut, seeds = [EDUCATOR_REPUTATION_SEED, educator.key().as_ref()], bump)]
+    pub educator_reputation: UncheckedAccount<'info>,
+
+    /// CHECK: EducatorStudent PDA, created the first time the educator awards the student
+    #[account(mut, seeds = [EDUCATOR_STUDENT_SEED, educator.key().as_ref(), student.key().as_ref()], bump)]
+    pub educator_student: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
 }
\ No newline at end of file
@@ -1,10 +1,16 @@
 pub mod mint;
 pub mod burn;
 pub mod transfer;
+pub mod batch_mint;
+pub mod redeem_attestation;
 pub mod mint_struct;
 pub mod burn_struct;
 pub mod transfer_struct;
+pub mod batch_mint_struct;
+pub mod redeem_attestation_struct;
 
 pub use mint::mint_to_student_handler;
 pub use burn::burn_handler;
 pub use transfer::transfer_handler;
+pub use batch_mint::batch_mint_completions_handler;
+pub use redeem_attestation::redeem_completion_attestation_handler;
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use crate::states::educator::EducatorAccount;
+use crate::states::course::{CompletionAttestation, AttestationRedeemed, CourseCompleted, TokensMinted};
+use crate::error::SolLearningError;
+use crate::constants::*;
+use crate::instructions::token::redeem_attestation_struct::RedeemCompletionAttestation;
+use crate::utils::pause::{check_program_running, check_function_running};
+use crate::utils::signature::verify_ed25519_instruction;
+use crate::utils::blocklist::check_not_blocklisted;
+use crate::utils::circuit_breaker::{record_mint_for_circuit_breakers, trip_circuit_breaker};
+use crate::utils::minting::{
+    validate_mint_cooldown, update_educator_stats, update_minted_supply, update_student_info, initialize_course_completion,
+    mint_reward, validate_clawback_delegate,
+};
+
+// The student pays for the transaction and the completion account. Replays are
+// rejected by the CourseCompletion PDA, which can only be created once per
+// student and course; the nonce lets educators issue distinguishable attestations.
+pub fn redeem_completion_attestation_handler(
+    ctx: Context<RedeemCompletionAttestation>,
+    attestation: CompletionAttestation,
+) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_MINT)?;
+    check_not_blocklisted(&ctx.accounts.student_blocklist, ctx.program_id)?;
+    check_not_blocklisted(&ctx.accounts.educator_blocklist, ctx.program_id)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    validate_attestation(&attestation, &ctx.accounts.educator, ctx.accounts.student.key(), current_time)?;
+    validate_mint_cooldown(&ctx.accounts.educator, current_time)?;
+
+    let message = build_attestation_message(ctx.program_id, &attestation)?;
+    verify_ed25519_instruction(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.educator.educator_address,
+        &message,
+    )?;
+
+    let student_key = ctx.accounts.student.key();
+    let educator_key = ctx.accounts.educator.key();
+    let amount = attestation.amount;
+    validate_clawback_delegate(&ctx.accounts.student_token_account, ctx.accounts.program_state.key(), amount)?;
+
+    if let Some(breaker) = record_mint_for_circuit_breakers(
+        &mut ctx.accounts.program_state,
+        &ctx.accounts.program_config,
+        &mut ctx.accounts.educator,
+        amount,
+        amount,
+        Clock::get()?.slot,
+    )? {
+        trip_circuit_breaker(&mut ctx.accounts.program_state, &mut ctx.accounts.educator, breaker, amount, current_time);
+        // The attestation stays redeemable once minting resumes
+        return ctx.accounts.course_completion.close(ctx.accounts.student.to_account_info());
+    }
+
+    {
+        let educator = &mut ctx.accounts.educator;
+        let program_state = &mut ctx.accounts.program_state;
+        let student_info = &mut ctx.accounts.student_info;
+        let course_completion = &mut ctx.accounts.course_completion;
+
+        update_educator_stats(educator, amount, current_time)?;
+        update_minted_supply(program_state, amount)?;
+        update_student_info(student_info, amount, current_time)?;
+        initialize_course_completion(
+            course_completion,
+            student_key,
+            &attestation.course_id,
+            educator_key,
+            amount,
+            current_time,
+            ctx.bumps.course_completion,
+        )?;
+    }
+
+    mint_reward(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        ctx.accounts.student_token_account.to_account_info(),
+        &ctx.accounts.program_state,
+        amount,
+    )?;
+    emit_events(&ctx, &attestation, current_time)?;
+
+    msg!(
+        "Student {} redeemed attestation #{} for course {}: {} tokens minted",
+        student_key,
+        attestation.nonce,
+        attestation.course_id,
+        amount
+    );
+
+    Ok(())
+}
+
+// Bytes the educator signs off-chain: domain separator, program id and the
+// borsh-serialized attestation.
+pub fn build_attestation_message(program_id: &Pubkey, attestation: &CompletionAttestation) -> Result<Vec<u8>> {
+    let mut message = Vec::with_capacity(ATTESTATION_DOMAIN.len() + 32 + 128);
+    message.extend_from_slice(ATTESTATION_DOMAIN);
+    message.extend_from_slice(program_id.as_ref());
+    attestation.serialize(&mut message)?;
+    Ok(message)
+}
+
+fn validate_attestation(
+    attestation: &CompletionAttestation,
+    educator: &Account<EducatorAccount>,
+    student: Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    require!(educator.is_active, SolLearningError::InactiveEducator);
+    require!(attestation.student == student, SolLearningError::InvalidStudent);
+    require!(attestation.expiry > current_time, SolLearningError::AttestationExpired);
+    require!(
+        attestation.amount > 0 && attestation.amount <= educator.mint_limit,
+        SolLearningError::InvalidAmount
+    );
+    Ok(())
+}
+
+fn emit_events(ctx: &Context<RedeemCompletionAttestation>, attestation: &CompletionAttestation, timestamp: i64) -> Result<()> {
+    emit!(AttestationRedeemed {
+        student: ctx.accounts.student.key(),
+        course_id: attestation.course_id.clone(),
+        educator: ctx.accounts.educator.key(),
+        amount: attestation.amount,
+        nonce: attestation.nonce,
+        timestamp,
+    });
+
+    emit!(CourseCompleted {
+        student: ctx.accounts.student.key(),
+        course_id: attestation.course_id.clone(),
+        educator: ctx.accounts.educator.key(),
+        tokens_awarded: attestation.amount,
+        timestamp,
+    });
+
+    emit!(TokensMinted {
+        recipient: ctx.accounts.student.key(),
+        amount: attestation.amount,
+        minted_by: ctx.accounts.educator.educator_address,
+        timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::states::program::ProgramState;
+use crate::states::config::ProgramConfig;
+use crate::states::educator::EducatorAccount;
+use crate::states::student::StudentInfo;
+use crate::states::course::{Course, CourseCompletion, CompletionAttestation};
+use crate::constants::*;
+use crate::error::SolLearningError;
+
+#[derive(Accounts)]
+#[instruction(attestation: CompletionAttestation)]
+pub struct RedeemCompletionAttestation<'info> {
+    #[account(mut)]
+    pub student: Signer<'info>,
+
+    #[account(mut)]
+    pub educator: Account<'info, EducatorAccount>,
+
+    #[account(mut)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(seeds = [CONFIG_SEED], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// CHECK: This is the token mint that will be used to mint tokens to the student
+    #[account(mut, address = program_state.token_mint @ SolLearningError::InvalidMint)]
+    pub token_mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [STUDENT_SEED, student.key().as_ref()],
+        bump = student_info.bump,
+    )]
+    pub student_info: Account<'info, StudentInfo>,
+
+    #[account(
+        mut,
+        constraint = student_token_account.mint == program_state.token_mint @ SolLearningError::InvalidMint,
+        constraint = student_token_account.owner == student.key() @ SolLearningError::InvalidTokenAccount,
+    )]
+    pub student_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, student.key().as_ref()], bump)]
+    pub student_blocklist: UncheckedAccount<'info>,
+
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, educator.educator_address.as_ref()], bump)]
+    pub educator_blocklist: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [COURSE_SEED, educator.key().as_ref(), attestation.course_id.as_bytes()],
+        bump = course.bump,
+        constraint = course.educator == educator.key() @ SolLearningError::CourseNotOwnedByEducator,
+        constraint = course.verifier_threshold == 0 @ SolLearningError::CourseRequiresVerification,
+        constraint = course.co_educator_count == 0 @ SolLearningError::CoTaughtCourseUnsupported,
+    )]
+    pub course: Account<'info, Course>,
+
+    #[account(
+        init,
+        payer = student,
+        space = 8 + std::mem::size_of::<CourseCompletion>(),
+        seeds = [COURSE_COMPLETION_SEED, student.key().as_ref(), attestation.course_id.as_bytes()],
+        bump,
+        constraint = course.is_active @ SolLearningError::CourseInactive,
+    )]
+    pub course_completion: Account<'info, CourseCompletion>,
+
+    /// CHECK: Instructions sysvar used to introspect the ed25519 verification instruction
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
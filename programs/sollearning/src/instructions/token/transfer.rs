@@ -3,11 +3,19 @@ use anchor_spl::token::{self};
 use crate::error::SolLearningError;
 use crate::instructions::token::transfer_struct::TransferInstruction;
 use crate::utils::pause::{check_program_running, check_function_running};
+use crate::utils::blocklist::check_not_blocklisted;
 use crate::constants::PAUSE_FLAG_TRANSFER;
 
 pub fn transfer_handler(ctx: Context<TransferInstruction>, amount: u64) -> Result<()> {
     check_program_running(&ctx.accounts.program_state)?;
     check_function_running(&ctx.accounts.program_state, PAUSE_FLAG_TRANSFER)?;
+
+    require!(
+        token::accessor::authority(&ctx.accounts.to)? == ctx.accounts.recipient.key(),
+        SolLearningError::InvalidTokenAccount
+    );
+    check_not_blocklisted(&ctx.accounts.sender_blocklist, ctx.program_id)?;
+    check_not_blocklisted(&ctx.accounts.recipient_blocklist, ctx.program_id)?;
     
     validate_transfer_amount(amount)?;
     
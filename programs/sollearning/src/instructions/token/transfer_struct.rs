@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Token;
 use crate::states::program::ProgramState;
+use crate::constants::BLOCKLIST_SEED;
 
 #[derive(Accounts)]
 #[instruction(amount: u64)]
@@ -16,6 +17,17 @@ pub struct TransferInstruction<'info> {
     #[account(mut)]
     pub to: AccountInfo<'info>,
 
+    /// CHECK: owner of `to`, checked against the token account in the handler
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, sender.key().as_ref()], bump)]
+    pub sender_blocklist: UncheckedAccount<'info>,
+
+    /// CHECK: blocklist entry PDA; only its existence is checked
+    #[account(seeds = [BLOCKLIST_SEED, recipient.key().as_ref()], bump)]
+    pub recipient_blocklist: UncheckedAccount<'info>,
+
     /// CHECK: checked
     #[account(mut)]
     pub token_mint: AccountInfo<'info>,
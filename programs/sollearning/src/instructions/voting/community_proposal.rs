@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use crate::states::proposal::{ProposalInstruction, ProposalStatus};
+use crate::states::voting::{CommunityProposalCreated, VoteCast, CommunityProposalFinalized};
+use crate::constants::*;
+use crate::error::SolLearningError;
+use crate::instructions::voting::community_proposal_struct::{CreateCommunityProposal, CastVote, FinalizeCommunityProposal};
+use crate::utils::governance::validate_custom_instruction;
+use crate::utils::pause::check_governance_running;
+use crate::utils::voting::{check_community_scope, get_voting_weight, community_proposal_passed};
+use crate::utils::execution::{ProposalExecution, execute_proposal_instruction};
+
+pub fn create_community_proposal_handler(
+    ctx: Context<CreateCommunityProposal>,
+    instruction: ProposalInstruction,
+    description: String,
+) -> Result<()> {
+    check_governance_running(&ctx.accounts.program_state, &instruction)?;
+    require!(ctx.accounts.voter_stake.amount > 0, SolLearningError::NoVotingWeight);
+    require!(description.len() <= MAX_DESCRIPTION_LENGTH, SolLearningError::DescriptionTooLong);
+    check_community_scope(&ctx.accounts.program_config, &instruction)?;
+
+    let community_governance = Pubkey::find_program_address(&[COMMUNITY_GOVERNANCE_SEED], ctx.program_id).0;
+    validate_custom_instruction(&instruction, &community_governance, ctx.program_id)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let config = &mut ctx.accounts.program_config;
+    let voting_ends_at = current_time
+        .checked_add(config.voting_period)
+        .ok_or(SolLearningError::Overflow)?;
+    let executable_at = voting_ends_at
+        .checked_add(config.proposal_delays[instruction.kind()])
+        .ok_or(SolLearningError::Overflow)?;
+
+    let index = config.community_proposal_count;
+    config.community_proposal_count = index.checked_add(1).ok_or(SolLearningError::Overflow)?;
+
+    let proposal = &mut ctx.accounts.community_proposal;
+    proposal.index = index;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.instruction = instruction.clone();
+    proposal.description = description.clone();
+    proposal.yes_weight = 0;
+    proposal.no_weight = 0;
+    proposal.status = ProposalStatus::Active;
+    proposal.created_at = current_time;
+    proposal.voting_ends_at = voting_ends_at;
+    proposal.executable_at = executable_at;
+    proposal.closed_at = None;
+    proposal.bump = ctx.bumps.community_proposal;
+
+    emit!(CommunityProposalCreated {
+        proposal: proposal.key(),
+        index,
+        proposer: proposal.proposer,
+        instruction,
+        description,
+        voting_ends_at,
+        executable_at,
+        timestamp: current_time,
+    });
+
+    msg!("Created community proposal #{}, voting ends at {}", index, voting_ends_at);
+
+    Ok(())
+}
+
+// The stake stays locked until voting ends so the same tokens cannot be
+// withdrawn and restaked under another key.
+pub fn cast_vote_handler(ctx: Context<CastVote>, approve: bool) -> Result<()> {
+    check_governance_running(&ctx.accounts.program_state, &ctx.accounts.community_proposal.instruction)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.community_proposal;
+    require!(current_time < proposal.voting_ends_at, SolLearningError::VotingClosed);
+
+    let weight = get_voting_weight(&ctx.accounts.voter_stake, proposal)?;
+
+    if approve {
+        proposal.yes_weight = proposal.yes_weight.checked_add(weight).ok_or(SolLearningError::Overflow)?;
+    } else {
+        proposal.no_weight = proposal.no_weight.checked_add(weight).ok_or(SolLearningError::Overflow)?;
+    }
+
+    let voter_stake = &mut ctx.accounts.voter_stake;
+    voter_stake.locked_until = voter_stake.locked_until.max(proposal.voting_ends_at);
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.proposal = proposal.key();
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.approve = approve;
+    vote_record.weight = weight;
+    vote_record.voted_at = current_time;
+    vote_record.bump = ctx.bumps.vote_record;
+
+    emit!(VoteCast {
+        proposal: proposal.key(),
+        voter: vote_record.voter,
+        approve,
+        weight,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Vote {} with weight {} on community proposal #{}",
+        if approve { "for" } else { "against" },
+        weight,
+        proposal.index
+    );
+
+    Ok(())
+}
+
+// A proposal that misses quorum or threshold is closed as Rejected. One that
+// passes runs through the same execution path as multisig proposals, with the
+// community governance PDA as the acting authority, once its timelock is over.
+pub fn finalize_community_proposal_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalizeCommunityProposal<'info>>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let proposal = &ctx.accounts.community_proposal;
+    require!(current_time >= proposal.voting_ends_at, SolLearningError::VotingStillOpen);
+
+    let passed = community_proposal_passed(&ctx.accounts.program_config, proposal.yes_weight, proposal.no_weight);
+
+    let status = if passed {
+        require!(current_time >= proposal.executable_at, SolLearningError::ProposalTimelockActive);
+        check_community_scope(&ctx.accounts.program_config, &proposal.instruction)?;
+
+        let instruction = proposal.instruction.clone();
+        let governance_bump = [ctx.bumps.community_governance];
+        let governance_seeds: &[&[u8]] = &[COMMUNITY_GOVERNANCE_SEED, &governance_bump];
+
+        let mut execution = ProposalExecution {
+            program_id: ctx.program_id,
+            payer: ctx.accounts.executor.to_account_info(),
+            governance: ctx.accounts.community_governance.to_account_info(),
+            governance_seeds,
+            program_state: &mut ctx.accounts.program_state,
+            program_config: &mut ctx.accounts.program_config,
+            multisig: None,
+            system_program: ctx.accounts.system_program.to_account_info(),
+            remaining_accounts: ctx.remaining_accounts,
+        };
+        execute_proposal_instruction(&mut execution, instruction, current_time)?;
+        ProposalStatus::Executed
+    } else {
+        ProposalStatus::Rejected
+    };
+
+    let proposal = &mut ctx.accounts.community_proposal;
+    proposal.status = status.clone();
+    proposal.closed_at = Some(current_time);
+
+    emit!(CommunityProposalFinalized {
+        proposal: proposal.key(),
+        status,
+        yes_weight: proposal.yes_weight,
+        no_weight: proposal.no_weight,
+        finalized_by: ctx.accounts.executor.key(),
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Community proposal #{} {} with {} for and {} against",
+        proposal.index,
+        if passed { "executed" } else { "rejected" },
+        proposal.yes_weight,
+        proposal.no_weight
+    );
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::config::ProgramConfig;
+use crate::states::proposal::{ProposalInstruction, ProposalStatus};
+use crate::states::voting::{VoterStake, CommunityProposal, VoteRecord};
+use crate::instructions::proposal::create_proposal_struct::calculate_instruction_space;
+use crate::constants::*;
+use crate::error::SolLearningError;
+
+#[derive(Accounts)]
+#[instruction(instruction: ProposalInstruction, description: String)]
+pub struct CreateCommunityProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [VOTER_STAKE_SEED, proposer.key().as_ref()],
+        bump = voter_stake.bump,
+        constraint = voter_stake.voter == proposer.key() @ SolLearningError::Unauthorized,
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+
+    #[account(mut, seeds = [CONFIG_SEED], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = calculate_community_proposal_space(&instruction, &description),
+        seeds = [COMMUNITY_PROPOSAL_SEED, &program_config.community_proposal_count.to_le_bytes()],
+        bump,
+    )]
+    pub community_proposal: Account<'info, CommunityProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [COMMUNITY_PROPOSAL_SEED, &community_proposal.index.to_le_bytes()],
+        bump = community_proposal.bump,
+        constraint = community_proposal.status == ProposalStatus::Active @ SolLearningError::InvalidProposalStatus,
+    )]
+    pub community_proposal: Account<'info, CommunityProposal>,
+
+    #[account(
+        mut,
+        seeds = [VOTER_STAKE_SEED, voter.key().as_ref()],
+        bump = voter_stake.bump,
+        has_one = voter,
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    // One record per voter and proposal; a second vote fails on init.
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + std::mem::size_of::<VoteRecord>(),
+        seeds = [VOTE_RECORD_SEED, community_proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Anyone may finalize once voting has closed. Instruction-specific accounts are
+// passed through remaining accounts, see utils::execution::ProposalExecution.
+#[derive(Accounts)]
+pub struct FinalizeCommunityProposal<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [COMMUNITY_PROPOSAL_SEED, &community_proposal.index.to_le_bytes()],
+        bump = community_proposal.bump,
+        constraint = community_proposal.status == ProposalStatus::Active @ SolLearningError::InvalidProposalStatus,
+    )]
+    pub community_proposal: Account<'info, CommunityProposal>,
+
+    #[account(mut, seeds = [CONFIG_SEED], bump = program_config.bump)]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(mut, seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// CHECK: Signing PDA for community governance, holds no data
+    #[account(seeds = [COMMUNITY_GOVERNANCE_SEED], bump)]
+    pub community_governance: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn calculate_community_proposal_space(instruction: &ProposalInstruction, description: &str) -> usize {
+    8 + 8 + 32 + calculate_instruction_space(instruction) + 4 + description.len() + 8 + 8 + 1 + 8 + 8 + 8 + 9 + 1
+}
@@ -0,0 +1,9 @@
+pub mod vote_stake;
+pub mod community_proposal;
+pub mod vote_stake_struct;
+pub mod community_proposal_struct;
+
+pub use vote_stake::{create_voter_stake_handler, deposit_vote_stake_handler, withdraw_vote_stake_handler};
+pub use community_proposal::{
+    create_community_proposal_handler, cast_vote_handler, finalize_community_proposal_handler,
+};
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use crate::states::voting::{VoteStakeDeposited, VoteStakeWithdrawn};
+use crate::constants::*;
+use crate::error::SolLearningError;
+use crate::instructions::voting::vote_stake_struct::{CreateVoterStake, UpdateVoteStake};
+use crate::utils::pause::check_program_running;
+
+pub fn create_voter_stake_handler(ctx: Context<CreateVoterStake>) -> Result<()> {
+    let voter_stake = &mut ctx.accounts.voter_stake;
+    voter_stake.voter = ctx.accounts.voter.key();
+    voter_stake.amount = 0;
+    voter_stake.last_deposit_at = 0;
+    voter_stake.locked_until = 0;
+    voter_stake.bump = ctx.bumps.voter_stake;
+
+    msg!("Created vote stake for {}", ctx.accounts.voter.key());
+
+    Ok(())
+}
+
+pub fn deposit_vote_stake_handler(ctx: Context<UpdateVoteStake>, amount: u64) -> Result<()> {
+    check_program_running(&ctx.accounts.program_state)?;
+    require!(amount > 0, SolLearningError::InvalidAmount);
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.voter_token_account.to_account_info(),
+                to: ctx.accounts.vote_vault.to_account_info(),
+                authority: ctx.accounts.voter.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let voter_stake = &mut ctx.accounts.voter_stake;
+    voter_stake.amount = voter_stake
+        .amount
+        .checked_add(amount)
+        .ok_or(SolLearningError::Overflow)?;
+    voter_stake.last_deposit_at = current_time;
+
+    emit!(VoteStakeDeposited {
+        voter: voter_stake.voter,
+        amount,
+        total_staked: voter_stake.amount,
+        timestamp: current_time,
+    });
+
+    msg!("Staked {} tokens for voting, total {}", amount, voter_stake.amount);
+
+    Ok(())
+}
+
+// Withdrawals stay available while the program is paused; only an open vote
+// keeps the stake locked.
+pub fn withdraw_vote_stake_handler(ctx: Context<UpdateVoteStake>, amount: u64) -> Result<()> {
+    require!(amount > 0, SolLearningError::InvalidAmount);
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let voter_stake = &ctx.accounts.voter_stake;
+    require!(current_time >= voter_stake.locked_until, SolLearningError::VoteStakeLocked);
+    require!(voter_stake.amount >= amount, SolLearningError::InsufficientVoteStake);
+
+    let voter_key = voter_stake.voter;
+    let signer_seeds: &[&[u8]] = &[VOTER_STAKE_SEED, voter_key.as_ref(), &[voter_stake.bump]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.vote_vault.to_account_info(),
+                to: ctx.accounts.voter_token_account.to_account_info(),
+                authority: ctx.accounts.voter_stake.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        amount,
+    )?;
+
+    let voter_stake = &mut ctx.accounts.voter_stake;
+    voter_stake.amount -= amount;
+
+    emit!(VoteStakeWithdrawn {
+        voter: voter_key,
+        amount,
+        total_staked: voter_stake.amount,
+        timestamp: current_time,
+    });
+
+    msg!("Withdrew {} tokens of vote stake, remaining {}", amount, voter_stake.amount);
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::states::program::ProgramState;
+use crate::states::voting::VoterStake;
+use crate::constants::{PROGRAM_STATE_SEED, VOTER_STAKE_SEED, VOTE_VAULT_SEED};
+use crate::error::SolLearningError;
+
+#[derive(Accounts)]
+pub struct CreateVoterStake<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(address = program_state.token_mint)]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + std::mem::size_of::<VoterStake>(),
+        seeds = [VOTER_STAKE_SEED, voter.key().as_ref()],
+        bump,
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+
+    // Each voter's tokens sit in their own vault owned by their stake PDA, so
+    // no other signer the program holds can move them.
+    #[account(
+        init,
+        payer = voter,
+        token::mint = token_mint,
+        token::authority = voter_stake,
+        seeds = [VOTE_VAULT_SEED, voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Shared by deposit_vote_stake and withdraw_vote_stake
+#[derive(Accounts)]
+pub struct UpdateVoteStake<'info> {
+    pub voter: Signer<'info>,
+
+    #[account(seeds = [PROGRAM_STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [VOTER_STAKE_SEED, voter.key().as_ref()],
+        bump = voter_stake.bump,
+        has_one = voter,
+    )]
+    pub voter_stake: Account<'info, VoterStake>,
+
+    #[account(mut, seeds = [VOTE_VAULT_SEED, voter.key().as_ref()], bump)]
+    pub vote_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = voter_token_account.owner == voter.key() @ SolLearningError::InvalidTokenAccount,
+        constraint = voter_token_account.mint == program_state.token_mint @ SolLearningError::InvalidTokenAccount,
+    )]
+    pub voter_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -7,6 +7,10 @@ pub mod instructions;
 pub mod utils;
 
 use crate::states::proposal::ProposalInstruction;
+use crate::states::course::CompletionAttestation;
+use crate::states::emergency::PauseReason;
+use crate::states::dispute::DisputeKind;
+use crate::states::course::CoEducatorShare;
 
 // Import handlers
 use instructions::educator::*;
@@ -18,6 +22,9 @@ use instructions::emergency::*;
 use instructions::token::*;
 use instructions::config::*;
 use instructions::initialize::*;
+use instructions::voting::*;
+use instructions::blocklist::*;
+use instructions::dispute::*;
 
 // Import structs
 use instructions::proposal::approve_proposal_struct::ApproveProposal;
@@ -27,7 +34,10 @@ use instructions::multisig::create_multisig_struct::CreateMultisig;
 use instructions::proposal::create_proposal_struct::CreateProposal;
 use instructions::student::create_student_account_struct::CreateStudentTokenAccount;
 use instructions::emergency::emergency_toggle_struct::EmergencyToggle;
+use instructions::emergency::pause_schedule_struct::ResumeIfDue;
 use instructions::proposal::execute_proposal_struct::ExecuteProposal;
+use instructions::proposal::veto_proposal_struct::VetoProposal;
+use instructions::proposal::expire_proposal_struct::ExpireProposal;
 use instructions::initialize::initialize_struct::InitializeAccounts;
 use instructions::token::mint_struct::MintToStudent;
 use instructions::educator::register_educator_struct::RegisterEducator;
@@ -35,14 +45,41 @@ use instructions::student::register_student_struct::RegisterStudent;
 use instructions::educator::set_educator_status_struct::SetEducatorStatus;
 use instructions::token::transfer_struct::TransferInstruction;
 use instructions::course::update_course_struct::UpdateCourse;
+use instructions::course::revoke_completion_struct::{CompletionRevocationRequest, RevokeCompletion};
+use instructions::course::course_verifiers_struct::{CreateCourseVerifiers, UpdateCourseVerifiers};
+use instructions::course::verify_completion_struct::VerifyCompletion;
+use instructions::course::co_educators_struct::{CreateCourseCoEducators, UpdateCourseCoEducators};
+use instructions::course::rate_course_struct::{RateCourse, UpdateCourseRating};
+use instructions::student::approve_clawback_delegate_struct::ApproveClawbackDelegate;
 use instructions::config::create_program_config_struct::CreateProgramConfig;
 use instructions::config::update_program_config_struct::UpdateProgramConfig;
+use instructions::config::authority_transfer_struct::{ProposeAuthorityTransfer, CancelAuthorityTransfer, AcceptAuthorityTransfer};
+use instructions::config::voting_config_struct::UpdateVotingConfig;
+use instructions::config::circuit_breaker_struct::UpdateCircuitBreakers;
+use instructions::config::dispute_config_struct::UpdateDisputeConfig;
+use instructions::educator::reputation_tier_struct::RefreshEducatorTier;
+use instructions::dispute::open_dispute_struct::OpenDispute;
+use instructions::dispute::review_dispute_struct::ReviewDispute;
+use instructions::dispute::settle_dispute_struct::SettleDisputeMint;
+use instructions::dispute::close_dispute_struct::CloseDispute;
+use instructions::voting::vote_stake_struct::{CreateVoterStake, UpdateVoteStake};
+use instructions::voting::community_proposal_struct::{CreateCommunityProposal, CastVote, FinalizeCommunityProposal};
 use instructions::multisig::create_emergency_multisig_struct::CreateEmergencyMultisig;
+use instructions::token::batch_mint_struct::BatchMintCompletions;
+use instructions::token::redeem_attestation_struct::RedeemCompletionAttestation;
+use instructions::educator::grant_operator_struct::GrantOperator;
+use instructions::educator::revoke_operator_struct::RevokeOperator;
+use instructions::educator::rotate_educator_key_struct::{RequestEducatorKeyRotation, CancelEducatorKeyRotation, RotateEducatorKey};
+use instructions::blocklist::block_address_struct::{BlockAddress, AppealBlock, UnblockAddress};
+use instructions::blocklist::freeze_account_struct::SetBlockedAccountFrozen;
+use instructions::educator::educator_application_struct::{ApplyAsEducator, ApproveEducatorApplication, RejectEducatorApplication};
 
 // Import _client_accounts_ (mantido conforme solicitado)
 use instructions::proposal::create_proposal_struct::__client_accounts_create_proposal;
 use instructions::proposal::approve_proposal_struct::__client_accounts_approve_proposal;
 use instructions::proposal::execute_proposal_struct::__client_accounts_execute_proposal;
+use instructions::proposal::veto_proposal_struct::__client_accounts_veto_proposal;
+use instructions::proposal::expire_proposal_struct::__client_accounts_expire_proposal;
 use instructions::token::mint_struct::__client_accounts_mint_to_student;
 use instructions::token::burn_struct::__client_accounts_burn_instruction;
 use instructions::token::transfer_struct::__client_accounts_transfer_instruction;
@@ -53,12 +90,52 @@ use instructions::educator::set_educator_status_struct::__client_accounts_set_ed
 use instructions::multisig::create_multisig_struct::__client_accounts_create_multisig;
 use instructions::multisig::create_emergency_multisig_struct::__client_accounts_create_emergency_multisig;
 use instructions::emergency::emergency_toggle_struct::__client_accounts_emergency_toggle;
+use instructions::emergency::pause_schedule_struct::__client_accounts_resume_if_due;
 use instructions::course::create_course_struct::__client_accounts_create_course;
 use instructions::course::update_course_struct::__client_accounts_update_course;
 use instructions::config::create_program_config_struct::__client_accounts_create_program_config;
 use instructions::config::update_program_config_struct::__client_accounts_update_program_config;
+use instructions::config::authority_transfer_struct::__client_accounts_propose_authority_transfer;
+use instructions::config::authority_transfer_struct::__client_accounts_cancel_authority_transfer;
+use instructions::config::authority_transfer_struct::__client_accounts_accept_authority_transfer;
+use instructions::config::voting_config_struct::__client_accounts_update_voting_config;
+use instructions::config::circuit_breaker_struct::__client_accounts_update_circuit_breakers;
+use instructions::voting::vote_stake_struct::__client_accounts_create_voter_stake;
+use instructions::voting::vote_stake_struct::__client_accounts_update_vote_stake;
+use instructions::voting::community_proposal_struct::__client_accounts_create_community_proposal;
+use instructions::voting::community_proposal_struct::__client_accounts_cast_vote;
+use instructions::voting::community_proposal_struct::__client_accounts_finalize_community_proposal;
 use instructions::initialize::initialize_struct::__client_accounts_initialize_accounts;
-
+use instructions::token::batch_mint_struct::__client_accounts_batch_mint_completions;
+use instructions::token::redeem_attestation_struct::__client_accounts_redeem_completion_attestation;
+use instructions::educator::grant_operator_struct::__client_accounts_grant_operator;
+use instructions::educator::revoke_operator_struct::__client_accounts_revoke_operator;
+use instructions::educator::rotate_educator_key_struct::__client_accounts_request_educator_key_rotation;
+use instructions::educator::rotate_educator_key_struct::__client_accounts_cancel_educator_key_rotation;
+use instructions::educator::rotate_educator_key_struct::__client_accounts_rotate_educator_key;
+use instructions::educator::educator_application_struct::__client_accounts_apply_as_educator;
+use instructions::educator::educator_application_struct::__client_accounts_approve_educator_application;
+use instructions::educator::educator_application_struct::__client_accounts_reject_educator_application;
+use instructions::course::revoke_completion_struct::__client_accounts_completion_revocation_request;
+use instructions::course::revoke_completion_struct::__client_accounts_revoke_completion;
+use instructions::course::course_verifiers_struct::__client_accounts_create_course_verifiers;
+use instructions::course::course_verifiers_struct::__client_accounts_update_course_verifiers;
+use instructions::course::verify_completion_struct::__client_accounts_verify_completion;
+use instructions::course::co_educators_struct::__client_accounts_create_course_co_educators;
+use instructions::course::co_educators_struct::__client_accounts_update_course_co_educators;
+use instructions::course::rate_course_struct::__client_accounts_rate_course;
+use instructions::course::rate_course_struct::__client_accounts_update_course_rating;
+use instructions::student::approve_clawback_delegate_struct::__client_accounts_approve_clawback_delegate;
+use instructions::config::dispute_config_struct::__client_accounts_update_dispute_config;
+use instructions::educator::reputation_tier_struct::__client_accounts_refresh_educator_tier;
+use instructions::dispute::open_dispute_struct::__client_accounts_open_dispute;
+use instructions::dispute::review_dispute_struct::__client_accounts_review_dispute;
+use instructions::dispute::settle_dispute_struct::__client_accounts_settle_dispute_mint;
+use instructions::dispute::close_dispute_struct::__client_accounts_close_dispute;
+use instructions::blocklist::block_address_struct::__client_accounts_block_address;
+use instructions::blocklist::block_address_struct::__client_accounts_appeal_block;
+use instructions::blocklist::block_address_struct::__client_accounts_unblock_address;
+use instructions::blocklist::freeze_account_struct::__client_accounts_set_blocked_account_frozen;
 
 declare_id!("3zYMcbfRqRnam7pncxKdiJkeZ6V7yg9Ub3SfXSsCjA5p");
 
@@ -82,6 +159,59 @@ pub mod sollearning {
         set_educator_status_handler(ctx, is_active, new_mint_limit)
     }
 
+    pub fn grant_operator(
+        ctx: Context<GrantOperator>,
+        operator: Pubkey,
+        permissions: u8,
+        mint_allowance: u64,
+        expires_at: i64,
+    ) -> Result<()> {
+        grant_operator_handler(ctx, operator, permissions, mint_allowance, expires_at)
+    }
+
+    pub fn revoke_operator(ctx: Context<RevokeOperator>) -> Result<()> {
+        revoke_operator_handler(ctx)
+    }
+
+    pub fn request_educator_key_rotation(
+        ctx: Context<RequestEducatorKeyRotation>,
+        new_educator_address: Pubkey,
+        new_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        request_educator_key_rotation_handler(ctx, new_educator_address, new_authority)
+    }
+
+    pub fn cancel_educator_key_rotation(ctx: Context<CancelEducatorKeyRotation>) -> Result<()> {
+        cancel_educator_key_rotation_handler(ctx)
+    }
+
+    pub fn rotate_educator_key(ctx: Context<RotateEducatorKey>) -> Result<()> {
+        rotate_educator_key_handler(ctx)
+    }
+
+    pub fn apply_as_educator(
+        ctx: Context<ApplyAsEducator>,
+        profile_hash: [u8; 32],
+        requested_mint_limit: u64,
+    ) -> Result<()> {
+        apply_as_educator_handler(ctx, profile_hash, requested_mint_limit)
+    }
+
+    pub fn approve_educator_application(
+        ctx: Context<ApproveEducatorApplication>,
+        mint_limit: Option<u64>,
+    ) -> Result<()> {
+        approve_educator_application_handler(ctx, mint_limit)
+    }
+
+    pub fn reject_educator_application(ctx: Context<RejectEducatorApplication>) -> Result<()> {
+        reject_educator_application_handler(ctx)
+    }
+
+    pub fn refresh_educator_tier(ctx: Context<RefreshEducatorTier>) -> Result<()> {
+        refresh_educator_tier_handler(ctx)
+    }
+
     pub fn register_student(ctx: Context<RegisterStudent>) -> Result<()> {
         register_student_handler(ctx)
     }
@@ -89,6 +219,10 @@ pub mod sollearning {
     pub fn create_student_token_account(ctx: Context<CreateStudentTokenAccount>) -> Result<()> {
         create_student_token_account_handler(ctx)
     }
+
+    pub fn approve_clawback_delegate(ctx: Context<ApproveClawbackDelegate>) -> Result<()> {
+        approve_clawback_delegate_handler(ctx)
+    }
     
     pub fn create_course(
         ctx: Context<CreateCourse>, 
@@ -100,8 +234,8 @@ pub mod sollearning {
         create_course_handler(ctx, course_id, course_name, reward_amount, metadata_hash)
     }
     
-    pub fn update_course(
-        ctx: Context<UpdateCourse>,
+    pub fn update_course<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateCourse<'info>>,
         course_name: Option<String>,
         reward_amount: Option<u64>,
         is_active: Option<bool>,
@@ -110,11 +244,107 @@ pub mod sollearning {
     ) -> Result<()> {
         update_course_handler(ctx, course_name, reward_amount, is_active, metadata_hash, change_description) 
     }
+
+    pub fn request_completion_revocation(
+        ctx: Context<CompletionRevocationRequest>,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        request_completion_revocation_handler(ctx, reason_hash)
+    }
+
+    pub fn cancel_completion_revocation(ctx: Context<CompletionRevocationRequest>) -> Result<()> {
+        cancel_completion_revocation_handler(ctx)
+    }
+
+    pub fn revoke_completion<'info>(ctx: Context<'_, '_, 'info, 'info, RevokeCompletion<'info>>) -> Result<()> {
+        revoke_completion_handler(ctx)
+    }
+
+    pub fn create_course_verifiers(
+        ctx: Context<CreateCourseVerifiers>,
+        verifiers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        create_course_verifiers_handler(ctx, verifiers, threshold)
+    }
+
+    pub fn update_course_verifiers(
+        ctx: Context<UpdateCourseVerifiers>,
+        verifiers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        update_course_verifiers_handler(ctx, verifiers, threshold)
+    }
+
+    pub fn verify_completion<'info>(ctx: Context<'_, '_, 'info, 'info, VerifyCompletion<'info>>) -> Result<()> {
+        verify_completion_handler(ctx)
+    }
+
+    pub fn create_course_co_educators(
+        ctx: Context<CreateCourseCoEducators>,
+        lead_weight: u16,
+        co_educators: Vec<CoEducatorShare>,
+        reward_percent: u8,
+    ) -> Result<()> {
+        create_course_co_educators_handler(ctx, lead_weight, co_educators, reward_percent)
+    }
+
+    pub fn update_course_co_educators<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateCourseCoEducators<'info>>,
+        lead_weight: u16,
+        co_educators: Vec<CoEducatorShare>,
+        reward_percent: u8,
+    ) -> Result<()> {
+        update_course_co_educators_handler(ctx, lead_weight, co_educators, reward_percent)
+    }
+
+    pub fn rate_course(ctx: Context<RateCourse>, rating: u8, review_hash: [u8; 32]) -> Result<()> {
+        rate_course_handler(ctx, rating, review_hash)
+    }
+
+    pub fn update_course_rating(ctx: Context<UpdateCourseRating>, rating: u8, review_hash: [u8; 32]) -> Result<()> {
+        update_course_rating_handler(ctx, rating, review_hash)
+    }
+
+    pub fn open_dispute(ctx: Context<OpenDispute>, kind: DisputeKind, evidence_hash: [u8; 32]) -> Result<()> {
+        open_dispute_handler(ctx, kind, evidence_hash)
+    }
+
+    pub fn review_dispute(ctx: Context<ReviewDispute>, uphold: bool) -> Result<()> {
+        review_dispute_handler(ctx, uphold)
+    }
+
+    pub fn settle_dispute_mint<'info>(ctx: Context<'_, '_, 'info, 'info, SettleDisputeMint<'info>>) -> Result<()> {
+        settle_dispute_mint_handler(ctx)
+    }
+
+    pub fn close_dispute(ctx: Context<CloseDispute>) -> Result<()> {
+        close_dispute_handler(ctx)
+    }
     
-    pub fn mint_to_student(ctx: Context<MintToStudent>, amount: u64, course_id: String) -> Result<()> {
+    pub fn mint_to_student<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintToStudent<'info>>,
+        amount: u64,
+        course_id: String,
+    ) -> Result<()> {
         mint_to_student_handler(ctx, amount, course_id)
     }
 
+    pub fn batch_mint_completions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchMintCompletions<'info>>,
+        amount: u64,
+        course_id: String,
+    ) -> Result<()> {
+        batch_mint_completions_handler(ctx, amount, course_id)
+    }
+
+    pub fn redeem_completion_attestation(
+        ctx: Context<RedeemCompletionAttestation>,
+        attestation: CompletionAttestation,
+    ) -> Result<()> {
+        redeem_completion_attestation_handler(ctx, attestation)
+    }
+
     pub fn transfer(ctx: Context<TransferInstruction>, amount: u64) -> Result<()> {
         transfer_handler(ctx, amount)
     }
@@ -123,20 +353,57 @@ pub mod sollearning {
         burn_handler(ctx, amount)
     }
 
-    pub fn emergency_toggle(ctx: Context<EmergencyToggle>, paused: bool) -> Result<()> {
-        emergency_toggle_handler(ctx, paused)
+    pub fn emergency_toggle(
+        ctx: Context<EmergencyToggle>,
+        paused: bool,
+        reason: Option<PauseReason>,
+    ) -> Result<()> {
+        emergency_toggle_handler(ctx, paused, reason)
+    }
+
+    pub fn resume_if_due(ctx: Context<ResumeIfDue>) -> Result<()> {
+        resume_if_due_handler(ctx)
+    }
+
+    pub fn block_address(ctx: Context<BlockAddress>, reason_hash: [u8; 32]) -> Result<()> {
+        block_address_handler(ctx, reason_hash)
+    }
+
+    pub fn appeal_block(ctx: Context<AppealBlock>, appeal_hash: [u8; 32]) -> Result<()> {
+        appeal_block_handler(ctx, appeal_hash)
+    }
+
+    pub fn unblock_address(ctx: Context<UnblockAddress>) -> Result<()> {
+        unblock_address_handler(ctx)
+    }
+
+    pub fn set_blocked_account_frozen(ctx: Context<SetBlockedAccountFrozen>, frozen: bool) -> Result<()> {
+        set_blocked_account_frozen_handler(ctx, frozen)
     }
 
     pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
         approve_proposal_handler(ctx)
     }
     
-    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    pub fn execute_proposal<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>) -> Result<()> {
         execute_proposal_handler(ctx)
     }
 
-    pub fn create_proposal(ctx: Context<CreateProposal>, instruction: ProposalInstruction, description: String) -> Result<()> {
-        create_proposal_handler(ctx, instruction, description)
+    pub fn veto_proposal(ctx: Context<VetoProposal>) -> Result<()> {
+        veto_proposal_handler(ctx)
+    }
+
+    pub fn expire_proposal(ctx: Context<ExpireProposal>) -> Result<()> {
+        expire_proposal_handler(ctx)
+    }
+
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        instruction: ProposalInstruction,
+        description: String,
+        expiry: Option<i64>,
+    ) -> Result<()> {
+        create_proposal_handler(ctx, instruction, description, expiry)
     }
 
     pub fn create_multisig(ctx: Context<CreateMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
@@ -158,6 +425,85 @@ pub mod sollearning {
             mint_cooldown_period,
         )
     }
+
+    pub fn propose_authority_transfer(
+        ctx: Context<ProposeAuthorityTransfer>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        propose_authority_transfer_handler(ctx, new_authority)
+    }
+
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        cancel_authority_transfer_handler(ctx)
+    }
+
+    pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
+        accept_authority_transfer_handler(ctx)
+    }
+
+    pub fn set_circuit_breakers(
+        ctx: Context<UpdateCircuitBreakers>,
+        breaker_window_slots: u64,
+        breaker_supply_ratio_bps: u16,
+        breaker_near_cap_bps: u16,
+        breaker_near_cap_streak: u8,
+    ) -> Result<()> {
+        set_circuit_breakers_handler(
+            ctx,
+            breaker_window_slots,
+            breaker_supply_ratio_bps,
+            breaker_near_cap_bps,
+            breaker_near_cap_streak,
+        )
+    }
+
+    pub fn set_dispute_config(
+        ctx: Context<UpdateDisputeConfig>,
+        dispute_reviewer: Pubkey,
+        dispute_quorum: u8,
+    ) -> Result<()> {
+        set_dispute_config_handler(ctx, dispute_reviewer, dispute_quorum)
+    }
+
+    pub fn set_voting_config(
+        ctx: Context<UpdateVotingConfig>,
+        vote_quorum: u64,
+        vote_threshold_bps: u16,
+        voting_period: i64,
+        community_scope: u32,
+    ) -> Result<()> {
+        set_voting_config_handler(ctx, vote_quorum, vote_threshold_bps, voting_period, community_scope)
+    }
+
+    pub fn create_voter_stake(ctx: Context<CreateVoterStake>) -> Result<()> {
+        create_voter_stake_handler(ctx)
+    }
+
+    pub fn deposit_vote_stake(ctx: Context<UpdateVoteStake>, amount: u64) -> Result<()> {
+        deposit_vote_stake_handler(ctx, amount)
+    }
+
+    pub fn withdraw_vote_stake(ctx: Context<UpdateVoteStake>, amount: u64) -> Result<()> {
+        withdraw_vote_stake_handler(ctx, amount)
+    }
+
+    pub fn create_community_proposal(
+        ctx: Context<CreateCommunityProposal>,
+        instruction: ProposalInstruction,
+        description: String,
+    ) -> Result<()> {
+        create_community_proposal_handler(ctx, instruction, description)
+    }
+
+    pub fn cast_vote(ctx: Context<CastVote>, approve: bool) -> Result<()> {
+        cast_vote_handler(ctx, approve)
+    }
+
+    pub fn finalize_community_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeCommunityProposal<'info>>,
+    ) -> Result<()> {
+        finalize_community_proposal_handler(ctx)
+    }
     
     pub fn update_program_config(
         ctx: Context<UpdateProgramConfig>,
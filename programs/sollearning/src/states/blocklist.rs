@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+// Exists only while `address` is blocked; unblocking closes it. Token accounts
+// frozen under the block are counted so none is left frozen after an unblock.
+#[account]
+pub struct BlocklistEntry {
+    pub address: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub blocked_by: Pubkey,
+    pub blocked_at: i64,
+    pub appeal_hash: Option<[u8; 32]>,
+    pub appealed_at: Option<i64>,
+    pub frozen_accounts: u16,
+    pub bump: u8,
+}
+
+#[event]
+pub struct AddressBlocked {
+    pub address: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub blocked_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BlockAppealed {
+    pub address: Pubkey,
+    pub appeal_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AddressUnblocked {
+    pub address: Pubkey,
+    pub unblocked_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BlockedTokenAccountFrozen {
+    pub address: Pubkey,
+    pub token_account: Pubkey,
+    pub frozen: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::constants::{PROPOSAL_INSTRUCTION_KINDS, MAX_REPUTATION_TIERS};
+use crate::states::reputation::ReputationTier;
 
 #[account]
 pub struct ProgramConfig {
@@ -8,6 +10,26 @@ pub struct ProgramConfig {
     pub mint_cooldown_period: i64,
     pub authority: Pubkey,
     pub last_updated_at: i64,
+    pub proposal_delays: [i64; PROPOSAL_INSTRUCTION_KINDS],
+    pub guardian: Pubkey,
+    pub vote_quorum: u64,
+    pub vote_threshold_bps: u16,
+    pub voting_period: i64,
+    pub community_scope: u32,
+    pub community_proposal_count: u64,
+    pub min_proposal_expiry: i64,
+    pub max_proposal_expiry: i64,
+    pub proposal_thresholds: [u16; PROPOSAL_INSTRUCTION_KINDS],
+    pub breaker_window_slots: u64,
+    pub breaker_supply_ratio_bps: u16,
+    pub breaker_near_cap_bps: u16,
+    pub breaker_near_cap_streak: u8,
+    pub dispute_reviewer: Pubkey,
+    pub dispute_quorum: u8,
+    pub educator_reward_bps: u16,
+    pub educator_reward_budget: u64,
+    pub reputation_tiers: [ReputationTier; MAX_REPUTATION_TIERS],
+    pub reputation_tier_count: u8,
     pub bump: u8,
 }
 
@@ -15,4 +37,71 @@ pub struct ProgramConfig {
 pub struct ConfigUpdated {
     pub authority: Pubkey,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct ProposalDelayUpdated {
+    pub instruction_kind: u8,
+    pub delay: i64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardianUpdated {
+    pub guardian: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VotingConfigUpdated {
+    pub vote_quorum: u64,
+    pub vote_threshold_bps: u16,
+    pub voting_period: i64,
+    pub community_scope: u32,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalExpiryBoundsUpdated {
+    pub min_proposal_expiry: i64,
+    pub max_proposal_expiry: i64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalThresholdUpdated {
+    pub instruction_kind: u8,
+    pub threshold_bps: u16,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CircuitBreakersUpdated {
+    pub breaker_window_slots: u64,
+    pub breaker_supply_ratio_bps: u16,
+    pub breaker_near_cap_bps: u16,
+    pub breaker_near_cap_streak: u8,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeConfigUpdated {
+    pub dispute_reviewer: Pubkey,
+    pub dispute_quorum: u8,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EducatorRewardConfigUpdated {
+    pub educator_reward_bps: u16,
+    pub educator_reward_budget: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
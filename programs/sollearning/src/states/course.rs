@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::constants::MAX_CO_EDUCATORS;
 
 #[account]
 pub struct Course {
@@ -12,6 +13,10 @@ pub struct Course {
     pub created_at: i64,
     pub last_updated_at: i64,
     pub version: u32, 
+    pub verifier_threshold: u8,
+    pub co_educator_count: u8,
+    pub rating_count: u32,
+    pub rating_sum: u64,
     pub bump: u8,
 }
 
@@ -31,15 +36,95 @@ pub struct CourseHistory {
 }
 
 #[account]
+#[derive(Default)]
 pub struct CourseCompletion {
     pub student: Pubkey,
     pub course_id: String,
     pub verified_by: Pubkey,
     pub completion_time: i64,
     pub tokens_awarded: u64,
+    pub revocation_requested_at: Option<i64>,
+    pub revocation_reason_hash: [u8; 32],
+    pub revoked_at: Option<i64>,
+    // What the award credited to the lead educator and each co-educator, so
+    // revoke_completion can reverse it exactly
+    pub lead_share: u64,
+    pub co_educator_credits: CoEducatorCredits,
+    // Incentive minted to the lead educator on top of the award
+    pub educator_reward: u64,
     pub bump: u8,
 }
 
+pub type CoEducatorCredits = [CoEducatorCredit; MAX_CO_EDUCATORS];
+
+// Unused slots keep the default educator key
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct CoEducatorCredit {
+    pub educator: Pubkey,
+    pub share: u64,
+    pub reward: u64,
+}
+
+// Verifiers for a course that requires k-of-n sign-off before its reward is minted
+#[account]
+pub struct CourseVerifiers {
+    pub course: Pubkey,
+    pub verifiers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct CoEducatorShare {
+    pub educator: Pubkey,
+    pub weight: u16,
+}
+
+// Co-educators of a course besides its lead (Course.educator). Minted amounts
+// are credited by weight, and each co-educator is minted reward_percent of the
+// student reward on top.
+#[account]
+pub struct CourseCoEducators {
+    pub course: Pubkey,
+    pub lead_weight: u16,
+    pub co_educators: Vec<CoEducatorShare>,
+    pub reward_percent: u8,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+// One per student and course, gated by the student's CourseCompletion
+#[account]
+pub struct CourseRating {
+    pub course: Pubkey,
+    pub student: Pubkey,
+    pub rating: u8,
+    pub review_hash: [u8; 32],
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct CompletionVerification {
+    pub course: Pubkey,
+    pub student: Pubkey,
+    pub verified_by: Vec<Pubkey>,
+    pub first_verified_at: i64,
+    pub completed_at: Option<i64>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompletionAttestation {
+    pub course_id: String,
+    pub student: Pubkey,
+    pub amount: u64,
+    pub expiry: i64,
+    pub nonce: u64,
+}
+
 #[event]
 pub struct CourseCreated {
     pub course_id: String,
@@ -73,6 +158,34 @@ pub struct CourseCompleted {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct CompletionSkipped {
+    pub student: Pubkey,
+    pub course_id: String,
+    pub educator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchMintCompleted {
+    pub course_id: String,
+    pub educator: Pubkey,
+    pub minted_count: u32,
+    pub skipped_count: u32,
+    pub total_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AttestationRedeemed {
+    pub student: Pubkey,
+    pub course_id: String,
+    pub educator: Pubkey,
+    pub amount: u64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct TokensMinted {
     pub recipient: Pubkey,
@@ -86,4 +199,90 @@ pub struct TokensBurned {
     pub burner: Pubkey,
     pub amount: u64,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct CompletionRevocationRequested {
+    pub student: Pubkey,
+    pub course_id: String,
+    pub reason_hash: [u8; 32],
+    pub requested_by: Pubkey,
+    pub revocable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompletionRevocationCancelled {
+    pub student: Pubkey,
+    pub course_id: String,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompletionRevoked {
+    pub student: Pubkey,
+    pub course_id: String,
+    pub educator: Pubkey,
+    pub tokens_reversed: u64,
+    pub tokens_burned: u64,
+    pub revoked_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseVerifiersUpdated {
+    pub course: Pubkey,
+    pub verifiers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompletionVerified {
+    pub course: Pubkey,
+    pub student: Pubkey,
+    pub verifier: Pubkey,
+    pub verifications: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseCoEducatorsUpdated {
+    pub course: Pubkey,
+    pub lead_weight: u16,
+    pub co_educators: Vec<CoEducatorShare>,
+    pub reward_percent: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CoEducatorCredited {
+    pub course: Pubkey,
+    pub educator: Pubkey,
+    pub share: u64,
+    pub reward: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseRated {
+    pub course: Pubkey,
+    pub student: Pubkey,
+    pub rating: u8,
+    pub review_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseRatingUpdated {
+    pub course: Pubkey,
+    pub student: Pubkey,
+    pub previous_rating: u8,
+    pub rating: u8,
+    pub review_hash: [u8; 32],
+    pub timestamp: i64,
+}
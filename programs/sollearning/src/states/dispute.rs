@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DisputeKind {
+    // Opened by the student for a completion that was never awarded
+    MissingCompletion,
+    // Opened by anyone against an awarded completion
+    FraudulentCompletion,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DisputeStatus {
+    Open,
+    Upheld,
+    Dismissed,
+    // An upheld dispute whose mint or revocation has been carried out
+    Settled,
+}
+
+// One dispute per course, student and opener, so a dispute opened by someone
+// else never blocks the student's own. Resolved disputes can be closed by
+// their opener and opened again. `course_completion` is only set for
+// FraudulentCompletion disputes.
+#[account]
+pub struct Dispute {
+    pub course: Pubkey,
+    pub student: Pubkey,
+    pub course_completion: Pubkey,
+    pub opened_by: Pubkey,
+    pub kind: DisputeKind,
+    pub evidence_hash: [u8; 32],
+    pub status: DisputeStatus,
+    pub reviewers: Vec<Pubkey>,
+    pub upheld_votes: u8,
+    pub dismissed_votes: u8,
+    pub opened_at: i64,
+    pub resolved_at: Option<i64>,
+    pub bump: u8,
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub dispute: Pubkey,
+    pub course: Pubkey,
+    pub student: Pubkey,
+    pub kind: DisputeKind,
+    pub evidence_hash: [u8; 32],
+    pub opened_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeReviewed {
+    pub dispute: Pubkey,
+    pub reviewer: Pubkey,
+    pub uphold: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub dispute: Pubkey,
+    pub status: DisputeStatus,
+    pub upheld_votes: u8,
+    pub dismissed_votes: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeSettled {
+    pub dispute: Pubkey,
+    pub kind: DisputeKind,
+    pub amount: u64,
+    pub settled_by: Pubkey,
+    pub timestamp: i64,
+}
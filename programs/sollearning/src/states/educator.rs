@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 #[account]
+#[derive(Default)]
 pub struct EducatorAccount {
     pub educator_address: Pubkey,
     pub authority: Pubkey,
@@ -11,13 +12,126 @@ pub struct EducatorAccount {
     pub created_at: i64,
     pub last_updated_at: i64, 
     pub last_mint_time: i64,
+    pub near_cap_streak: u8,
+    pub rewards_earned: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct EducatorOperator {
+    pub educator: Pubkey,
+    pub operator: Pubkey,
+    pub permissions: u8,
+    pub mint_allowance: u64,
+    pub total_minted: u64,
+    pub expires_at: i64,
+    pub granted_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct EducatorKeyRotation {
+    pub educator: Pubkey,
+    pub new_educator_address: Pubkey,
+    pub new_authority: Pubkey,
+    pub requested_by: Pubkey,
+    pub requested_at: i64,
+    pub executable_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct EducatorApplication {
+    pub applicant: Pubkey,
+    pub profile_hash: [u8; 32],
+    pub requested_mint_limit: u64,
+    pub submitted_at: i64,
     pub bump: u8,
 }
 
+#[event]
+pub struct EducatorRewarded {
+    pub educator: Pubkey,
+    pub student: Pubkey,
+    pub course_id: String,
+    pub amount: u64,
+    pub rewards_earned: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct EducatorRegistered {
     pub educator: Pubkey,
     pub authority: Pubkey,
     pub mint_limit: u64,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct OperatorGranted {
+    pub educator: Pubkey,
+    pub operator: Pubkey,
+    pub permissions: u8,
+    pub mint_allowance: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OperatorRevoked {
+    pub educator: Pubkey,
+    pub operator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EducatorKeyRotationRequested {
+    pub educator: Pubkey,
+    pub current_address: Pubkey,
+    pub new_educator_address: Pubkey,
+    pub new_authority: Pubkey,
+    pub requested_by: Pubkey,
+    pub executable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EducatorKeyRotationCancelled {
+    pub educator: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EducatorKeyRotated {
+    pub educator: Pubkey,
+    pub previous_address: Pubkey,
+    pub new_educator_address: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EducatorApplicationSubmitted {
+    pub applicant: Pubkey,
+    pub profile_hash: [u8; 32],
+    pub requested_mint_limit: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EducatorApplicationApproved {
+    pub applicant: Pubkey,
+    pub educator_account: Pubkey,
+    pub mint_limit: u64,
+    pub approved_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EducatorApplicationRejected {
+    pub applicant: Pubkey,
+    pub rejected_by: Pubkey,
+    pub timestamp: i64,
+}
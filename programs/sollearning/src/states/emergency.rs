@@ -15,4 +15,50 @@ pub struct EmergencyMultisigCreated {
     pub signers: Vec<Pubkey>,
     pub threshold: u8,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PauseReason {
+    pub code: u8,
+    pub text_hash: [u8; 32],
+    pub resume_after: Option<i64>,
+}
+
+// Why the program is paused, or was last paused. `text_hash` points at an
+// off-chain explanation integrators can show to users. `pause_flags` holds the
+// function flags the pause set, which are the only ones resuming clears.
+#[account]
+pub struct PauseRecord {
+    pub reason_code: u8,
+    pub reason_hash: [u8; 32],
+    pub paused_by: Pubkey,
+    pub paused_at: i64,
+    pub resume_after: Option<i64>,
+    pub extended_by: Option<Pubkey>,
+    pub resumed_at: Option<i64>,
+    pub pause_flags: u32,
+    pub bump: u8,
+}
+
+#[event]
+pub struct ProgramPaused {
+    pub reason_code: u8,
+    pub reason_hash: [u8; 32],
+    pub resume_after: Option<i64>,
+    pub paused_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseExtended {
+    pub resume_after: Option<i64>,
+    pub extended_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProgramResumed {
+    pub resumed_by: Pubkey,
+    pub scheduled: bool,
+    pub timestamp: i64,
+}
@@ -5,4 +5,8 @@ pub mod course;
 pub mod signers;
 pub mod proposal;
 pub mod config;
-pub mod emergency;
\ No newline at end of file
+pub mod emergency;
+pub mod voting;
+pub mod blocklist;
+pub mod dispute;
+pub mod reputation;
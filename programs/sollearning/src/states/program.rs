@@ -10,6 +10,19 @@ pub struct ProgramState {
     pub educator_count: u16,
     pub paused: bool,
     pub pause_flags: u32,
+    pub mint_window_start_slot: u64,
+    pub mint_window_minted: u64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(Default)]
+pub struct PendingAuthorityTransfer {
+    pub current_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub proposed_by: Pubkey,
+    pub proposed_at: i64,
+    pub by_governance: bool,
     pub bump: u8,
 }
 
@@ -25,4 +38,46 @@ pub struct ProgramGranularPauseChanged {
     pub pause_flags: u32,
     pub authority: Pubkey,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum CircuitBreaker {
+    MintVolume,
+    NearCapStreak,
+}
+
+// Alert raised when a circuit breaker pauses minting on its own
+#[event]
+pub struct CircuitBreakerTripped {
+    pub breaker: CircuitBreaker,
+    pub educator: Pubkey,
+    pub attempted_amount: u64,
+    pub window_minted: u64,
+    pub circulating_supply: u64,
+    pub near_cap_streak: u8,
+    pub pause_flags: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub current_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub proposed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub current_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::states::reputation::ReputationTier;
 
 #[account]
 pub struct Proposal {
@@ -6,8 +8,12 @@ pub struct Proposal {
     pub index: u64,
     pub instruction: ProposalInstruction,
     pub signers: Vec<bool>,
+    pub membership_version: u64,
     pub status: ProposalStatus,
     pub created_at: i64,
+    pub executable_at: i64,
+    pub expires_at: i64,
+    pub threshold: u8,
     pub closed_at: Option<i64>,
     pub description: String,
     pub bump: u8,
@@ -22,6 +28,84 @@ pub enum ProposalInstruction {
     AddSigner { signer: Pubkey },
     RemoveSigner { signer: Pubkey },
     ChangeThreshold { threshold: u8 },
+    Custom { program_id: Pubkey, accounts: Vec<ProposalAccountMeta>, data: Vec<u8> },
+    SetProposalDelay { instruction_kind: u8, delay: i64 },
+    SetProposalThreshold { instruction_kind: u8, threshold_bps: u16 },
+    SetEducatorReward { educator_reward_bps: u16, educator_reward_budget: u64 },
+    SetReputationTiers { tiers: Vec<ReputationTier> },
+    SetGuardian { guardian: Pubkey },
+    CreateCommittee { label: String, signers: Vec<Pubkey>, threshold: u8, scope: u32 },
+    SetProposalExpiryBounds { min_proposal_expiry: i64, max_proposal_expiry: i64 },
+    ExtendPause { resume_after: Option<i64> },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposalAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl ProposalInstruction {
+    // Index into per-variant governance settings such as ProgramConfig.proposal_delays
+    // and ProgramConfig.proposal_thresholds
+    pub fn kind(&self) -> usize {
+        match self {
+            ProposalInstruction::ChangeAuthority { .. } => 0,
+            ProposalInstruction::TogglePause { .. } => 1,
+            ProposalInstruction::RegisterEducator { .. } => 2,
+            ProposalInstruction::UpdateEducatorStatus { .. } => 3,
+            ProposalInstruction::AddSigner { .. } => 4,
+            ProposalInstruction::RemoveSigner { .. } => 5,
+            ProposalInstruction::ChangeThreshold { .. } => 6,
+            ProposalInstruction::Custom { .. } => 7,
+            ProposalInstruction::SetProposalDelay { .. } => 8,
+            ProposalInstruction::SetProposalThreshold { .. } => 9,
+            ProposalInstruction::SetEducatorReward { .. } => 10,
+            ProposalInstruction::SetReputationTiers { .. } => 11,
+            ProposalInstruction::SetGuardian { .. } => 12,
+            ProposalInstruction::CreateCommittee { .. } => 13,
+            ProposalInstruction::SetProposalExpiryBounds { .. } => 14,
+            ProposalInstruction::ExtendPause { .. } => 15,
+        }
+    }
+
+    // Instructions that stay available to governance while the program is
+    // paused, so a pause can be lifted and compromised keys rotated out
+    pub fn is_recovery(&self) -> bool {
+        matches!(
+            self,
+            ProposalInstruction::ChangeAuthority { .. }
+                | ProposalInstruction::TogglePause { .. }
+                | ProposalInstruction::ExtendPause { .. }
+                | ProposalInstruction::AddSigner { .. }
+                | ProposalInstruction::RemoveSigner { .. }
+                | ProposalInstruction::ChangeThreshold { .. }
+        )
+    }
+
+    // Governance scope a multisig must hold to create or execute this instruction
+    pub fn required_scope(&self) -> u32 {
+        match self {
+            ProposalInstruction::ChangeAuthority { .. }
+            | ProposalInstruction::TogglePause { .. }
+            | ProposalInstruction::ExtendPause { .. }
+            | ProposalInstruction::SetProposalDelay { .. }
+            | ProposalInstruction::SetProposalThreshold { .. }
+            | ProposalInstruction::SetEducatorReward { .. }
+            | ProposalInstruction::SetReputationTiers { .. }
+            | ProposalInstruction::SetGuardian { .. }
+            | ProposalInstruction::SetProposalExpiryBounds { .. } => GOVERNANCE_SCOPE_CONFIG,
+            ProposalInstruction::RegisterEducator { .. }
+            | ProposalInstruction::UpdateEducatorStatus { .. } => GOVERNANCE_SCOPE_EDUCATORS,
+            ProposalInstruction::AddSigner { .. }
+            | ProposalInstruction::RemoveSigner { .. }
+            | ProposalInstruction::ChangeThreshold { .. } => GOVERNANCE_SCOPE_MEMBERSHIP,
+            ProposalInstruction::Custom { .. } => GOVERNANCE_SCOPE_TREASURY,
+            // Committees may be granted any scope, so only full-scope governance creates them
+            ProposalInstruction::CreateCommittee { .. } => GOVERNANCE_SCOPE_ALL,
+        }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
@@ -30,6 +114,7 @@ pub enum ProposalStatus {
     Executed,
     Cancelled,
     Expired,
+    Rejected,
 }
 
 #[event]
@@ -39,6 +124,9 @@ pub struct ProposalCreated {
     pub proposer: Pubkey,
     pub instruction: ProposalInstruction,
     pub description: String,
+    pub executable_at: i64,
+    pub expires_at: i64,
+    pub threshold: u8,
     pub timestamp: i64,
 }
 
@@ -65,3 +153,19 @@ pub struct ProposalCancelled {
     pub canceller: Pubkey,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct ProposalVetoed {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub vetoed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalExpired {
+    pub multisig: Pubkey,
+    pub proposal: Pubkey,
+    pub expired_by: Pubkey,
+    pub timestamp: i64,
+}
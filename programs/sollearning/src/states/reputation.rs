@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+// Aggregated track record of an educator, created on its first completion,
+// dispute or rating. Rating fields mirror the ratings left on its courses.
+#[account]
+pub struct EducatorReputation {
+    pub educator: Pubkey,
+    pub completions: u64,
+    pub unique_students: u64,
+    pub disputes_lost: u32,
+    pub rating_count: u64,
+    pub rating_sum: u64,
+    pub tier: u8,
+    pub last_updated_at: i64,
+    pub bump: u8,
+}
+
+// Marks that an educator has awarded a student at least once, so each student
+// counts once towards unique_students.
+#[account]
+pub struct EducatorStudent {
+    pub educator: Pubkey,
+    pub student: Pubkey,
+    pub first_completion_at: i64,
+    pub bump: u8,
+}
+
+// An educator qualifies for a tier when it meets every minimum. A
+// min_average_rating of zero skips the rating check; otherwise it is the
+// average rating in hundredths (e.g. 450 for 4.5).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct ReputationTier {
+    pub min_completions: u64,
+    pub min_unique_students: u64,
+    pub min_average_rating: u16,
+    pub max_disputes_lost: u32,
+    pub mint_limit: u64,
+}
+
+#[event]
+pub struct ReputationUpdated {
+    pub educator: Pubkey,
+    pub completions: u64,
+    pub unique_students: u64,
+    pub disputes_lost: u32,
+    pub rating_count: u64,
+    pub rating_sum: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReputationTiersUpdated {
+    pub tiers: Vec<ReputationTier>,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EducatorTierApplied {
+    pub educator: Pubkey,
+    pub tier: u8,
+    pub previous_mint_limit: u64,
+    pub mint_limit: u64,
+    pub timestamp: i64,
+}
@@ -1,11 +1,15 @@
 use anchor_lang::prelude::*;
 
 #[account]
+#[derive(Default)]
 pub struct Multisig {
     pub signers: Vec<Pubkey>,
     pub threshold: u8,
     pub proposal_count: u64,
     pub authority: Pubkey,
+    pub label: String,
+    pub scope: u32,
+    pub membership_version: u64,
     pub bump: u8,
 }
 
@@ -14,5 +18,33 @@ pub struct MultisigCreated {
     pub multisig: Pubkey,
     pub signers: Vec<Pubkey>,
     pub threshold: u8,
+    pub label: String,
+    pub scope: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigSignerAdded {
+    pub multisig: Pubkey,
+    pub signer: Pubkey,
+    pub signers_count: u8,
+    pub membership_version: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigSignerRemoved {
+    pub multisig: Pubkey,
+    pub signer: Pubkey,
+    pub signers_count: u8,
+    pub membership_version: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigThresholdChanged {
+    pub multisig: Pubkey,
+    pub old_threshold: u8,
+    pub new_threshold: u8,
     pub timestamp: i64,
 }
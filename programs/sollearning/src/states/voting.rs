@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::states::proposal::{ProposalInstruction, ProposalStatus};
+
+// Tokens a holder has locked for community voting. Weight is the staked amount,
+// counted only for proposals created no earlier than the last deposit.
+#[account]
+pub struct VoterStake {
+    pub voter: Pubkey,
+    pub amount: u64,
+    pub last_deposit_at: i64,
+    pub locked_until: i64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct CommunityProposal {
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub instruction: ProposalInstruction,
+    pub description: String,
+    pub yes_weight: u64,
+    pub no_weight: u64,
+    pub status: ProposalStatus,
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    pub executable_at: i64,
+    pub closed_at: Option<i64>,
+    pub bump: u8,
+}
+
+#[account]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub weight: u64,
+    pub voted_at: i64,
+    pub bump: u8,
+}
+
+#[event]
+pub struct VoteStakeDeposited {
+    pub voter: Pubkey,
+    pub amount: u64,
+    pub total_staked: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteStakeWithdrawn {
+    pub voter: Pubkey,
+    pub amount: u64,
+    pub total_staked: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CommunityProposalCreated {
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub instruction: ProposalInstruction,
+    pub description: String,
+    pub voting_ends_at: i64,
+    pub executable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteCast {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CommunityProposalFinalized {
+    pub proposal: Pubkey,
+    pub status: ProposalStatus,
+    pub yes_weight: u64,
+    pub no_weight: u64,
+    pub finalized_by: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use crate::states::program::ProgramState;
+use crate::states::config::ProgramConfig;
+use crate::states::signers::Multisig;
+use crate::states::emergency::EmergencyMultisig;
+use crate::states::educator::{EducatorAccount, EducatorOperator};
+use crate::states::course::{CourseCoEducators, CourseVerifiers};
+use crate::error::SolLearningError;
+use crate::constants::{GOVERNANCE_SCOPE_EDUCATORS, OPERATOR_PERMISSION_UPDATE_COURSE};
+
+// Roles a signer can act under:
+// - Admin: ProgramState.authority
+// - Governance: a signer of the governance multisig
+// - Educator: the signing key (educator_address) of an EducatorAccount
+// - Operator: a delegate holding an EducatorOperator grant
+// - Pauser: the admin or a signer of the emergency multisig
+// - Guardian: ProgramConfig.guardian or a signer of the emergency multisig
+// - Reviewer: ProgramConfig.dispute_reviewer or a signer of a multisig holding
+//   the educators scope
+// - CoEducators: a majority of a course's co-educators signing together
+// - Verifiers: a threshold of a course's current verifiers signing together
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    Admin,
+    Governance,
+    Educator,
+    Operator,
+    Pauser,
+    Guardian,
+    Reviewer,
+    CoEducators,
+    Verifiers,
+}
+
+pub fn check_admin(program_state: &ProgramState, signer: &Signer) -> Result<Role> {
+    require!(
+        program_state.authority == signer.key(),
+        SolLearningError::UnauthorizedAuthority
+    );
+    Ok(Role::Admin)
+}
+
+// ProgramConfig keeps a copy of the admin key for the config setters
+pub fn check_config_admin(program_config: &ProgramConfig, signer: &Signer) -> Result<Role> {
+    require!(
+        program_config.authority == signer.key(),
+        SolLearningError::UnauthorizedAuthority
+    );
+    Ok(Role::Admin)
+}
+
+pub fn check_governance(multisig: &Multisig, signer: &Signer) -> Result<Role> {
+    require!(multisig.signers.contains(&signer.key()), SolLearningError::Unauthorized);
+    Ok(Role::Governance)
+}
+
+// A signer of the root multisig or of a committee holding any of `scope`
+pub fn check_scoped_governance(multisig: &Multisig, scope: u32, signer: &Signer) -> Result<Role> {
+    require!(multisig.scope & scope != 0, SolLearningError::ProposalOutOfScope);
+    check_governance(multisig, signer)
+}
+
+pub fn check_pauser(
+    program_state: &ProgramState,
+    emergency_multisig: Option<&EmergencyMultisig>,
+    signer: &Signer,
+) -> Result<Role> {
+    let is_emergency_signer = emergency_multisig
+        .map(|multisig| multisig.signers.contains(&signer.key()))
+        .unwrap_or(false);
+    require!(
+        program_state.authority == signer.key() || is_emergency_signer,
+        SolLearningError::Unauthorized
+    );
+    Ok(Role::Pauser)
+}
+
+pub fn check_guardian(
+    program_config: &ProgramConfig,
+    emergency_multisig: Option<&EmergencyMultisig>,
+    signer: &Signer,
+) -> Result<Role> {
+    let is_emergency_signer = emergency_multisig
+        .map(|multisig| multisig.signers.contains(&signer.key()))
+        .unwrap_or(false);
+    let is_guardian = program_config.guardian != Pubkey::default()
+        && program_config.guardian == signer.key();
+    require!(is_guardian || is_emergency_signer, SolLearningError::Unauthorized);
+    Ok(Role::Guardian)
+}
+
+// Only the educator's own key; used where delegation is not allowed.
+pub fn check_educator(educator: &EducatorAccount, signer: &Signer) -> Result<Role> {
+    require!(educator.educator_address == signer.key(), SolLearningError::Unauthorized);
+    require!(educator.is_active, SolLearningError::InactiveEducator);
+    Ok(Role::Educator)
+}
+
+// Passes when the signer is the active educator itself, or an unexpired
+// operator of that educator holding `permission`. The operator PDA seeds are
+// checked by the accounts struct, so a provided operator always belongs to
+// (educator, signer).
+pub fn check_educator_or_operator(
+    educator: &Account<EducatorAccount>,
+    signer: &Signer,
+    operator: &Option<Account<EducatorOperator>>,
+    permission: u8,
+    current_time: i64,
+) -> Result<Role> {
+    require!(educator.is_active, SolLearningError::InactiveEducator);
+
+    if signer.key() == educator.educator_address {
+        return Ok(Role::Educator);
+    }
+
+    let operator = operator.as_ref().ok_or(SolLearningError::Unauthorized)?;
+    require!(
+        operator.educator == educator.key() && operator.operator == signer.key(),
+        SolLearningError::Unauthorized
+    );
+    require!(
+        operator.permissions & permission == permission,
+        SolLearningError::OperatorPermissionDenied
+    );
+    require!(operator.expires_at > current_time, SolLearningError::OperatorExpired);
+    Ok(Role::Operator)
+}
+
+pub fn check_dispute_reviewer(
+    program_config: &ProgramConfig,
+    multisig: Option<&Multisig>,
+    signer: &Signer,
+) -> Result<Role> {
+    let is_panel_signer = multisig
+        .map(|multisig| {
+            multisig.scope & GOVERNANCE_SCOPE_EDUCATORS != 0 && multisig.signers.contains(&signer.key())
+        })
+        .unwrap_or(false);
+    let is_reviewer = program_config.dispute_reviewer != Pubkey::default()
+        && program_config.dispute_reviewer == signer.key();
+    require!(is_reviewer || is_panel_signer, SolLearningError::Unauthorized);
+    Ok(Role::Reviewer)
+}
+
+// Co-educators sign by passing [EducatorAccount, signer] pairs through the
+// remaining accounts; more than half of the listed co-educators must sign.
+pub fn check_co_educator_majority<'info>(
+    co_educators: &CourseCoEducators,
+    signers: &'info [AccountInfo<'info>],
+) -> Result<Role> {
+    require!(
+        !co_educators.co_educators.is_empty() && signers.len().is_multiple_of(2),
+        SolLearningError::CoEducatorMajorityRequired
+    );
+
+    let mut approved: Vec<Pubkey> = Vec::new();
+    for pair in signers.chunks(2) {
+        let educator = Account::<EducatorAccount>::try_from(&pair[0])
+            .map_err(|_| SolLearningError::InvalidCoEducatorAccounts)?;
+        let is_listed = co_educators.co_educators.iter().any(|share| share.educator == educator.key());
+        require!(
+            is_listed && educator.is_active && pair[1].is_signer && pair[1].key() == educator.educator_address,
+            SolLearningError::CoEducatorMajorityRequired
+        );
+        if !approved.contains(&educator.key()) {
+            approved.push(educator.key());
+        }
+    }
+
+    require!(
+        approved.len() * 2 > co_educators.co_educators.len(),
+        SolLearningError::CoEducatorMajorityRequired
+    );
+    Ok(Role::CoEducators)
+}
+
+// Current verifiers sign by passing their keys as signers through the remaining
+// accounts; as many distinct verifiers as the current threshold must sign.
+pub fn check_verifier_quorum(course_verifiers: &CourseVerifiers, signers: &[AccountInfo]) -> Result<Role> {
+    let mut approved: Vec<Pubkey> = Vec::new();
+    for signer in signers {
+        require!(
+            signer.is_signer && course_verifiers.verifiers.contains(signer.key),
+            SolLearningError::VerifierQuorumRequired
+        );
+        if !approved.contains(signer.key) {
+            approved.push(signer.key());
+        }
+    }
+
+    require!(
+        approved.len() >= course_verifiers.threshold as usize,
+        SolLearningError::VerifierQuorumRequired
+    );
+    Ok(Role::Verifiers)
+}
+
+// The lead educator (or one of its operators) changes a course on its own;
+// otherwise a majority of co-educators has to sign.
+pub fn check_course_editor<'info>(
+    educator: &Account<EducatorAccount>,
+    signer: &Signer,
+    operator: &Option<Account<EducatorOperator>>,
+    co_educators: Option<&CourseCoEducators>,
+    co_educator_signers: &'info [AccountInfo<'info>],
+    current_time: i64,
+) -> Result<Role> {
+    if co_educator_signers.is_empty() {
+        return check_educator_or_operator(educator, signer, operator, OPERATOR_PERMISSION_UPDATE_COURSE, current_time);
+    }
+
+    let co_educators = co_educators.ok_or(SolLearningError::CoEducatorMajorityRequired)?;
+    check_co_educator_majority(co_educators, co_educator_signers)
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+// Helpers for PDAs that are created conditionally inside a handler, where an
+// `init` constraint would fail once the account exists.
+
+// Anyone can send lamports to a PDA before it exists, which makes a plain
+// create_account fail. A pre-funded address is topped up to the rent-exempt
+// minimum instead, then allocated and assigned to the program.
+pub fn create_program_account<'info>(
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    target: AccountInfo<'info>,
+    program_id: &Pubkey,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = target.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                system_program::CreateAccount { from: payer, to: target },
+                &[signer_seeds],
+            ),
+            lamports,
+            space as u64,
+            program_id,
+        );
+    }
+
+    let shortfall = lamports.saturating_sub(current_lamports);
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer { from: payer, to: target.clone() },
+            ),
+            shortfall,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate { account_to_allocate: target.clone() },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program,
+            system_program::Assign { account_to_assign: target },
+            &[signer_seeds],
+        ),
+        program_id,
+    )
+}
+
+pub fn write_account<T: AccountSerialize>(target: &AccountInfo, account: &T) -> Result<()> {
+    let mut data = target.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data[..];
+    account.try_serialize(&mut writer)?;
+    Ok(())
+}
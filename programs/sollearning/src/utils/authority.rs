@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::states::program::{ProgramState, PendingAuthorityTransfer};
+use crate::error::SolLearningError;
+
+// Rejects handovers to the default key or to the current authority, the two
+// typos that would otherwise leave an unusable pending transfer behind.
+pub fn validate_new_authority(program_state: &ProgramState, new_authority: Pubkey) -> Result<()> {
+    require!(
+        new_authority != Pubkey::default() && new_authority != program_state.authority,
+        SolLearningError::InvalidAuthorityTransfer
+    );
+    Ok(())
+}
+
+pub fn initialize_authority_transfer(
+    pending_transfer: &mut PendingAuthorityTransfer,
+    current_authority: Pubkey,
+    new_authority: Pubkey,
+    proposed_by: Pubkey,
+    by_governance: bool,
+    current_time: i64,
+    bump: u8,
+) {
+    pending_transfer.current_authority = current_authority;
+    pending_transfer.new_authority = new_authority;
+    pending_transfer.proposed_by = proposed_by;
+    pending_transfer.proposed_at = current_time;
+    pending_transfer.by_governance = by_governance;
+    pending_transfer.bump = bump;
+}
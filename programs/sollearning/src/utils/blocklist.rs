@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+use crate::error::SolLearningError;
+
+// Blocklist entries are passed as unchecked accounts at their PDA address; an
+// address is blocked exactly when its entry has been created by this program.
+pub fn check_not_blocklisted(blocklist_entry: &AccountInfo, program_id: &Pubkey) -> Result<()> {
+    require!(
+        blocklist_entry.owner != program_id || blocklist_entry.data_is_empty(),
+        SolLearningError::AddressBlocklisted
+    );
+    Ok(())
+}
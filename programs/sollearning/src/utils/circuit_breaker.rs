@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use crate::states::program::{ProgramState, CircuitBreaker, CircuitBreakerTripped};
+use crate::states::config::ProgramConfig;
+use crate::states::educator::EducatorAccount;
+use crate::constants::*;
+use crate::error::SolLearningError;
+
+pub fn validate_circuit_breakers(
+    breaker_window_slots: u64,
+    breaker_supply_ratio_bps: u16,
+    breaker_near_cap_bps: u16,
+    breaker_near_cap_streak: u8,
+) -> Result<()> {
+    require!(
+        breaker_supply_ratio_bps as u64 <= BPS_DENOMINATOR && breaker_near_cap_bps as u64 <= BPS_DENOMINATOR,
+        SolLearningError::InvalidCircuitBreakerConfig
+    );
+    require!(
+        breaker_supply_ratio_bps == 0 || breaker_window_slots > 0,
+        SolLearningError::InvalidCircuitBreakerConfig
+    );
+    require!(
+        (breaker_near_cap_bps == 0) == (breaker_near_cap_streak == 0),
+        SolLearningError::InvalidCircuitBreakerConfig
+    );
+    Ok(())
+}
+
+// Records a pending mint against both breakers and reports the first one it
+// trips. `amount` is the completion reward the educator awards and `minted`
+// everything the completion mints, including co-educator and educator
+// rewards. The caller pauses minting instead of completing the mint in that
+// case, so a tripped mint is not added to the window total.
+pub fn record_mint_for_circuit_breakers(
+    program_state: &mut ProgramState,
+    config: &ProgramConfig,
+    educator: &mut EducatorAccount,
+    amount: u64,
+    minted: u64,
+    current_slot: u64,
+) -> Result<Option<CircuitBreaker>> {
+    if config.breaker_window_slots > 0
+        && current_slot >= program_state.mint_window_start_slot.saturating_add(config.breaker_window_slots)
+    {
+        program_state.mint_window_start_slot = current_slot;
+        program_state.mint_window_minted = 0;
+    }
+
+    let window_minted = program_state
+        .mint_window_minted
+        .checked_add(minted)
+        .ok_or(SolLearningError::Overflow)?;
+    // Below the floor every mint would be a large share of supply, so the
+    // ratio is measured against the floor until the supply grows past it
+    let measured_supply = program_state
+        .total_minted
+        .saturating_sub(program_state.total_burned)
+        .max(CIRCUIT_BREAKER_SUPPLY_FLOOR);
+    if config.breaker_supply_ratio_bps > 0
+        && window_minted as u128 * BPS_DENOMINATOR as u128
+            > measured_supply as u128 * config.breaker_supply_ratio_bps as u128
+    {
+        return Ok(Some(CircuitBreaker::MintVolume));
+    }
+
+    let near_cap = config.breaker_near_cap_bps > 0
+        && amount as u128 * BPS_DENOMINATOR as u128
+            >= educator.mint_limit as u128 * config.breaker_near_cap_bps as u128;
+    educator.near_cap_streak = if near_cap { educator.near_cap_streak.saturating_add(1) } else { 0 };
+    if config.breaker_near_cap_streak > 0 && educator.near_cap_streak >= config.breaker_near_cap_streak {
+        return Ok(Some(CircuitBreaker::NearCapStreak));
+    }
+
+    program_state.mint_window_minted = window_minted;
+    Ok(None)
+}
+
+// Pauses minting after a breaker tripped. Callers return Ok afterwards so the
+// pause is persisted, and drop the mint along with any account created for it.
+pub fn trip_circuit_breaker(
+    program_state: &mut ProgramState,
+    educator: &mut Account<EducatorAccount>,
+    breaker: CircuitBreaker,
+    amount: u64,
+    current_time: i64,
+) {
+    program_state.pause_flags |= PAUSE_FLAG_MINT;
+
+    emit!(CircuitBreakerTripped {
+        breaker,
+        educator: educator.key(),
+        attempted_amount: amount,
+        window_minted: program_state.mint_window_minted,
+        circulating_supply: program_state.total_minted.saturating_sub(program_state.total_burned),
+        near_cap_streak: educator.near_cap_streak,
+        pause_flags: program_state.pause_flags,
+        timestamp: current_time,
+    });
+
+    educator.near_cap_streak = 0;
+
+    msg!(
+        "Circuit breaker tripped by educator {}: minting paused, {} tokens not minted",
+        educator.key(),
+        amount
+    );
+}
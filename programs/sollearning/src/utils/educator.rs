@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::states::educator::EducatorAccount;
+use crate::states::program::ProgramState;
+use crate::constants::MAX_MINT_AMOUNT;
+use crate::error::SolLearningError;
+
+pub fn validate_mint_limit(mint_limit: u64) -> Result<()> {
+    require!(mint_limit > 0 && mint_limit <= MAX_MINT_AMOUNT, SolLearningError::InvalidAmount);
+    Ok(())
+}
+
+pub fn initialize_educator_account(
+    educator_account: &mut EducatorAccount,
+    educator: Pubkey,
+    authority: Pubkey,
+    mint_limit: u64,
+    current_time: i64,
+    bump: u8,
+) {
+    educator_account.educator_address = educator;
+    educator_account.authority = authority;
+    educator_account.mint_limit = mint_limit;
+    educator_account.total_minted = 0;
+    educator_account.course_count = 0;
+    educator_account.is_active = true;
+    educator_account.created_at = current_time;
+    educator_account.last_updated_at = current_time;
+    educator_account.last_mint_time = 0;
+    educator_account.near_cap_streak = 0;
+    educator_account.rewards_earned = 0;
+    educator_account.bump = bump;
+}
+
+// Shared by set_educator_status and UpdateEducatorStatus proposals
+pub fn update_educator_status(
+    educator_account: &mut EducatorAccount,
+    is_active: bool,
+    new_mint_limit: Option<u64>,
+    current_time: i64,
+) -> Result<()> {
+    educator_account.is_active = is_active;
+
+    if let Some(mint_limit) = new_mint_limit {
+        validate_mint_limit(mint_limit)?;
+        educator_account.mint_limit = mint_limit;
+    }
+
+    educator_account.last_updated_at = current_time;
+    Ok(())
+}
+
+pub fn increment_educator_count(program_state: &mut ProgramState) -> Result<()> {
+    program_state.educator_count = program_state
+        .educator_count
+        .checked_add(1)
+        .ok_or(SolLearningError::Overflow)?;
+    Ok(())
+}
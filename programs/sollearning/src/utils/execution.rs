@@ -0,0 +1,758 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::states::config::{
+    ProgramConfig, ProposalDelayUpdated, ProposalThresholdUpdated, EducatorRewardConfigUpdated, GuardianUpdated,
+    ProposalExpiryBoundsUpdated,
+};
+use crate::states::reputation::{ReputationTier, ReputationTiersUpdated};
+use crate::states::program::{ProgramState, PendingAuthorityTransfer, AuthorityTransferProposed};
+use crate::states::program::ProgramStatusChanged;
+use crate::states::emergency::{PauseRecord, PauseReason, ProgramPaused, ProgramResumed};
+use crate::states::educator::{EducatorAccount, EducatorApplication, EducatorApplicationApproved, EducatorRegistered};
+use crate::states::proposal::{ProposalInstruction, ProposalAccountMeta};
+use crate::states::signers::{Multisig, MultisigSignerAdded, MultisigSignerRemoved, MultisigThresholdChanged};
+use crate::instructions::multisig::create_multisig_struct::calculate_multisig_space;
+use crate::constants::*;
+use crate::error::SolLearningError;
+use crate::utils::pause::{
+    check_function_running, check_governance_running, set_program_paused, record_pause, extend_pause,
+};
+use crate::utils::authority::{validate_new_authority, initialize_authority_transfer};
+use crate::utils::educator::{
+    validate_mint_limit, initialize_educator_account, increment_educator_count, update_educator_status,
+};
+use crate::utils::reputation::validate_reputation_tiers;
+use crate::utils::blocklist::check_not_blocklisted;
+use crate::utils::accounts::{create_program_account, write_account};
+use crate::utils::multisig::{
+    validate_label, validate_scope, validate_multisig, validate_unique_signers, initialize_multisig,
+    emit_multisig_created,
+};
+
+// Everything a governance body needs to carry out a ProposalInstruction. The
+// `governance` account is recorded as the acting authority and, through
+// `governance_seeds`, signs Custom instructions. Membership instructions act
+// on `multisig`, which only multisig governance provides, and governance
+// settings on `program_config`; every other instruction takes its accounts
+// from `remaining_accounts`:
+// - ChangeAuthority: [PendingAuthorityTransfer PDA]
+// - TogglePause, ExtendPause: [PauseRecord PDA]
+// - RegisterEducator: [EducatorApplication, EducatorAccount PDA, applicant,
+//   applicant blocklist entry PDA]
+// - UpdateEducatorStatus: [EducatorAccount PDA]
+// - CreateCommittee: [committee Multisig PDA]
+// - Custom: the target program followed by every account the instruction uses
+pub struct ProposalExecution<'a, 'info> {
+    pub program_id: &'a Pubkey,
+    pub payer: AccountInfo<'info>,
+    pub governance: AccountInfo<'info>,
+    pub governance_seeds: &'a [&'a [u8]],
+    pub program_state: &'a mut Account<'info, ProgramState>,
+    pub program_config: &'a mut Account<'info, ProgramConfig>,
+    pub multisig: Option<&'a mut Account<'info, Multisig>>,
+    pub system_program: AccountInfo<'info>,
+    pub remaining_accounts: &'info [AccountInfo<'info>],
+}
+
+pub fn execute_proposal_instruction(
+    execution: &mut ProposalExecution,
+    instruction: ProposalInstruction,
+    current_time: i64,
+) -> Result<()> {
+    check_governance_running(execution.program_state, &instruction)?;
+
+    match instruction {
+        ProposalInstruction::ChangeAuthority { new_authority } => {
+            execute_change_authority(execution, new_authority, current_time)
+        }
+        ProposalInstruction::TogglePause { paused } => {
+            execute_toggle_pause(execution, paused, current_time)
+        }
+        ProposalInstruction::RegisterEducator { educator, mint_limit } => {
+            execute_register_educator(execution, educator, mint_limit, current_time)
+        }
+        ProposalInstruction::UpdateEducatorStatus { educator, is_active, mint_limit } => {
+            execute_update_educator_status(execution, educator, is_active, mint_limit, current_time)
+        }
+        ProposalInstruction::AddSigner { signer } => {
+            execute_add_signer(execution, signer, current_time)
+        }
+        ProposalInstruction::RemoveSigner { signer } => {
+            execute_remove_signer(execution, signer, current_time)
+        }
+        ProposalInstruction::ChangeThreshold { threshold } => {
+            execute_change_threshold(execution, threshold, current_time)
+        }
+        ProposalInstruction::Custom { program_id, accounts, data } => {
+            execute_custom(execution, program_id, accounts, data)
+        }
+        ProposalInstruction::SetProposalDelay { instruction_kind, delay } => {
+            execute_set_proposal_delay(execution, instruction_kind, delay, current_time)
+        }
+        ProposalInstruction::SetProposalThreshold { instruction_kind, threshold_bps } => {
+            execute_set_proposal_threshold(execution, instruction_kind, threshold_bps, current_time)
+        }
+        ProposalInstruction::SetEducatorReward { educator_reward_bps, educator_reward_budget } => {
+            execute_set_educator_reward(execution, educator_reward_bps, educator_reward_budget, current_time)
+        }
+        ProposalInstruction::SetReputationTiers { tiers } => {
+            execute_set_reputation_tiers(execution, tiers, current_time)
+        }
+        ProposalInstruction::SetGuardian { guardian } => {
+            execute_set_guardian(execution, guardian, current_time)
+        }
+        ProposalInstruction::CreateCommittee { label, signers, threshold, scope } => {
+            execute_create_committee(execution, label, signers, threshold, scope, current_time)
+        }
+        ProposalInstruction::SetProposalExpiryBounds { min_proposal_expiry, max_proposal_expiry } => {
+            execute_set_proposal_expiry_bounds(execution, min_proposal_expiry, max_proposal_expiry, current_time)
+        }
+        ProposalInstruction::ExtendPause { resume_after } => {
+            execute_extend_pause(execution, resume_after, current_time)
+        }
+    }
+}
+
+// Pausing records a governance pause with no scheduled resume. If the program
+// is already paused, the pause is extended instead: any scheduled resume is
+// cancelled and the original reason is kept.
+fn execute_toggle_pause(execution: &mut ProposalExecution, paused: bool, current_time: i64) -> Result<()> {
+    let mut pause_record = load_pause_record(execution)?;
+    let governance = execution.governance.key();
+    let extends = paused && execution.program_state.paused;
+
+    if extends {
+        extend_pause(&mut pause_record, None, governance, current_time)?;
+    } else if paused {
+        let reason = PauseReason {
+            code: PAUSE_REASON_GOVERNANCE,
+            text_hash: [0; 32],
+            resume_after: None,
+        };
+        record_pause(&mut pause_record, &reason, governance, false, current_time)?;
+
+        emit!(ProgramPaused {
+            reason_code: reason.code,
+            reason_hash: reason.text_hash,
+            resume_after: None,
+            paused_by: governance,
+            timestamp: current_time,
+        });
+    } else {
+        pause_record.resumed_at = Some(current_time);
+
+        emit!(ProgramResumed {
+            resumed_by: governance,
+            scheduled: false,
+            timestamp: current_time,
+        });
+    }
+
+    if !extends {
+        set_program_paused(execution.program_state, &mut pause_record, paused);
+
+        emit!(ProgramStatusChanged {
+            paused,
+            authority: governance,
+            timestamp: current_time,
+        });
+    }
+
+    pause_record.exit(execution.program_id)
+}
+
+// Moves the scheduled resume of the current pause later, or drops it so the
+// program stays paused until resumed explicitly.
+fn execute_extend_pause(execution: &mut ProposalExecution, resume_after: Option<i64>, current_time: i64) -> Result<()> {
+    require!(execution.program_state.paused, SolLearningError::InvalidPauseSchedule);
+
+    let mut pause_record = load_pause_record(execution)?;
+    extend_pause(&mut pause_record, resume_after, execution.governance.key(), current_time)?;
+
+    pause_record.exit(execution.program_id)
+}
+
+fn load_pause_record<'info>(execution: &ProposalExecution<'_, 'info>) -> Result<Account<'info, PauseRecord>> {
+    let [pause_record_info] = execution.remaining_accounts else {
+        return Err(SolLearningError::InvalidProposalAccounts.into());
+    };
+
+    let (expected_pause_record, _) = Pubkey::find_program_address(&[PAUSE_RECORD_SEED], execution.program_id);
+    require!(
+        pause_record_info.key() == expected_pause_record,
+        SolLearningError::InvalidProposalAccounts
+    );
+    Account::<PauseRecord>::try_from(pause_record_info)
+        .map_err(|_| SolLearningError::InvalidProposalAccounts.into())
+}
+
+// The educator account is identified by its current address, so the proposal
+// still applies after a key rotation.
+fn execute_update_educator_status(
+    execution: &mut ProposalExecution,
+    educator: Pubkey,
+    is_active: bool,
+    mint_limit: Option<u64>,
+    current_time: i64,
+) -> Result<()> {
+    check_function_running(execution.program_state, PAUSE_FLAG_REGISTER)?;
+
+    let [educator_account_info] = execution.remaining_accounts else {
+        return Err(SolLearningError::InvalidProposalAccounts.into());
+    };
+
+    let mut educator_account = Account::<EducatorAccount>::try_from(educator_account_info)
+        .map_err(|_| SolLearningError::InvalidProposalAccounts)?;
+    require!(
+        educator_account.educator_address == educator,
+        SolLearningError::InvalidProposalAccounts
+    );
+
+    update_educator_status(&mut educator_account, is_active, mint_limit, current_time)?;
+    educator_account.exit(execution.program_id)?;
+
+    Ok(())
+}
+
+// Converts a pending EducatorApplication into an EducatorAccount, paid for by
+// the executor, and returns the application rent to the applicant.
+fn execute_register_educator(
+    execution: &mut ProposalExecution,
+    educator: Pubkey,
+    mint_limit: u64,
+    current_time: i64,
+) -> Result<()> {
+    check_function_running(execution.program_state, PAUSE_FLAG_REGISTER)?;
+    validate_mint_limit(mint_limit)?;
+
+    let [application_info, educator_account_info, applicant_info, applicant_blocklist_info] =
+        execution.remaining_accounts
+    else {
+        return Err(SolLearningError::InvalidProposalAccounts.into());
+    };
+
+    let (expected_blocklist, _) = Pubkey::find_program_address(
+        &[BLOCKLIST_SEED, educator.as_ref()],
+        execution.program_id,
+    );
+    require!(
+        applicant_blocklist_info.key() == expected_blocklist,
+        SolLearningError::InvalidProposalAccounts
+    );
+    check_not_blocklisted(applicant_blocklist_info, execution.program_id)?;
+
+    let application = Account::<EducatorApplication>::try_from(application_info)
+        .map_err(|_| SolLearningError::InvalidEducatorApplication)?;
+    require!(
+        application.applicant == educator && applicant_info.key() == educator,
+        SolLearningError::InvalidEducatorApplication
+    );
+
+    let (expected_application, _) = Pubkey::find_program_address(
+        &[EDUCATOR_APPLICATION_SEED, educator.as_ref()],
+        execution.program_id,
+    );
+    require!(application_info.key() == expected_application, SolLearningError::InvalidEducatorApplication);
+
+    let (expected_educator_account, educator_bump) = Pubkey::find_program_address(
+        &[EDUCATOR_SEED, educator.as_ref()],
+        execution.program_id,
+    );
+    require!(
+        educator_account_info.key() == expected_educator_account && educator_account_info.data_is_empty(),
+        SolLearningError::InvalidProposalAccounts
+    );
+
+    create_program_account(
+        execution.payer.clone(),
+        execution.system_program.clone(),
+        educator_account_info.clone(),
+        execution.program_id,
+        8 + std::mem::size_of::<EducatorAccount>(),
+        &[EDUCATOR_SEED, educator.as_ref(), &[educator_bump]],
+    )?;
+
+    let governance = execution.governance.key();
+
+    let mut educator_account = EducatorAccount::default();
+    initialize_educator_account(
+        &mut educator_account,
+        educator,
+        governance,
+        mint_limit,
+        current_time,
+        educator_bump,
+    );
+    write_account(educator_account_info, &educator_account)?;
+
+    application.close(applicant_info.clone())?;
+    increment_educator_count(execution.program_state)?;
+
+    emit!(EducatorApplicationApproved {
+        applicant: educator,
+        educator_account: educator_account_info.key(),
+        mint_limit,
+        approved_by: governance,
+        timestamp: current_time,
+    });
+
+    emit!(EducatorRegistered {
+        educator,
+        authority: governance,
+        mint_limit,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// Starts the two-step handover on behalf of governance. The new key still has
+// to sign `accept_authority_transfer`, so a mistyped proposal cannot brick the
+// program. Any pending transfer is replaced, so the current authority cannot
+// hold the slot with a transfer of its own; the account and its rent stay with
+// whoever opened it.
+fn execute_change_authority(
+    execution: &mut ProposalExecution,
+    new_authority: Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    validate_new_authority(execution.program_state, new_authority)?;
+
+    let [pending_transfer_info] = execution.remaining_accounts else {
+        return Err(SolLearningError::InvalidProposalAccounts.into());
+    };
+
+    let (expected_pending_transfer, bump) = Pubkey::find_program_address(
+        &[AUTHORITY_TRANSFER_SEED],
+        execution.program_id,
+    );
+    require!(
+        pending_transfer_info.key() == expected_pending_transfer,
+        SolLearningError::InvalidProposalAccounts
+    );
+
+    let proposed_by = if pending_transfer_info.data_is_empty() {
+        create_program_account(
+            execution.payer.clone(),
+            execution.system_program.clone(),
+            pending_transfer_info.clone(),
+            execution.program_id,
+            8 + std::mem::size_of::<PendingAuthorityTransfer>(),
+            &[AUTHORITY_TRANSFER_SEED, &[bump]],
+        )?;
+        execution.payer.key()
+    } else {
+        require!(
+            pending_transfer_info.owner == execution.program_id,
+            SolLearningError::InvalidProposalAccounts
+        );
+        let data = pending_transfer_info.try_borrow_data()?;
+        PendingAuthorityTransfer::try_deserialize(&mut &data[..])?.proposed_by
+    };
+
+    let current_authority = execution.program_state.authority;
+
+    let mut pending_transfer = PendingAuthorityTransfer::default();
+    initialize_authority_transfer(
+        &mut pending_transfer,
+        current_authority,
+        new_authority,
+        proposed_by,
+        true,
+        current_time,
+        bump,
+    );
+    write_account(pending_transfer_info, &pending_transfer)?;
+
+    emit!(AuthorityTransferProposed {
+        current_authority,
+        new_authority,
+        proposed_by: execution.governance.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// Adding or removing a signer resizes the multisig account and bumps its
+// membership version, which retires approvals on every other open proposal.
+fn execute_add_signer(execution: &mut ProposalExecution, signer: Pubkey, current_time: i64) -> Result<()> {
+    let payer = execution.payer.clone();
+    let system_program_info = execution.system_program.clone();
+    let multisig = execution
+        .multisig
+        .as_deref_mut()
+        .ok_or(SolLearningError::InvalidProposalAccounts)?;
+
+    require!(!multisig.signers.contains(&signer), SolLearningError::SignerAlreadyExists);
+    require!(multisig.signers.len() < MAX_SIGNERS, SolLearningError::MaxSignersReached);
+
+    multisig.signers.push(signer);
+    multisig.membership_version = multisig
+        .membership_version
+        .checked_add(1)
+        .ok_or(SolLearningError::Overflow)?;
+    resize_multisig(multisig, &payer, &system_program_info)?;
+
+    emit!(MultisigSignerAdded {
+        multisig: multisig.key(),
+        signer,
+        signers_count: multisig.signers.len() as u8,
+        membership_version: multisig.membership_version,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+fn execute_remove_signer(execution: &mut ProposalExecution, signer: Pubkey, current_time: i64) -> Result<()> {
+    let payer = execution.payer.clone();
+    let system_program_info = execution.system_program.clone();
+    let multisig = execution
+        .multisig
+        .as_deref_mut()
+        .ok_or(SolLearningError::InvalidProposalAccounts)?;
+
+    let signer_index = multisig
+        .signers
+        .iter()
+        .position(|&key| key == signer)
+        .ok_or(SolLearningError::SignerDoesNotExist)?;
+    require!(multisig.signers.len() > 1, SolLearningError::CannotRemoveLastSigner);
+    require!(
+        multisig.signers.len() > multisig.threshold as usize,
+        SolLearningError::CannotRemoveSigner
+    );
+
+    multisig.signers.remove(signer_index);
+    multisig.membership_version = multisig
+        .membership_version
+        .checked_add(1)
+        .ok_or(SolLearningError::Overflow)?;
+    resize_multisig(multisig, &payer, &system_program_info)?;
+
+    emit!(MultisigSignerRemoved {
+        multisig: multisig.key(),
+        signer,
+        signers_count: multisig.signers.len() as u8,
+        membership_version: multisig.membership_version,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// Open proposals keep the threshold they were created with.
+fn execute_change_threshold(execution: &mut ProposalExecution, threshold: u8, current_time: i64) -> Result<()> {
+    let multisig = execution
+        .multisig
+        .as_deref_mut()
+        .ok_or(SolLearningError::InvalidProposalAccounts)?;
+
+    require!(
+        threshold > 0 && (threshold as usize) <= multisig.signers.len(),
+        SolLearningError::InvalidThreshold
+    );
+
+    let old_threshold = multisig.threshold;
+    multisig.threshold = threshold;
+
+    emit!(MultisigThresholdChanged {
+        multisig: multisig.key(),
+        old_threshold,
+        new_threshold: threshold,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// `instruction_kind` follows ProposalInstruction::kind(). The delay only applies
+// to proposals created after the change.
+fn execute_set_proposal_delay(
+    execution: &mut ProposalExecution,
+    instruction_kind: u8,
+    delay: i64,
+    current_time: i64,
+) -> Result<()> {
+    require!(
+        (instruction_kind as usize) < PROPOSAL_INSTRUCTION_KINDS,
+        SolLearningError::InvalidProposalDelay
+    );
+    require!(
+        (0..=MAX_PROPOSAL_DELAY).contains(&delay),
+        SolLearningError::InvalidProposalDelay
+    );
+
+    let config = &mut execution.program_config;
+    config.proposal_delays[instruction_kind as usize] = delay;
+    config.last_updated_at = current_time;
+
+    emit!(ProposalDelayUpdated {
+        instruction_kind,
+        delay,
+        authority: execution.governance.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// Bounds for the expiry a proposer may choose. Existing proposals keep the
+// expiry they were created with.
+fn execute_set_proposal_expiry_bounds(
+    execution: &mut ProposalExecution,
+    min_proposal_expiry: i64,
+    max_proposal_expiry: i64,
+    current_time: i64,
+) -> Result<()> {
+    require!(
+        MIN_PROPOSAL_EXPIRY <= min_proposal_expiry
+            && min_proposal_expiry <= max_proposal_expiry
+            && max_proposal_expiry <= MAX_PROPOSAL_EXPIRY,
+        SolLearningError::InvalidProposalExpiry
+    );
+
+    let config = &mut execution.program_config;
+    config.min_proposal_expiry = min_proposal_expiry;
+    config.max_proposal_expiry = max_proposal_expiry;
+    config.last_updated_at = current_time;
+
+    emit!(ProposalExpiryBoundsUpdated {
+        min_proposal_expiry,
+        max_proposal_expiry,
+        authority: execution.governance.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// `threshold_bps` is the share of signers that must approve proposals of the
+// given kind, e.g. 10000 for all signers. Zero restores the multisig threshold.
+fn execute_set_proposal_threshold(
+    execution: &mut ProposalExecution,
+    instruction_kind: u8,
+    threshold_bps: u16,
+    current_time: i64,
+) -> Result<()> {
+    require!(
+        (instruction_kind as usize) < PROPOSAL_INSTRUCTION_KINDS,
+        SolLearningError::InvalidProposalThreshold
+    );
+    require!(
+        threshold_bps as u64 <= BPS_DENOMINATOR,
+        SolLearningError::InvalidProposalThreshold
+    );
+
+    let config = &mut execution.program_config;
+    config.proposal_thresholds[instruction_kind as usize] = threshold_bps;
+    config.last_updated_at = current_time;
+
+    emit!(ProposalThresholdUpdated {
+        instruction_kind,
+        threshold_bps,
+        authority: execution.governance.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// Educators earn `educator_reward_bps` of every student reward minted through
+// mint_to_student, up to `educator_reward_budget` over their lifetime. A rate
+// of zero turns the incentive off.
+fn execute_set_educator_reward(
+    execution: &mut ProposalExecution,
+    educator_reward_bps: u16,
+    educator_reward_budget: u64,
+    current_time: i64,
+) -> Result<()> {
+    require!(
+        educator_reward_bps <= MAX_EDUCATOR_REWARD_BPS && (educator_reward_bps == 0 || educator_reward_budget > 0),
+        SolLearningError::InvalidEducatorRewardConfig
+    );
+
+    let config = &mut execution.program_config;
+    config.educator_reward_bps = educator_reward_bps;
+    config.educator_reward_budget = educator_reward_budget;
+    config.last_updated_at = current_time;
+
+    emit!(EducatorRewardConfigUpdated {
+        educator_reward_bps,
+        educator_reward_budget,
+        authority: execution.governance.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// Tiers are ordered by increasing mint_limit. Once set, educators' mint limits
+// follow their reputation on every completion and on refresh_educator_tier;
+// an empty list returns mint limits to set_educator_status.
+fn execute_set_reputation_tiers(
+    execution: &mut ProposalExecution,
+    tiers: Vec<ReputationTier>,
+    current_time: i64,
+) -> Result<()> {
+    validate_reputation_tiers(&tiers)?;
+
+    let config = &mut execution.program_config;
+    config.reputation_tiers = Default::default();
+    config.reputation_tiers[..tiers.len()].copy_from_slice(&tiers);
+    config.reputation_tier_count = tiers.len() as u8;
+    config.last_updated_at = current_time;
+
+    emit!(ReputationTiersUpdated {
+        tiers,
+        authority: execution.governance.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// Setting the default key removes the guardian; the emergency multisig can
+// still veto.
+fn execute_set_guardian(execution: &mut ProposalExecution, guardian: Pubkey, current_time: i64) -> Result<()> {
+    let config = &mut execution.program_config;
+    config.guardian = guardian;
+    config.last_updated_at = current_time;
+
+    emit!(GuardianUpdated {
+        guardian,
+        authority: execution.governance.key(),
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+// Creates a committee multisig, seeded by its label and limited to `scope`,
+// paid for by the executor.
+fn execute_create_committee(
+    execution: &mut ProposalExecution,
+    label: String,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+    scope: u32,
+    current_time: i64,
+) -> Result<()> {
+    validate_label(&label)?;
+    validate_scope(scope)?;
+    validate_multisig(&signers, threshold)?;
+    validate_unique_signers(&signers)?;
+
+    let [committee_info] = execution.remaining_accounts else {
+        return Err(SolLearningError::InvalidProposalAccounts.into());
+    };
+
+    let (expected_committee, committee_bump) = Pubkey::find_program_address(
+        &[MULTISIG_SEED, label.as_bytes()],
+        execution.program_id,
+    );
+    require!(
+        committee_info.key() == expected_committee && committee_info.data_is_empty(),
+        SolLearningError::InvalidProposalAccounts
+    );
+
+    create_program_account(
+        execution.payer.clone(),
+        execution.system_program.clone(),
+        committee_info.clone(),
+        execution.program_id,
+        calculate_multisig_space(&signers, &label),
+        &[MULTISIG_SEED, label.as_bytes(), &[committee_bump]],
+    )?;
+
+    let mut committee = Multisig::default();
+    initialize_multisig(
+        &mut committee,
+        &signers,
+        threshold,
+        execution.governance.key(),
+        &label,
+        scope,
+        committee_bump,
+    );
+    write_account(committee_info, &committee)?;
+
+    emit_multisig_created(committee_info.key(), &signers, threshold, &label, scope, current_time);
+
+    Ok(())
+}
+
+// Reallocs the multisig to fit its signer list. The executor tops up rent when
+// it grows and receives the surplus when it shrinks.
+fn resize_multisig<'info>(
+    multisig: &Account<'info, Multisig>,
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+) -> Result<()> {
+    let multisig_info = multisig.to_account_info();
+    let new_space = calculate_multisig_space(&multisig.signers, &multisig.label);
+    let required_lamports = Rent::get()?.minimum_balance(new_space);
+    let current_lamports = multisig_info.lamports();
+
+    if required_lamports > current_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program_info.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: multisig_info.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    } else if current_lamports > required_lamports {
+        let surplus = current_lamports - required_lamports;
+        **multisig_info.try_borrow_mut_lamports()? -= surplus;
+        **payer.try_borrow_mut_lamports()? += surplus;
+    }
+
+    multisig_info.realloc(new_space, false)?;
+    Ok(())
+}
+
+// Invokes the stored instruction with the governance PDA as the only available
+// signer. The program state PDA holds the mint and freeze authorities and the
+// clawback delegation, so it never signs for governance.
+fn execute_custom(
+    execution: &mut ProposalExecution,
+    program_id: Pubkey,
+    accounts: Vec<ProposalAccountMeta>,
+    data: Vec<u8>,
+) -> Result<()> {
+    require!(
+        execution.remaining_accounts.iter().any(|account| account.key() == program_id),
+        SolLearningError::InvalidProposalAccounts
+    );
+
+    let account_metas = accounts
+        .iter()
+        .map(|meta| {
+            if meta.is_writable {
+                AccountMeta::new(meta.pubkey, meta.is_signer)
+            } else {
+                AccountMeta::new_readonly(meta.pubkey, meta.is_signer)
+            }
+        })
+        .collect();
+
+    let instruction = Instruction {
+        program_id,
+        accounts: account_metas,
+        data,
+    };
+
+    let mut account_infos = execution.remaining_accounts.to_vec();
+    account_infos.push(execution.governance.clone());
+
+    invoke_signed(&instruction, &account_infos, &[execution.governance_seeds])?;
+
+    Ok(())
+}
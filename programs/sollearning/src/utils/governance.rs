@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::states::signers::Multisig;
+use crate::states::config::ProgramConfig;
+use crate::states::proposal::{Proposal, ProposalInstruction};
+use crate::constants::*;
+use crate::error::SolLearningError;
+
+pub fn check_proposal_scope(multisig: &Multisig, instruction: &ProposalInstruction) -> Result<()> {
+    let required_scope = instruction.required_scope();
+    require!(multisig.scope & required_scope == required_scope, SolLearningError::ProposalOutOfScope);
+    Ok(())
+}
+
+// Approvals are stored by signer position, so they stop meaning anything once
+// a signer is added or removed. Proposals from an older signer set can no
+// longer be approved or executed and have to be raised again.
+pub fn check_proposal_membership(multisig: &Multisig, proposal: &Proposal) -> Result<()> {
+    require!(
+        proposal.membership_version == multisig.membership_version,
+        SolLearningError::StaleProposal
+    );
+    Ok(())
+}
+
+pub fn is_proposal_expired(proposal: &Proposal, current_time: i64) -> bool {
+    current_time > proposal.expires_at
+}
+
+// Proposers may pick an expiry within the governed bounds; until governance
+// sets bounds only the default is accepted. The proposal must also outlive its
+// timelock or it could never be executed.
+pub fn resolve_proposal_expiry(config: &ProgramConfig, expiry: Option<i64>, delay: i64) -> Result<i64> {
+    let expiry = expiry.unwrap_or(PROPOSAL_EXPIRATION_TIME);
+    let (min_expiry, max_expiry) = if config.max_proposal_expiry == 0 {
+        (PROPOSAL_EXPIRATION_TIME, PROPOSAL_EXPIRATION_TIME)
+    } else {
+        (config.min_proposal_expiry, config.max_proposal_expiry)
+    };
+    require!(
+        (min_expiry..=max_expiry).contains(&expiry) && expiry > delay,
+        SolLearningError::InvalidProposalExpiry
+    );
+    Ok(expiry)
+}
+
+// A per-variant threshold is a share of the signer set in basis points, rounded
+// up; zero falls back to the multisig threshold.
+pub fn resolve_proposal_threshold(
+    config: &ProgramConfig,
+    multisig: &Multisig,
+    instruction: &ProposalInstruction,
+) -> u8 {
+    let threshold_bps = config.proposal_thresholds[instruction.kind()] as u64;
+    if threshold_bps == 0 {
+        return multisig.threshold;
+    }
+    let signers = multisig.signers.len() as u64;
+    (signers * threshold_bps).div_ceil(BPS_DENOMINATOR).max(1) as u8
+}
+
+// Only rejects; persisting the Expired status is left to expire_proposal since
+// any write made here is rolled back with the error.
+pub fn check_proposal_not_expired(proposal: &Proposal, current_time: i64) -> Result<()> {
+    require!(!is_proposal_expired(proposal, current_time), SolLearningError::ProposalExpired);
+    Ok(())
+}
+
+// Custom instructions may only ask for the signature of the proposing
+// governance PDA (a multisig or the community governance PDA). The program
+// state PDA is never offered: it is the mint and freeze authority and the
+// clawback delegate of student token accounts. Calls back into this program
+// are rejected so a CPI can never race the account state held by
+// execute_proposal.
+pub fn validate_custom_instruction(
+    instruction: &ProposalInstruction,
+    governance: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<()> {
+    if let ProposalInstruction::Custom { program_id: target, accounts, data } = instruction {
+        require!(target != program_id, SolLearningError::InvalidCustomInstruction);
+        require!(
+            accounts.len() <= MAX_CUSTOM_INSTRUCTION_ACCOUNTS && data.len() <= MAX_CUSTOM_INSTRUCTION_DATA,
+            SolLearningError::InvalidCustomInstruction
+        );
+
+        require!(
+            accounts.iter().all(|meta| !meta.is_signer || meta.pubkey == *governance),
+            SolLearningError::InvalidCustomInstruction
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount};
+use crate::states::program::ProgramState;
+use crate::states::config::ProgramConfig;
+use crate::states::educator::EducatorAccount;
+use crate::utils::blocklist::check_not_blocklisted;
+use crate::states::student::StudentInfo;
+use crate::states::course::{Course, CourseCompletion, CourseCoEducators, CoEducatorCredited, CoEducatorCredit, CoEducatorCredits};
+use crate::error::SolLearningError;
+use crate::constants::{PROGRAM_STATE_SEED, BLOCKLIST_SEED, MINT_COOLDOWN_PERIOD, BPS_DENOMINATOR, CO_EDUCATOR_MINT_ACCOUNTS};
+
+// Accounting shared by every path that awards a course completion through the
+// mint_to_student flow.
+
+pub fn validate_mint_cooldown(educator: &EducatorAccount, current_time: i64) -> Result<()> {
+    let time_since_last_mint = current_time - educator.last_mint_time;
+    require!(
+        time_since_last_mint >= MINT_COOLDOWN_PERIOD || educator.last_mint_time == 0,
+        SolLearningError::MintingTooFrequent
+    );
+    Ok(())
+}
+
+// Rewards only go to token accounts that still delegate at least the reward
+// to the ProgramState PDA, so revoke_completion can always claw them back
+pub fn validate_clawback_delegate(token_account: &TokenAccount, program_state: Pubkey, amount: u64) -> Result<()> {
+    require!(
+        token_account.delegate == anchor_lang::solana_program::program_option::COption::Some(program_state)
+            && token_account.delegated_amount >= amount,
+        SolLearningError::ClawbackDelegateRequired
+    );
+    Ok(())
+}
+
+// Incentive minted to the educator on top of a mint_to_student reward, limited
+// by what is left of the educator's reward budget
+pub fn educator_reward_amount(config: &ProgramConfig, educator: &EducatorAccount, amount: u64) -> u64 {
+    let remaining_budget = config.educator_reward_budget.saturating_sub(educator.rewards_earned);
+    ((amount as u128 * config.educator_reward_bps as u128 / BPS_DENOMINATOR as u128) as u64).min(remaining_budget)
+}
+
+// Rewards distribute_co_educator_shares mints on top of `amount`
+pub fn co_educator_rewards_total(course: &Course, co_educators: Option<&CourseCoEducators>, amount: u64) -> u64 {
+    match co_educators {
+        Some(co_educators) if course.co_educator_count > 0 => {
+            let reward = (amount as u128 * co_educators.reward_percent as u128 / 100) as u64;
+            reward.saturating_mul(co_educators.co_educators.len() as u64)
+        }
+        _ => 0,
+    }
+}
+
+pub fn update_educator_stats(educator: &mut EducatorAccount, amount: u64, current_time: i64) -> Result<()> {
+    educator.total_minted = educator.total_minted.checked_add(amount).ok_or(SolLearningError::Overflow)?;
+    educator.last_mint_time = current_time;
+    Ok(())
+}
+
+pub fn update_minted_supply(program_state: &mut ProgramState, amount: u64) -> Result<()> {
+    program_state.total_minted = program_state.total_minted.checked_add(amount).ok_or(SolLearningError::Overflow)?;
+    Ok(())
+}
+
+pub fn update_student_info(student_info: &mut StudentInfo, amount: u64, current_time: i64) -> Result<()> {
+    student_info.total_earned = student_info.total_earned.checked_add(amount).ok_or(SolLearningError::Overflow)?;
+    student_info.courses_completed = student_info.courses_completed.checked_add(1).ok_or(SolLearningError::Overflow)?;
+    student_info.last_activity = current_time;
+    Ok(())
+}
+
+pub fn initialize_course_completion(
+    course_completion: &mut CourseCompletion,
+    student: Pubkey,
+    course_id: &str,
+    educator: Pubkey,
+    amount: u64,
+    current_time: i64,
+    bump: u8,
+) -> Result<()> {
+    course_completion.student = student;
+    course_completion.course_id = course_id.to_string();
+    course_completion.verified_by = educator;
+    course_completion.completion_time = current_time;
+    course_completion.tokens_awarded = amount;
+    course_completion.lead_share = amount;
+    course_completion.co_educator_credits = CoEducatorCredits::default();
+    course_completion.educator_reward = 0;
+    course_completion.bump = bump;
+    Ok(())
+}
+
+// Mints `amount` to `recipient`, signed by the ProgramState PDA as mint authority
+pub fn mint_reward<'info>(
+    token_program: AccountInfo<'info>,
+    token_mint: AccountInfo<'info>,
+    recipient: AccountInfo<'info>,
+    program_state: &Account<'info, ProgramState>,
+    amount: u64,
+) -> Result<()> {
+    let signer_seeds = &[PROGRAM_STATE_SEED, &[program_state.bump]];
+    let signer = &[&signer_seeds[..]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program,
+            token::MintTo {
+                mint: token_mint,
+                to: recipient,
+                authority: program_state.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+    Ok(())
+}
+
+// Co-taught courses pass one [EducatorAccount, token account, blocklist entry]
+// triple per co-educator through the remaining accounts, in CourseCoEducators
+// order. Each co-educator must be active and not blocklisted, and its token
+// account must keep the clawback delegate. It is credited its weighted share of
+// `amount` and minted the course's reward percentage. The lead's share, which
+// absorbs rounding, is returned for the caller to credit, along with what each
+// co-educator got so the completion can record it.
+#[allow(clippy::too_many_arguments)]
+pub fn distribute_co_educator_shares<'info>(
+    course: &Account<'info, Course>,
+    co_educators: Option<&CourseCoEducators>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    token_program: AccountInfo<'info>,
+    token_mint: AccountInfo<'info>,
+    program_state: &mut Account<'info, ProgramState>,
+    amount: u64,
+    current_time: i64,
+    program_id: &Pubkey,
+) -> Result<(u64, CoEducatorCredits)> {
+    let mut credits = CoEducatorCredits::default();
+    if course.co_educator_count == 0 {
+        return Ok((amount, credits));
+    }
+
+    let co_educators = co_educators.ok_or(SolLearningError::InvalidCoEducatorAccounts)?;
+    require!(
+        co_educators.course == course.key()
+            && remaining_accounts.len() == co_educators.co_educators.len() * CO_EDUCATOR_MINT_ACCOUNTS,
+        SolLearningError::InvalidCoEducatorAccounts
+    );
+
+    let total_weight = co_educators
+        .co_educators
+        .iter()
+        .fold(co_educators.lead_weight as u128, |total, share| total + share.weight as u128);
+    let reward = (amount as u128 * co_educators.reward_percent as u128 / 100) as u64;
+    let mut lead_share = amount;
+
+    let entries = remaining_accounts.chunks(CO_EDUCATOR_MINT_ACCOUNTS);
+    for ((share, entry), credit) in co_educators.co_educators.iter().zip(entries).zip(credits.iter_mut()) {
+        require!(entry[0].is_writable && entry[1].is_writable, SolLearningError::InvalidCoEducatorAccounts);
+
+        let mut educator = Account::<EducatorAccount>::try_from(&entry[0])
+            .map_err(|_| SolLearningError::InvalidCoEducatorAccounts)?;
+        require!(educator.key() == share.educator, SolLearningError::InvalidCoEducatorAccounts);
+        require!(educator.is_active, SolLearningError::InactiveEducator);
+
+        let (expected_blocklist, _) =
+            Pubkey::find_program_address(&[BLOCKLIST_SEED, educator.educator_address.as_ref()], program_id);
+        require!(entry[2].key() == expected_blocklist, SolLearningError::InvalidCoEducatorAccounts);
+        check_not_blocklisted(&entry[2], program_id)?;
+
+        let token_account = Account::<TokenAccount>::try_from(&entry[1])
+            .map_err(|_| SolLearningError::InvalidTokenAccount)?;
+        require!(token_account.mint == program_state.token_mint, SolLearningError::InvalidMint);
+        require!(token_account.owner == educator.educator_address, SolLearningError::InvalidTokenAccount);
+        validate_clawback_delegate(&token_account, program_state.key(), reward)?;
+
+        let credited = (amount as u128 * share.weight as u128 / total_weight) as u64;
+        lead_share = lead_share.checked_sub(credited).ok_or(SolLearningError::Overflow)?;
+        educator.total_minted = educator.total_minted.checked_add(credited).ok_or(SolLearningError::Overflow)?;
+        educator.exit(program_id)?;
+
+        if reward > 0 {
+            mint_reward(token_program.clone(), token_mint.clone(), entry[1].clone(), program_state, reward)?;
+            update_minted_supply(program_state, reward)?;
+        }
+
+        *credit = CoEducatorCredit { educator: share.educator, share: credited, reward };
+
+        emit!(CoEducatorCredited {
+            course: course.key(),
+            educator: share.educator,
+            share: credited,
+            reward,
+            timestamp: current_time,
+        });
+    }
+
+    Ok((lead_share, credits))
+}
@@ -1,2 +1,16 @@
 pub mod pause;
-pub mod config;
\ No newline at end of file
+pub mod config;
+pub mod signature;
+pub mod operator;
+pub mod educator;
+pub mod access;
+pub mod authority;
+pub mod governance;
+pub mod execution;
+pub mod voting;
+pub mod circuit_breaker;
+pub mod blocklist;
+pub mod minting;
+pub mod accounts;
+pub mod reputation;
+pub mod multisig;
\ No newline at end of file
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use std::collections::HashSet;
+use crate::states::signers::{Multisig, MultisigCreated};
+use crate::constants::*;
+use crate::error::SolLearningError;
+
+// An empty label would collide with the root multisig address
+pub fn validate_label(label: &str) -> Result<()> {
+    require!(
+        !label.is_empty() && label.len() <= MAX_MULTISIG_LABEL_LENGTH,
+        SolLearningError::InvalidMultisigLabel
+    );
+    Ok(())
+}
+
+pub fn validate_scope(scope: u32) -> Result<()> {
+    require!(
+        scope != 0 && scope & !GOVERNANCE_SCOPE_ALL == 0,
+        SolLearningError::InvalidMultisigConfig
+    );
+    Ok(())
+}
+
+pub fn validate_multisig(signers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(!signers.is_empty(), SolLearningError::InvalidMultisigConfig);
+    require!(signers.len() <= MAX_SIGNERS, SolLearningError::MaxSignersReached);
+    require!(
+        threshold > 0 && (threshold as usize) <= signers.len(),
+        SolLearningError::InvalidThreshold
+    );
+    Ok(())
+}
+
+pub fn validate_unique_signers(signers: &[Pubkey]) -> Result<()> {
+    let mut unique_signers = HashSet::new();
+    for signer in signers {
+        require!(unique_signers.insert(*signer), SolLearningError::SignerAlreadyExists);
+    }
+    Ok(())
+}
+
+pub fn initialize_multisig(
+    multisig: &mut Multisig,
+    signers: &[Pubkey],
+    threshold: u8,
+    authority: Pubkey,
+    label: &str,
+    scope: u32,
+    bump: u8,
+) {
+    multisig.signers = signers.to_vec();
+    multisig.threshold = threshold;
+    multisig.proposal_count = 0;
+    multisig.authority = authority;
+    multisig.label = label.to_string();
+    multisig.scope = scope;
+    multisig.membership_version = 0;
+    multisig.bump = bump;
+}
+
+pub fn emit_multisig_created(
+    multisig_key: Pubkey,
+    signers: &[Pubkey],
+    threshold: u8,
+    label: &str,
+    scope: u32,
+    timestamp: i64,
+) {
+    emit!(MultisigCreated {
+        multisig: multisig_key,
+        signers: signers.to_vec(),
+        threshold,
+        label: label.to_string(),
+        scope,
+        timestamp,
+    });
+
+    msg!(
+        "Multisig created with {} signers and threshold of {}",
+        signers.len(),
+        threshold
+    );
+}
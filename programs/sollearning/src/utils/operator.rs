@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::states::educator::EducatorOperator;
+use crate::error::SolLearningError;
+
+// Charges a completion reward against the operator's allowance. Only called
+// when the signer acted under the Operator role.
+pub fn consume_operator_allowance(
+    operator: &mut Option<Account<EducatorOperator>>,
+    amount: u64,
+) -> Result<()> {
+    let operator = operator.as_mut().ok_or(SolLearningError::Unauthorized)?;
+    charge_operator_allowance(operator, amount)
+}
+
+pub fn charge_operator_allowance(operator: &mut EducatorOperator, amount: u64) -> Result<()> {
+    let total_minted = operator
+        .total_minted
+        .checked_add(amount)
+        .ok_or(SolLearningError::Overflow)?;
+    require!(total_minted <= operator.mint_allowance, SolLearningError::OperatorAllowanceExceeded);
+    operator.total_minted = total_minted;
+    Ok(())
+}
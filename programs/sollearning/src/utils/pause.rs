@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::states::program::ProgramState;
+use crate::states::emergency::{PauseRecord, PauseReason, PauseExtended};
+use crate::states::proposal::ProposalInstruction;
+use crate::constants::{PAUSE_FLAG_ALL, PAUSE_FLAG_GOVERNANCE};
 use crate::error::SolLearningError;
 
 pub fn check_program_running(program_state: &Account<ProgramState>) -> Result<()> {
@@ -15,10 +18,87 @@ pub fn check_function_running(program_state: &Account<ProgramState>, function_fl
     Ok(())
 }
 
-pub fn check_authority(program_state: &Account<ProgramState>, authority: &Signer) -> Result<()> {
-    require!(
-        program_state.authority == authority.key(),
-        SolLearningError::UnauthorizedAuthority
-    );
+// Recovery instructions bypass both the global pause and the governance flag
+pub fn check_governance_running(
+    program_state: &Account<ProgramState>,
+    instruction: &ProposalInstruction,
+) -> Result<()> {
+    if instruction.is_recovery() {
+        return Ok(());
+    }
+    check_program_running(program_state)?;
+    check_function_running(program_state, PAUSE_FLAG_GOVERNANCE)
+}
+
+// Pausing the whole program also sets every function flag. The pause record
+// keeps the flags that were not already set, so resuming clears only those and
+// leaves granular pauses and circuit breaker trips in place.
+pub fn set_program_paused(program_state: &mut ProgramState, pause_record: &mut PauseRecord, paused: bool) {
+    if paused {
+        if !program_state.paused {
+            pause_record.pause_flags = PAUSE_FLAG_ALL & !program_state.pause_flags;
+        }
+        program_state.pause_flags = PAUSE_FLAG_ALL;
+    } else {
+        program_state.pause_flags &= !pause_record.pause_flags;
+        pause_record.pause_flags = 0;
+    }
+    program_state.paused = paused;
+}
+
+// Pausing an already paused program replaces the reason but never brings the
+// scheduled resume forward, and a pause without a resume time stays
+// indefinite.
+pub fn record_pause(
+    pause_record: &mut PauseRecord,
+    reason: &PauseReason,
+    paused_by: Pubkey,
+    already_paused: bool,
+    current_time: i64,
+) -> Result<()> {
+    let mut resume_after = reason.resume_after;
+    if let Some(new_resume_after) = resume_after {
+        require!(new_resume_after > current_time, SolLearningError::InvalidPauseSchedule);
+        if already_paused {
+            resume_after = pause_record
+                .resume_after
+                .map(|current| current.max(new_resume_after));
+        }
+    }
+    pause_record.reason_code = reason.code;
+    pause_record.reason_hash = reason.text_hash;
+    pause_record.paused_by = paused_by;
+    pause_record.paused_at = current_time;
+    pause_record.resume_after = resume_after;
+    pause_record.extended_by = None;
+    pause_record.resumed_at = None;
     Ok(())
-}
\ No newline at end of file
+}
+
+// Extensions can only push the resume time later; `None` keeps the program
+// paused until it is resumed explicitly.
+pub fn extend_pause(
+    pause_record: &mut PauseRecord,
+    resume_after: Option<i64>,
+    extended_by: Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    if let Some(new_resume_after) = resume_after {
+        let extends = pause_record
+            .resume_after
+            .map(|current| new_resume_after > current)
+            .unwrap_or(false);
+        require!(extends, SolLearningError::InvalidPauseSchedule);
+    }
+
+    pause_record.resume_after = resume_after;
+    pause_record.extended_by = Some(extended_by);
+
+    emit!(PauseExtended {
+        resume_after,
+        extended_by,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
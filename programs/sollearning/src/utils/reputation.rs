@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use crate::states::config::ProgramConfig;
+use crate::states::educator::EducatorAccount;
+use crate::states::reputation::{
+    EducatorReputation, EducatorStudent, ReputationTier, ReputationUpdated, EducatorTierApplied,
+};
+use crate::error::SolLearningError;
+use crate::constants::{EDUCATOR_REPUTATION_SEED, EDUCATOR_STUDENT_SEED, MAX_REPUTATION_TIERS};
+use crate::utils::accounts::{create_program_account, write_account};
+use crate::utils::educator::validate_mint_limit;
+
+// The reputation and educator-student PDAs are passed as unchecked accounts
+// with their seeds enforced by the accounts struct, and created on first use.
+
+pub fn load_or_create_reputation<'info>(
+    reputation: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    educator: Pubkey,
+    bump: u8,
+    program_id: &Pubkey,
+) -> Result<EducatorReputation> {
+    if !reputation.data_is_empty() {
+        require!(reputation.owner == program_id, SolLearningError::InvalidReputationAccount);
+        let data = reputation.try_borrow_data()?;
+        return EducatorReputation::try_deserialize(&mut &data[..]);
+    }
+
+    create_program_account(
+        payer.clone(),
+        system_program.clone(),
+        reputation.clone(),
+        program_id,
+        8 + std::mem::size_of::<EducatorReputation>(),
+        &[EDUCATOR_REPUTATION_SEED, educator.as_ref(), &[bump]],
+    )?;
+
+    Ok(EducatorReputation {
+        educator,
+        completions: 0,
+        unique_students: 0,
+        disputes_lost: 0,
+        rating_count: 0,
+        rating_sum: 0,
+        tier: 0,
+        last_updated_at: 0,
+        bump,
+    })
+}
+
+pub fn store_reputation(reputation_account: &AccountInfo, reputation: &mut EducatorReputation, current_time: i64) -> Result<()> {
+    reputation.last_updated_at = current_time;
+    write_account(reputation_account, reputation)?;
+
+    emit!(ReputationUpdated {
+        educator: reputation.educator,
+        completions: reputation.completions,
+        unique_students: reputation.unique_students,
+        disputes_lost: reputation.disputes_lost,
+        rating_count: reputation.rating_count,
+        rating_sum: reputation.rating_sum,
+        timestamp: current_time,
+    });
+    Ok(())
+}
+
+// Returns true the first time `educator` awards `student`
+#[allow(clippy::too_many_arguments)]
+pub fn mark_student_taught<'info>(
+    educator_student: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    educator: Pubkey,
+    student: Pubkey,
+    bump: u8,
+    program_id: &Pubkey,
+    current_time: i64,
+) -> Result<bool> {
+    if !educator_student.data_is_empty() {
+        return Ok(false);
+    }
+
+    create_program_account(
+        payer.clone(),
+        system_program.clone(),
+        educator_student.clone(),
+        program_id,
+        8 + std::mem::size_of::<EducatorStudent>(),
+        &[EDUCATOR_STUDENT_SEED, educator.as_ref(), student.as_ref(), &[bump]],
+    )?;
+    write_account(
+        educator_student,
+        &EducatorStudent {
+            educator,
+            student,
+            first_completion_at: current_time,
+            bump,
+        },
+    )?;
+    Ok(true)
+}
+
+pub fn record_completion(reputation: &mut EducatorReputation, new_student: bool) -> Result<()> {
+    reputation.completions = reputation.completions.checked_add(1).ok_or(SolLearningError::Overflow)?;
+    if new_student {
+        reputation.unique_students = reputation.unique_students.checked_add(1).ok_or(SolLearningError::Overflow)?;
+    }
+    Ok(())
+}
+
+pub fn record_dispute_lost(reputation: &mut EducatorReputation) -> Result<()> {
+    reputation.disputes_lost = reputation.disputes_lost.checked_add(1).ok_or(SolLearningError::Overflow)?;
+    Ok(())
+}
+
+// `previous` is the rating being replaced when a student edits their rating
+pub fn record_rating(reputation: &mut EducatorReputation, previous: Option<u8>, rating: u8) -> Result<()> {
+    match previous {
+        Some(previous) => {
+            reputation.rating_sum = reputation
+                .rating_sum
+                .checked_sub(previous as u64)
+                .ok_or(SolLearningError::Overflow)?;
+        }
+        None => {
+            reputation.rating_count = reputation.rating_count.checked_add(1).ok_or(SolLearningError::Overflow)?;
+        }
+    }
+    reputation.rating_sum = reputation.rating_sum.checked_add(rating as u64).ok_or(SolLearningError::Overflow)?;
+    Ok(())
+}
+
+pub fn validate_reputation_tiers(tiers: &[ReputationTier]) -> Result<()> {
+    require!(tiers.len() <= MAX_REPUTATION_TIERS, SolLearningError::InvalidReputationTiers);
+    for tier in tiers {
+        validate_mint_limit(tier.mint_limit).map_err(|_| SolLearningError::InvalidReputationTiers)?;
+    }
+    require!(
+        tiers.windows(2).all(|pair| pair[0].mint_limit < pair[1].mint_limit),
+        SolLearningError::InvalidReputationTiers
+    );
+    Ok(())
+}
+
+fn qualifies(tier: &ReputationTier, reputation: &EducatorReputation) -> bool {
+    let meets_rating = tier.min_average_rating == 0
+        || (reputation.rating_count > 0
+            && reputation.rating_sum * 100 / reputation.rating_count >= tier.min_average_rating as u64);
+
+    reputation.completions >= tier.min_completions
+        && reputation.unique_students >= tier.min_unique_students
+        && reputation.disputes_lost <= tier.max_disputes_lost
+        && meets_rating
+}
+
+// The highest tier the educator qualifies for sets its mint_limit; the first
+// tier is the floor for educators that qualify for none. Does nothing while no
+// tiers are configured.
+pub fn apply_reputation_tier(
+    config: &ProgramConfig,
+    reputation: &mut EducatorReputation,
+    educator: &mut EducatorAccount,
+    educator_key: Pubkey,
+    current_time: i64,
+) {
+    let tiers = &config.reputation_tiers[..config.reputation_tier_count as usize];
+    if tiers.is_empty() {
+        return;
+    }
+
+    let tier = tiers.iter().rposition(|tier| qualifies(tier, reputation)).unwrap_or(0);
+    let mint_limit = tiers[tier].mint_limit;
+    reputation.tier = tier as u8;
+
+    if educator.mint_limit != mint_limit {
+        let previous_mint_limit = educator.mint_limit;
+        educator.mint_limit = mint_limit;
+        educator.last_updated_at = current_time;
+
+        emit!(EducatorTierApplied {
+            educator: educator_key,
+            tier: tier as u8,
+            previous_mint_limit,
+            mint_limit,
+            timestamp: current_time,
+        });
+    }
+}
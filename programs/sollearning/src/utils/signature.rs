@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+use crate::error::SolLearningError;
+
+const ED25519_HEADER_SIZE: usize = 2;
+const ED25519_OFFSETS_SIZE: usize = 14;
+const ED25519_PUBKEY_SIZE: usize = 32;
+const ED25519_SIGNATURE_SIZE: usize = 64;
+const CURRENT_INSTRUCTION_INDEX: u16 = u16::MAX;
+
+// Checks that the instruction right before the current one is an ed25519
+// verification of `expected_message` signed by `expected_signer`. The native
+// program has already verified the signature by the time we get here, so only
+// the signer and message it covered need to be matched.
+pub fn verify_ed25519_instruction(
+    instructions_sysvar: &AccountInfo,
+    expected_signer: &Pubkey,
+    expected_message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, SolLearningError::InvalidSignatureInstruction);
+
+    let instruction = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+
+    require!(
+        instruction.program_id == ed25519_program::ID && instruction.accounts.is_empty(),
+        SolLearningError::InvalidSignatureInstruction
+    );
+
+    let data = &instruction.data;
+    require!(
+        data.len() >= ED25519_HEADER_SIZE + ED25519_OFFSETS_SIZE && data[0] == 1,
+        SolLearningError::InvalidSignatureInstruction
+    );
+
+    let offsets = &data[ED25519_HEADER_SIZE..ED25519_HEADER_SIZE + ED25519_OFFSETS_SIZE];
+    let signature_offset = read_u16(offsets, 0) as usize;
+    let signature_instruction_index = read_u16(offsets, 2);
+    let public_key_offset = read_u16(offsets, 4) as usize;
+    let public_key_instruction_index = read_u16(offsets, 6);
+    let message_offset = read_u16(offsets, 8) as usize;
+    let message_size = read_u16(offsets, 10) as usize;
+    let message_instruction_index = read_u16(offsets, 12);
+
+    require!(
+        signature_instruction_index == CURRENT_INSTRUCTION_INDEX
+            && public_key_instruction_index == CURRENT_INSTRUCTION_INDEX
+            && message_instruction_index == CURRENT_INSTRUCTION_INDEX,
+        SolLearningError::InvalidSignatureInstruction
+    );
+
+    require!(
+        signature_offset + ED25519_SIGNATURE_SIZE <= data.len()
+            && public_key_offset + ED25519_PUBKEY_SIZE <= data.len()
+            && message_offset + message_size <= data.len(),
+        SolLearningError::InvalidSignatureInstruction
+    );
+
+    require!(
+        &data[public_key_offset..public_key_offset + ED25519_PUBKEY_SIZE] == expected_signer.as_ref(),
+        SolLearningError::InvalidAttestationSigner
+    );
+
+    require!(
+        &data[message_offset..message_offset + message_size] == expected_message,
+        SolLearningError::AttestationMismatch
+    );
+
+    Ok(())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([data[offset], data[offset + 1]])
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::states::config::ProgramConfig;
+use crate::states::proposal::ProposalInstruction;
+use crate::states::voting::{VoterStake, CommunityProposal};
+use crate::constants::*;
+use crate::error::SolLearningError;
+
+pub fn validate_voting_config(
+    vote_quorum: u64,
+    vote_threshold_bps: u16,
+    voting_period: i64,
+    community_scope: u32,
+) -> Result<()> {
+    require!(vote_quorum > 0, SolLearningError::InvalidVotingConfig);
+    require!(
+        vote_threshold_bps > 0 && vote_threshold_bps as u64 <= BPS_DENOMINATOR,
+        SolLearningError::InvalidVotingConfig
+    );
+    require!(
+        voting_period > 0 && voting_period <= MAX_VOTING_PERIOD,
+        SolLearningError::InvalidVotingConfig
+    );
+    // Membership instructions act on the proposing multisig, which community
+    // proposals do not have.
+    require!(
+        community_scope & !GOVERNANCE_SCOPE_ALL == 0 && community_scope & GOVERNANCE_SCOPE_MEMBERSHIP == 0,
+        SolLearningError::InvalidVotingConfig
+    );
+    Ok(())
+}
+
+// A zero community scope keeps token voting switched off entirely.
+pub fn check_community_scope(config: &ProgramConfig, instruction: &ProposalInstruction) -> Result<()> {
+    require!(config.community_scope != 0, SolLearningError::CommunityVotingDisabled);
+    let required_scope = instruction.required_scope();
+    require!(
+        config.community_scope & required_scope == required_scope,
+        SolLearningError::ProposalOutOfScope
+    );
+    Ok(())
+}
+
+// Weight is snapshotted at proposal creation: a stake topped up afterwards
+// cannot vote on it, so weight cannot be bought once the outcome is in play.
+pub fn get_voting_weight(stake: &VoterStake, proposal: &CommunityProposal) -> Result<u64> {
+    require!(
+        stake.amount > 0 && stake.last_deposit_at <= proposal.created_at,
+        SolLearningError::NoVotingWeight
+    );
+    Ok(stake.amount)
+}
+
+pub fn community_proposal_passed(config: &ProgramConfig, yes_weight: u64, no_weight: u64) -> bool {
+    let total = yes_weight as u128 + no_weight as u128;
+    total >= config.vote_quorum as u128
+        && yes_weight as u128 * BPS_DENOMINATOR as u128 >= config.vote_threshold_bps as u128 * total
+}
@@ -11,7 +11,8 @@ use {
             StudentInfo, 
             Course, 
             ProgramState, 
-            CourseCompletion
+            CourseCompletion,
+            ProposalInstruction
         },
         error::SolLearningError,
     }
@@ -228,4 +229,66 @@ pub async fn burn_tokens(
 
     ctx.banks_client.process_transaction(tx).await
         .expect("Token burn should succeed");
-}
\ No newline at end of file
+}
+
+/// Helper function to create the root multisig with a single signer
+pub async fn create_root_multisig(ctx: &mut TestContext) -> Keypair {
+    let signer = Keypair::new();
+
+    let ix = sollearning::instruction::create_multisig(
+        ctx.authority.pubkey(),
+        vec![signer.pubkey()],
+        1
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &ctx.authority],
+        ctx.recent_blockhash
+    );
+
+    ctx.banks_client.process_transaction(tx).await
+        .expect("Multisig creation should succeed");
+
+    signer
+}
+
+/// Helper function to pass a governance instruction through the root multisig
+/// created by `create_root_multisig`, proposing and executing it in one go
+pub async fn execute_governance_instruction(
+    ctx: &mut TestContext,
+    signer: &Keypair,
+    proposal_index: u64,
+    instruction: ProposalInstruction,
+    remaining_accounts: Vec<Pubkey>
+) -> Result<(), BanksClientError> {
+    let (multisig_pda, _) = Pubkey::find_program_address(&[b"multisig"], sollearning::ID);
+    let (proposal_pda, _) = Pubkey::find_program_address(
+        &[b"proposal", multisig_pda.as_ref(), &proposal_index.to_le_bytes()],
+        sollearning::ID
+    );
+
+    let create_ix = sollearning::instruction::create_proposal(
+        signer.pubkey(),
+        instruction,
+        "Governance setting".to_string(),
+        None
+    );
+
+    let execute_ix = sollearning::instruction::execute_proposal(
+        signer.pubkey(),
+        proposal_pda,
+        multisig_pda,
+        remaining_accounts
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_ix, execute_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, signer],
+        ctx.recent_blockhash
+    );
+
+    ctx.banks_client.process_transaction(tx).await
+}
@@ -0,0 +1,321 @@
+#[cfg(test)]
+mod blocklist_tests {
+    use super::*;
+    use crate::common::{
+        setup_test,
+        register_educator,
+        register_student,
+        create_course,
+        mint_tokens_to_student
+    };
+    use sollearning::{
+        state::BlocklistEntry,
+        constants::BLOCKLIST_SEED
+    };
+
+    fn blocklist_pda(address: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[BLOCKLIST_SEED, address.as_ref()],
+            &sollearning::ID
+        ).0
+    }
+
+    async fn block(ctx: &mut TestContext, address: Pubkey) {
+        let ix = sollearning::instruction::block_address(
+            ctx.authority.pubkey(),
+            address,
+            [9u8; 32]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Blocking should succeed");
+    }
+
+    async fn unblock(ctx: &mut TestContext, address: Pubkey) -> Result<(), BanksClientError> {
+        let ix = sollearning::instruction::unblock_address(
+            ctx.authority.pubkey(),
+            blocklist_pda(address)
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    #[tokio::test]
+    async fn blocked_student_cannot_register() {
+        let mut ctx = setup_test().await;
+        let student = Keypair::new();
+        block(&mut ctx, student.pubkey()).await;
+
+        let (student_pda, _) = Pubkey::find_program_address(
+            &[b"student", student.pubkey().as_ref()],
+            sollearning::ID
+        );
+        let ix = sollearning::instruction::register_student(
+            student.pubkey(),
+            student_pda
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &student],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Blocked student registration should fail");
+    }
+
+    #[tokio::test]
+    async fn blocked_student_cannot_receive_mint() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let (course_pda, _) = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        block(&mut ctx, student.pubkey()).await;
+
+        let mint_ix = sollearning::instruction::mint_to_student(
+            educator.pubkey(),
+            student.pubkey(),
+            course_pda,
+            course_id.to_string(),
+            100
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[mint_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &educator],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Minting to a blocked student should fail");
+    }
+
+    #[tokio::test]
+    async fn blocked_educator_cannot_batch_mint() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let student = Keypair::new();
+        let (student_pda, _) = register_student(&mut ctx, student.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let (course_pda, _) = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        block(&mut ctx, educator.pubkey()).await;
+
+        let (course_completion_pda, _) = Pubkey::find_program_address(
+            &[b"course-completion", student.pubkey().as_ref(), course_id.as_bytes()],
+            sollearning::ID
+        );
+
+        let ix = sollearning::instruction::batch_mint_completions(
+            educator.pubkey(),
+            course_pda,
+            course_id.to_string(),
+            100,
+            vec![
+                AccountMeta::new(student_pda, false),
+                AccountMeta::new(get_associated_token_address(&student.pubkey(), &token_mint_pubkey()), false),
+                AccountMeta::new(course_completion_pda, false),
+                AccountMeta::new_readonly(blocklist_pda(student.pubkey()), false),
+            ]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &educator],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "A blocked educator cannot batch mint");
+    }
+
+    #[tokio::test]
+    async fn blocked_applicant_cannot_be_approved() {
+        let mut ctx = setup_test().await;
+
+        let applicant = Keypair::new();
+        let (application_pda, _) = Pubkey::find_program_address(
+            &[b"educator-application", applicant.pubkey().as_ref()],
+            sollearning::ID
+        );
+
+        let apply_ix = sollearning::instruction::apply_as_educator(
+            applicant.pubkey(),
+            application_pda,
+            [7u8; 32],
+            1_000
+        );
+
+        let tx1 = Transaction::new_signed_with_payer(
+            &[apply_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &applicant],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx1).await
+            .expect("Application should succeed");
+
+        block(&mut ctx, applicant.pubkey()).await;
+
+        let approve_ix = sollearning::instruction::approve_educator_application(
+            ctx.authority.pubkey(),
+            applicant.pubkey(),
+            application_pda,
+            Pubkey::find_program_address(&[b"educator", applicant.pubkey().as_ref()], sollearning::ID).0,
+            None
+        );
+
+        let tx2 = Transaction::new_signed_with_payer(
+            &[approve_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx2).await;
+
+        assert!(result.is_err(), "A blocked applicant cannot become an educator");
+    }
+
+    #[tokio::test]
+    async fn blocked_sender_cannot_transfer() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let sender = Keypair::new();
+        let recipient = Keypair::new();
+        let _ = register_student(&mut ctx, sender.pubkey()).await;
+        let _ = register_student(&mut ctx, recipient.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let (course_pda, _) = create_course(&mut ctx, educator.pubkey(), course_id).await;
+        let _ = mint_tokens_to_student(
+            &mut ctx,
+            educator.pubkey(),
+            sender.pubkey(),
+            course_pda,
+            course_id,
+            200
+        ).await;
+
+        block(&mut ctx, sender.pubkey()).await;
+
+        let transfer_ix = sollearning::instruction::transfer(
+            sender.pubkey(),
+            recipient.pubkey(),
+            50
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[transfer_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &sender],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Transfer from a blocked sender should fail");
+    }
+
+    #[tokio::test]
+    async fn appeal_is_recorded_and_unblock_closes_entry() {
+        let mut ctx = setup_test().await;
+        let blocked = Keypair::new();
+        block(&mut ctx, blocked.pubkey()).await;
+
+        let appeal_ix = sollearning::instruction::appeal_block(
+            blocked.pubkey(),
+            [4u8; 32]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[appeal_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &blocked],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Appeal should succeed");
+
+        let entry_account = ctx.banks_client
+            .get_account(blocklist_pda(blocked.pubkey()))
+            .await
+            .expect("Blocklist entry must exist");
+
+        let entry = BlocklistEntry::try_deserialize(
+            &mut entry_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(entry.appeal_hash, Some([4u8; 32]));
+
+        unblock(&mut ctx, blocked.pubkey()).await
+            .expect("Unblocking should succeed");
+
+        let entry_account = ctx.banks_client
+            .get_account(blocklist_pda(blocked.pubkey()))
+            .await
+            .expect("Account lookup should succeed");
+
+        assert!(entry_account.is_none(), "Blocklist entry should be closed");
+    }
+
+    #[tokio::test]
+    async fn outsider_cannot_block() {
+        let mut ctx = setup_test().await;
+        let outsider = Keypair::new();
+
+        let ix = sollearning::instruction::block_address(
+            outsider.pubkey(),
+            Keypair::new().pubkey(),
+            [9u8; 32]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &outsider],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Only pausers can block addresses");
+    }
+}
@@ -0,0 +1,5 @@
+mod role_matrix;
+mod blocklist;
+
+pub use role_matrix::*;
+pub use blocklist::*;
@@ -0,0 +1,302 @@
+#[cfg(test)]
+mod role_matrix_tests {
+    use super::*;
+    use crate::common::{
+        setup_test,
+        register_educator,
+        register_student,
+        create_course
+    };
+    use sollearning::{
+        state::{ProposalInstruction, PauseReason},
+        constants::{OPERATOR_PERMISSION_CREATE_COURSE, PAUSE_REASON_SECURITY_INCIDENT},
+        error::SolLearningError
+    };
+
+    // One actor per role; every instruction is tried with each role it must reject
+    struct Actors {
+        educator: Keypair,
+        educator_pda: Pubkey,
+        other_educator: Keypair,
+        operator: Keypair,
+        operator_pda: Pubkey,
+        governance_signer: Keypair,
+        outsider: Keypair,
+        student: Keypair,
+        course_pda: Pubkey,
+    }
+
+    const COURSE_ID: &str = "BLOCKCHAIN101";
+
+    async fn setup_actors(ctx: &mut TestContext) -> Actors {
+        let educator = Keypair::new();
+        let (educator_pda, _) = register_educator(ctx, educator.pubkey()).await;
+
+        let other_educator = Keypair::new();
+        let _ = register_educator(ctx, other_educator.pubkey()).await;
+
+        let student = Keypair::new();
+        let _ = register_student(ctx, student.pubkey()).await;
+
+        let (course_pda, _) = create_course(ctx, educator.pubkey(), COURSE_ID).await;
+
+        let operator = Keypair::new();
+        let (operator_pda, _) = Pubkey::find_program_address(
+            &[b"educator-operator", educator_pda.as_ref(), operator.pubkey().as_ref()],
+            sollearning::ID
+        );
+
+        let grant_ix = sollearning::instruction::grant_operator(
+            educator.pubkey(),
+            educator_pda,
+            operator.pubkey(),
+            OPERATOR_PERMISSION_CREATE_COURSE,
+            0,
+            i64::MAX
+        );
+
+        let governance_signer = Keypair::new();
+        let multisig_ix = sollearning::instruction::create_multisig(
+            ctx.authority.pubkey(),
+            vec![governance_signer.pubkey(), ctx.authority.pubkey()],
+            1
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[grant_ix, multisig_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &educator, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Actor setup should succeed");
+
+        Actors {
+            educator,
+            educator_pda,
+            other_educator,
+            operator,
+            operator_pda,
+            governance_signer,
+            outsider: Keypair::new(),
+            student,
+            course_pda,
+        }
+    }
+
+    async fn assert_rejected(ctx: &mut TestContext, ix: Instruction, signer: &Keypair, case: &str) {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, signer],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "{} must be rejected", case);
+    }
+
+    #[tokio::test]
+    async fn register_educator_requires_admin() {
+        let mut ctx = setup_test().await;
+        let actors = setup_actors(&mut ctx).await;
+
+        let candidate = Keypair::new();
+        let (candidate_pda, _) = Pubkey::find_program_address(
+            &[b"educator", candidate.pubkey().as_ref()],
+            sollearning::ID
+        );
+
+        for (signer, role) in [
+            (&actors.educator, "Educator"),
+            (&actors.operator, "Operator"),
+            (&actors.governance_signer, "Governance"),
+            (&actors.outsider, "Outsider"),
+        ] {
+            let ix = sollearning::instruction::register_educator(
+                signer.pubkey(),
+                candidate.pubkey(),
+                candidate_pda,
+                1_000_000
+            );
+
+            assert_rejected(&mut ctx, ix, signer, &format!("register_educator by {}", role)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn set_educator_status_requires_admin() {
+        let mut ctx = setup_test().await;
+        let actors = setup_actors(&mut ctx).await;
+
+        for (signer, role) in [
+            (&actors.educator, "Educator"),
+            (&actors.operator, "Operator"),
+            (&actors.governance_signer, "Governance"),
+            (&actors.outsider, "Outsider"),
+        ] {
+            let ix = sollearning::instruction::set_educator_status(
+                signer.pubkey(),
+                actors.educator.pubkey(),
+                true,
+                Some(u64::MAX / 2)
+            );
+
+            assert_rejected(&mut ctx, ix, signer, &format!("set_educator_status by {}", role)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn update_course_requires_course_educator() {
+        let mut ctx = setup_test().await;
+        let actors = setup_actors(&mut ctx).await;
+
+        // The operator only holds the create permission
+        for (signer, operator, role) in [
+            (&actors.other_educator, None, "Other educator"),
+            (&actors.operator, Some(actors.operator_pda), "Operator without permission"),
+            (&ctx.authority, None, "Admin"),
+            (&actors.governance_signer, None, "Governance"),
+            (&actors.outsider, None, "Outsider"),
+        ] {
+            let ix = sollearning::instruction::update_course(
+                signer.pubkey(),
+                actors.educator_pda,
+                operator,
+                COURSE_ID.to_string(),
+                Some("Hijacked".to_string()),
+                None,
+                None,
+                None,
+                "Unauthorized update".to_string()
+            );
+
+            assert_rejected(&mut ctx, ix, signer, &format!("update_course by {}", role)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn mint_to_student_requires_course_educator() {
+        let mut ctx = setup_test().await;
+        let actors = setup_actors(&mut ctx).await;
+
+        for (signer, operator, role) in [
+            (&actors.other_educator, None, "Other educator"),
+            (&actors.operator, Some(actors.operator_pda), "Operator without permission"),
+            (&ctx.authority, None, "Admin"),
+            (&actors.governance_signer, None, "Governance"),
+            (&actors.student, None, "Student"),
+        ] {
+            let ix = sollearning::instruction::mint_to_student(
+                signer.pubkey(),
+                actors.educator_pda,
+                operator,
+                actors.student.pubkey(),
+                actors.course_pda,
+                COURSE_ID.to_string(),
+                100
+            );
+
+            assert_rejected(&mut ctx, ix, signer, &format!("mint_to_student by {}", role)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn inactive_educator_cannot_update_or_mint() {
+        let mut ctx = setup_test().await;
+        let actors = setup_actors(&mut ctx).await;
+
+        let deactivate_ix = sollearning::instruction::set_educator_status(
+            ctx.authority.pubkey(),
+            actors.educator.pubkey(),
+            false,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[deactivate_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Educator deactivation should succeed");
+
+        let update_ix = sollearning::instruction::update_course(
+            actors.educator.pubkey(),
+            actors.educator_pda,
+            None,
+            COURSE_ID.to_string(),
+            Some("Updated".to_string()),
+            None,
+            None,
+            None,
+            "Update while inactive".to_string()
+        );
+
+        assert_rejected(&mut ctx, update_ix, &actors.educator, "update_course by inactive educator").await;
+
+        let mint_ix = sollearning::instruction::mint_to_student(
+            actors.educator.pubkey(),
+            actors.educator_pda,
+            None,
+            actors.student.pubkey(),
+            actors.course_pda,
+            COURSE_ID.to_string(),
+            100
+        );
+
+        assert_rejected(&mut ctx, mint_ix, &actors.educator, "mint_to_student by inactive educator").await;
+    }
+
+    #[tokio::test]
+    async fn pause_requires_pauser() {
+        let mut ctx = setup_test().await;
+        let actors = setup_actors(&mut ctx).await;
+
+        for (signer, role) in [
+            (&actors.educator, "Educator"),
+            (&actors.operator, "Operator"),
+            (&actors.governance_signer, "Governance"),
+            (&actors.outsider, "Outsider"),
+        ] {
+            let ix = sollearning::instruction::emergency_toggle(
+                signer.pubkey(),
+                true,
+                Some(PauseReason {
+                    code: PAUSE_REASON_SECURITY_INCIDENT,
+                    text_hash: [1u8; 32],
+                    resume_after: None,
+                })
+            );
+
+            assert_rejected(&mut ctx, ix, signer, &format!("emergency_toggle by {}", role)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn create_proposal_requires_governance() {
+        let mut ctx = setup_test().await;
+        let actors = setup_actors(&mut ctx).await;
+
+        for (signer, role) in [
+            (&actors.educator, "Educator"),
+            (&actors.operator, "Operator"),
+            (&actors.outsider, "Outsider"),
+        ] {
+            let ix = sollearning::instruction::create_proposal(
+                signer.pubkey(),
+                ProposalInstruction::ChangeAuthority {
+                    new_authority: signer.pubkey()
+                },
+                "Unauthorized proposal".to_string(),
+                None
+            );
+
+            assert_rejected(&mut ctx, ix, signer, &format!("create_proposal by {}", role)).await;
+        }
+    }
+}
@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod authority_transfer_tests {
+    use super::*;
+    use crate::common::setup_test;
+    use sollearning::{
+        state::{ProgramConfig, PendingAuthorityTransfer},
+        error::SolLearningError
+    };
+
+    fn pending_transfer_pda() -> Pubkey {
+        Pubkey::find_program_address(&[b"authority-transfer"], sollearning::ID).0
+    }
+
+    async fn propose_transfer(ctx: &mut TestContext, new_authority: Pubkey) {
+        let ix = sollearning::instruction::propose_authority_transfer(
+            ctx.authority.pubkey(),
+            pending_transfer_pda(),
+            new_authority
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Authority transfer proposal should succeed");
+    }
+
+    #[tokio::test]
+    async fn accepted_transfer_updates_state_and_config() {
+        let mut ctx = setup_test().await;
+
+        let new_authority = Keypair::new();
+        propose_transfer(&mut ctx, new_authority.pubkey()).await;
+
+        let pending_account = ctx.banks_client
+            .get_account(pending_transfer_pda())
+            .await
+            .expect("Pending transfer must exist");
+
+        let pending_data = PendingAuthorityTransfer::try_deserialize(
+            &mut pending_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(pending_data.new_authority, new_authority.pubkey());
+
+        let ix = sollearning::instruction::accept_authority_transfer(
+            new_authority.pubkey(),
+            pending_transfer_pda(),
+            ctx.authority.pubkey()
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &new_authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Authority transfer acceptance should succeed");
+
+        let program_state_pda = Pubkey::find_program_address(&[b"program-state"], sollearning::ID).0;
+        let program_state_account = ctx.banks_client
+            .get_account(program_state_pda)
+            .await
+            .expect("Program state account must exist");
+
+        let program_state = ProgramState::try_deserialize(
+            &mut program_state_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        let config_pda = Pubkey::find_program_address(&[b"program-config"], sollearning::ID).0;
+        let config_account = ctx.banks_client
+            .get_account(config_pda)
+            .await
+            .expect("Program config account must exist");
+
+        let config = ProgramConfig::try_deserialize(
+            &mut config_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(program_state.authority, new_authority.pubkey());
+        assert_eq!(config.authority, new_authority.pubkey());
+    }
+
+    #[tokio::test]
+    async fn transfer_cannot_be_accepted_by_other_key() {
+        let mut ctx = setup_test().await;
+
+        let new_authority = Keypair::new();
+        propose_transfer(&mut ctx, new_authority.pubkey()).await;
+
+        let attacker = Keypair::new();
+        let ix = sollearning::instruction::accept_authority_transfer(
+            attacker.pubkey(),
+            pending_transfer_pda(),
+            ctx.authority.pubkey()
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &attacker],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Only the proposed key can accept the transfer");
+    }
+
+    #[tokio::test]
+    async fn unauthorized_transfer_proposal() {
+        let mut ctx = setup_test().await;
+
+        let attacker = Keypair::new();
+        let ix = sollearning::instruction::propose_authority_transfer(
+            attacker.pubkey(),
+            pending_transfer_pda(),
+            attacker.pubkey()
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &attacker],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Only the current authority can propose a transfer");
+    }
+
+    #[tokio::test]
+    async fn proposed_authority_declines_transfer() {
+        let mut ctx = setup_test().await;
+
+        let new_authority = Keypair::new();
+        propose_transfer(&mut ctx, new_authority.pubkey()).await;
+
+        let ix = sollearning::instruction::cancel_authority_transfer(
+            new_authority.pubkey(),
+            pending_transfer_pda(),
+            ctx.authority.pubkey()
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &new_authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Proposed authority should be able to decline");
+
+        let pending_account = ctx.banks_client
+            .get_account(pending_transfer_pda())
+            .await
+            .expect("Lookup must succeed");
+
+        assert!(pending_account.is_none(), "Pending transfer must be closed");
+    }
+}
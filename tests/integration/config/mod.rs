@@ -0,0 +1,3 @@
+mod authority_transfer;
+
+pub use authority_transfer::*;
@@ -0,0 +1,254 @@
+#[cfg(test)]
+mod course_co_educator_tests {
+    use super::*;
+    use crate::common::{
+        setup_test,
+        register_educator,
+        register_student,
+        create_course
+    };
+    use sollearning::{
+        state::{CoEducatorShare, CourseCompletion, DisputeKind, EducatorAccount},
+        constants::{BLOCKLIST_SEED, DISPUTE_SEED}
+    };
+
+    const COURSE_ID: &str = "COTAUGHT101";
+
+    fn educator_pda(educator: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"educator", educator.as_ref()], sollearning::ID).0
+    }
+
+    // Lead weight 2 and two co-educators of weight 1 each
+    async fn setup_co_taught_course(ctx: &mut TestContext, lead: &Keypair, co_educators: &[Keypair]) -> Pubkey {
+        let _ = register_educator(ctx, lead.pubkey()).await;
+        for co_educator in co_educators {
+            let _ = register_educator(ctx, co_educator.pubkey()).await;
+        }
+        let (course_pda, _) = create_course(ctx, lead.pubkey(), COURSE_ID).await;
+
+        let ix = sollearning::instruction::create_course_co_educators(
+            lead.pubkey(),
+            course_pda,
+            2,
+            co_educators
+                .iter()
+                .map(|co_educator| CoEducatorShare { educator: educator_pda(co_educator.pubkey()), weight: 1 })
+                .collect(),
+            10
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, lead],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Co-educator setup should succeed");
+
+        course_pda
+    }
+
+    fn co_educator_entry(co_educator: Pubkey) -> Vec<AccountMeta> {
+        let (blocklist_pda, _) = Pubkey::find_program_address(&[BLOCKLIST_SEED, co_educator.as_ref()], &sollearning::ID);
+        vec![
+            AccountMeta::new(educator_pda(co_educator), false),
+            AccountMeta::new(get_associated_token_address(&co_educator, &token_mint_pubkey()), false),
+            AccountMeta::new_readonly(blocklist_pda, false),
+        ]
+    }
+
+    async fn rename_course(ctx: &mut TestContext, lead: Pubkey, signers: &[&Keypair]) -> Result<(), BanksClientError> {
+        // The first co-educator signs as educator_authority and pays for the history
+        let mut ix = sollearning::instruction::update_course(
+            signers[0].pubkey(),
+            educator_pda(lead),
+            None,
+            COURSE_ID.to_string(),
+            Some("Co-taught Blockchain".to_string()),
+            None,
+            None,
+            None,
+            "Renamed by co-educators".to_string()
+        );
+        for signer in signers {
+            ix.accounts.push(AccountMeta::new_readonly(educator_pda(signer.pubkey()), false));
+            ix.accounts.push(AccountMeta::new_readonly(signer.pubkey(), true));
+        }
+
+        let mut keys: Vec<&Keypair> = vec![&ctx.payer];
+        keys.extend_from_slice(signers);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &keys,
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    async fn mint_co_taught(ctx: &mut TestContext, lead: &Keypair, co_educators: &[Keypair], course_pda: Pubkey, student: Pubkey) {
+        let mut ix = sollearning::instruction::mint_to_student(
+            lead.pubkey(),
+            student,
+            course_pda,
+            COURSE_ID.to_string(),
+            100
+        );
+        for co_educator in co_educators {
+            ix.accounts.extend(co_educator_entry(co_educator.pubkey()));
+        }
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, lead],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Co-taught minting should succeed");
+    }
+
+    async fn total_minted(ctx: &mut TestContext, educator: Pubkey) -> u64 {
+        let educator_account = ctx.banks_client
+            .get_account(educator_pda(educator))
+            .await
+            .expect("Educator account must exist");
+
+        EducatorAccount::try_deserialize(&mut educator_account.data.as_slice())
+            .expect("Must deserialize")
+            .total_minted
+    }
+
+    #[tokio::test]
+    async fn mint_splits_accounting_by_weight() {
+        let mut ctx = setup_test().await;
+        let lead = Keypair::new();
+        let co_educators = [Keypair::new(), Keypair::new()];
+        let course_pda = setup_co_taught_course(&mut ctx, &lead, &co_educators).await;
+
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+        mint_co_taught(&mut ctx, &lead, &co_educators, course_pda, student.pubkey()).await;
+
+        let expected = [(lead.pubkey(), 50), (co_educators[0].pubkey(), 25), (co_educators[1].pubkey(), 25)];
+        for (educator, expected_total) in expected {
+            assert_eq!(total_minted(&mut ctx, educator).await, expected_total);
+        }
+    }
+
+    #[tokio::test]
+    async fn co_taught_mint_requires_co_educator_accounts() {
+        let mut ctx = setup_test().await;
+        let lead = Keypair::new();
+        let co_educators = [Keypair::new(), Keypair::new()];
+        let course_pda = setup_co_taught_course(&mut ctx, &lead, &co_educators).await;
+
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+
+        let ix = sollearning::instruction::mint_to_student(
+            lead.pubkey(),
+            student.pubkey(),
+            course_pda,
+            COURSE_ID.to_string(),
+            100
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &lead],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Co-educator shares must not be skipped");
+    }
+
+    #[tokio::test]
+    async fn co_educator_majority_can_update_course() {
+        let mut ctx = setup_test().await;
+        let lead = Keypair::new();
+        let co_educators = [Keypair::new(), Keypair::new()];
+        setup_co_taught_course(&mut ctx, &lead, &co_educators).await;
+
+        let result = rename_course(&mut ctx, lead.pubkey(), &[&co_educators[0]]).await;
+        assert!(result.is_err(), "One of two co-educators is not a majority");
+
+        rename_course(&mut ctx, lead.pubkey(), &[&co_educators[0], &co_educators[1]]).await
+            .expect("A co-educator majority should be able to update the course");
+    }
+
+    #[tokio::test]
+    async fn revocation_reverses_recorded_credits() {
+        let mut ctx = setup_test().await;
+        let lead = Keypair::new();
+        let co_educators = [Keypair::new(), Keypair::new()];
+        let course_pda = setup_co_taught_course(&mut ctx, &lead, &co_educators).await;
+
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+        mint_co_taught(&mut ctx, &lead, &co_educators, course_pda, student.pubkey()).await;
+
+        let (course_completion, _) = Pubkey::find_program_address(
+            &[b"course-completion", student.pubkey().as_ref(), COURSE_ID.as_bytes()],
+            &sollearning::ID
+        );
+        let completion_account = ctx.banks_client
+            .get_account(course_completion)
+            .await
+            .expect("Course completion must exist");
+        let completion = CourseCompletion::try_deserialize(&mut completion_account.data.as_slice())
+            .expect("Must deserialize");
+        assert_eq!(completion.lead_share, 50);
+        assert_eq!(completion.co_educator_credits[0].share, 25);
+        assert_eq!(completion.co_educator_credits[1].reward, 10);
+
+        // Uphold a fraud dispute so the completion can be revoked right away
+        let reviewer = Keypair::new();
+        let whistleblower = Keypair::new();
+        let config_ix = sollearning::instruction::set_dispute_config(ctx.authority.pubkey(), reviewer.pubkey(), 1);
+        let open_ix = sollearning::instruction::open_dispute(
+            whistleblower.pubkey(),
+            course_pda,
+            student.pubkey(),
+            DisputeKind::FraudulentCompletion,
+            [5u8; 32]
+        );
+        let (dispute, _) = Pubkey::find_program_address(
+            &[DISPUTE_SEED, course_pda.as_ref(), student.pubkey().as_ref(), whistleblower.pubkey().as_ref()],
+            &sollearning::ID
+        );
+        let review_ix = sollearning::instruction::review_dispute(reviewer.pubkey(), dispute, true);
+
+        let mut revoke_ix = sollearning::instruction::revoke_completion(
+            whistleblower.pubkey(),
+            course_completion,
+            Some(dispute),
+            None
+        );
+        for co_educator in &co_educators {
+            revoke_ix.accounts.extend(co_educator_entry(co_educator.pubkey()).into_iter().take(2));
+        }
+
+        let tx = Transaction::new_signed_with_payer(
+            &[config_ix, open_ix, review_ix, revoke_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority, &whistleblower, &reviewer],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Upheld fraud dispute should allow revocation");
+
+        assert_eq!(total_minted(&mut ctx, lead.pubkey()).await, 0);
+        for co_educator in &co_educators {
+            assert_eq!(total_minted(&mut ctx, co_educator.pubkey()).await, 0);
+        }
+    }
+}
@@ -1,5 +1,13 @@
 mod creation;
 mod management;
+mod revocation;
+mod verifiers;
+mod co_educators;
+mod ratings;
 
 pub use creation::*;
-pub use management::*;
\ No newline at end of file
+pub use management::*;
+pub use revocation::*;
+pub use verifiers::*;
+pub use co_educators::*;
+pub use ratings::*;
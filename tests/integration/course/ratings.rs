@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod course_rating_tests {
+    use super::*;
+    use crate::common::{
+        setup_test,
+        register_educator,
+        register_student,
+        create_course,
+        mint_tokens_to_student
+    };
+    use sollearning::state::{Course, CourseRating};
+
+    const COURSE_ID: &str = "RATED101";
+
+    // Creates a course and, when `complete` is set, mints the student a completion
+    async fn setup_rated_course(ctx: &mut TestContext, student: &Keypair, complete: bool) -> Pubkey {
+        let educator = Keypair::new();
+        let _ = register_educator(ctx, educator.pubkey()).await;
+        let _ = register_student(ctx, student.pubkey()).await;
+        let (course_pda, _) = create_course(ctx, educator.pubkey(), COURSE_ID).await;
+
+        if complete {
+            mint_tokens_to_student(
+                ctx,
+                educator.pubkey(),
+                student.pubkey(),
+                course_pda,
+                COURSE_ID,
+                100
+            ).await;
+        }
+
+        course_pda
+    }
+
+    async fn rate(ctx: &mut TestContext, student: &Keypair, course: Pubkey, rating: u8) -> Result<(), BanksClientError> {
+        let ix = sollearning::instruction::rate_course(
+            student.pubkey(),
+            course,
+            rating,
+            [rating; 32]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, student],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    async fn fetch_course(ctx: &mut TestContext, course: Pubkey) -> Course {
+        let account = ctx.banks_client
+            .get_account(course)
+            .await
+            .expect("Course account must exist");
+
+        Course::try_deserialize(&mut account.data.as_slice()).expect("Must deserialize")
+    }
+
+    #[tokio::test]
+    async fn completed_student_rates_course() {
+        let mut ctx = setup_test().await;
+        let student = Keypair::new();
+        let course = setup_rated_course(&mut ctx, &student, true).await;
+
+        rate(&mut ctx, &student, course, 4).await
+            .expect("Rating should succeed");
+
+        let course_account = fetch_course(&mut ctx, course).await;
+        assert_eq!(course_account.rating_count, 1);
+        assert_eq!(course_account.rating_sum, 4);
+
+        let rating_pda = Pubkey::find_program_address(
+            &[b"course-rating", course.as_ref(), student.pubkey().as_ref()],
+            sollearning::ID
+        ).0;
+        let rating_account = ctx.banks_client
+            .get_account(rating_pda)
+            .await
+            .expect("Rating account must exist");
+        let rating = CourseRating::try_deserialize(&mut rating_account.data.as_slice())
+            .expect("Must deserialize");
+
+        assert_eq!(rating.rating, 4);
+        assert_eq!(rating.review_hash, [4u8; 32]);
+    }
+
+    #[tokio::test]
+    async fn rating_out_of_range_fails() {
+        let mut ctx = setup_test().await;
+        let student = Keypair::new();
+        let course = setup_rated_course(&mut ctx, &student, true).await;
+
+        assert!(rate(&mut ctx, &student, course, 0).await.is_err(), "Rating of 0 should fail");
+        assert!(rate(&mut ctx, &student, course, 6).await.is_err(), "Rating of 6 should fail");
+    }
+
+    #[tokio::test]
+    async fn rating_without_completion_fails() {
+        let mut ctx = setup_test().await;
+        let student = Keypair::new();
+        let course = setup_rated_course(&mut ctx, &student, false).await;
+
+        let result = rate(&mut ctx, &student, course, 5).await;
+
+        assert!(result.is_err(), "Only students who completed the course can rate it");
+    }
+
+    #[tokio::test]
+    async fn rating_edit_updates_course_sum() {
+        let mut ctx = setup_test().await;
+        let student = Keypair::new();
+        let course = setup_rated_course(&mut ctx, &student, true).await;
+
+        rate(&mut ctx, &student, course, 2).await
+            .expect("Rating should succeed");
+
+        let ix = sollearning::instruction::update_course_rating(
+            student.pubkey(),
+            course,
+            5,
+            [9u8; 32]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &student],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Rating edit within the window should succeed");
+
+        let course_account = fetch_course(&mut ctx, course).await;
+        assert_eq!(course_account.rating_count, 1);
+        assert_eq!(course_account.rating_sum, 5);
+    }
+}
@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod completion_revocation_tests {
+    use super::*;
+    use crate::common::{
+        setup_test,
+        register_educator,
+        register_student,
+        create_course,
+        mint_tokens_to_student,
+        create_root_multisig
+    };
+    use sollearning::state::CourseCompletion;
+
+    const COURSE_ID: &str = "BLOCKCHAIN101";
+
+    // Mints a completion worth 100 tokens and returns its PDA
+    async fn award_completion(ctx: &mut TestContext, student: &Keypair) -> Pubkey {
+        let educator = Keypair::new();
+        let _ = register_educator(ctx, educator.pubkey()).await;
+        let _ = register_student(ctx, student.pubkey()).await;
+        let (course_pda, _) = create_course(ctx, educator.pubkey(), COURSE_ID).await;
+
+        mint_tokens_to_student(
+            ctx,
+            educator.pubkey(),
+            student.pubkey(),
+            course_pda,
+            COURSE_ID,
+            100
+        ).await
+    }
+
+    fn multisig_pda() -> Pubkey {
+        Pubkey::find_program_address(&[b"multisig"], sollearning::ID).0
+    }
+
+    async fn request_revocation(ctx: &mut TestContext, signer: &Keypair, course_completion: Pubkey) {
+        let ix = sollearning::instruction::request_completion_revocation(
+            signer.pubkey(),
+            multisig_pda(),
+            course_completion,
+            [3u8; 32]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, signer],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Revocation request should succeed");
+    }
+
+    async fn revoke(ctx: &mut TestContext, signer: &Keypair, course_completion: Pubkey) -> Result<(), BanksClientError> {
+        let ix = sollearning::instruction::revoke_completion(
+            signer.pubkey(),
+            course_completion,
+            None,
+            Some(multisig_pda())
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, signer],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    #[tokio::test]
+    async fn revocation_request_opens_dispute_window() {
+        let mut ctx = setup_test().await;
+        let student = Keypair::new();
+        let course_completion = award_completion(&mut ctx, &student).await;
+        let signer = create_root_multisig(&mut ctx).await;
+
+        request_revocation(&mut ctx, &signer, course_completion).await;
+
+        let completion_account = ctx.banks_client
+            .get_account(course_completion)
+            .await
+            .expect("Course completion must exist");
+
+        let completion = CourseCompletion::try_deserialize(
+            &mut completion_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert!(completion.revocation_requested_at.is_some());
+        assert_eq!(completion.revocation_reason_hash, [3u8; 32]);
+        assert!(completion.revoked_at.is_none());
+
+        let result = revoke(&mut ctx, &signer, course_completion).await;
+
+        assert!(result.is_err(), "Revocation must wait for the dispute window to close");
+    }
+
+    #[tokio::test]
+    async fn revoke_without_request_fails() {
+        let mut ctx = setup_test().await;
+        let student = Keypair::new();
+        let course_completion = award_completion(&mut ctx, &student).await;
+        let signer = create_root_multisig(&mut ctx).await;
+
+        let result = revoke(&mut ctx, &signer, course_completion).await;
+
+        assert!(result.is_err(), "Revocation requires a prior request");
+    }
+
+    #[tokio::test]
+    async fn revocation_request_requires_governance() {
+        let mut ctx = setup_test().await;
+        let student = Keypair::new();
+        let course_completion = award_completion(&mut ctx, &student).await;
+        let _ = create_root_multisig(&mut ctx).await;
+
+        let ix = sollearning::instruction::request_completion_revocation(
+            student.pubkey(),
+            multisig_pda(),
+            course_completion,
+            [3u8; 32]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &student],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Only governance can request a revocation");
+    }
+}
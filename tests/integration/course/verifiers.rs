@@ -0,0 +1,248 @@
+#[cfg(test)]
+mod course_verifier_tests {
+    use super::*;
+    use crate::common::{
+        setup_test,
+        register_educator,
+        register_student,
+        create_course
+    };
+    use sollearning::{
+        state::{CourseVerifiers, CompletionVerification},
+        constants::{COURSE_COMPLETION_SEED, COURSE_VERIFIERS_SEED, COMPLETION_VERIFICATION_SEED}
+    };
+
+    const COURSE_ID: &str = "PEER101";
+
+    // Registers an educator, student and course guarded by 2 of 3 verifiers
+    async fn setup_verified_course(ctx: &mut TestContext, educator: &Keypair, student: &Keypair, verifiers: &[Keypair]) -> Pubkey {
+        let _ = register_educator(ctx, educator.pubkey()).await;
+        let _ = register_student(ctx, student.pubkey()).await;
+        let (course_pda, _) = create_course(ctx, educator.pubkey(), COURSE_ID).await;
+
+        let ix = sollearning::instruction::create_course_verifiers(
+            educator.pubkey(),
+            course_pda,
+            verifiers.iter().map(|verifier| verifier.pubkey()).collect(),
+            2
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, educator],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Verifier set creation should succeed");
+
+        course_pda
+    }
+
+    async fn verify(ctx: &mut TestContext, verifier: &Keypair, course_pda: Pubkey, student: Pubkey) -> Result<(), BanksClientError> {
+        let ix = sollearning::instruction::verify_completion(
+            verifier.pubkey(),
+            course_pda,
+            student
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, verifier],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    async fn update_verifiers(
+        ctx: &mut TestContext,
+        educator: &Keypair,
+        course_pda: Pubkey,
+        verifiers: Vec<Pubkey>,
+        threshold: u8,
+        co_signers: &[&Keypair]
+    ) -> Result<(), BanksClientError> {
+        let mut ix = sollearning::instruction::update_course_verifiers(
+            educator.pubkey(),
+            course_pda,
+            verifiers,
+            threshold
+        );
+        for co_signer in co_signers {
+            ix.accounts.push(AccountMeta::new_readonly(co_signer.pubkey(), true));
+        }
+
+        let mut keys: Vec<&Keypair> = vec![&ctx.payer, educator];
+        keys.extend_from_slice(co_signers);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &keys,
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    #[tokio::test]
+    async fn reward_minted_on_threshold_verification() {
+        let mut ctx = setup_test().await;
+        let educator = Keypair::new();
+        let student = Keypair::new();
+        let verifiers = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let course_pda = setup_verified_course(&mut ctx, &educator, &student, &verifiers).await;
+
+        let verifiers_pda = Pubkey::find_program_address(
+            &[COURSE_VERIFIERS_SEED, course_pda.as_ref()],
+            sollearning::ID
+        ).0;
+        let verification_pda = Pubkey::find_program_address(
+            &[COMPLETION_VERIFICATION_SEED, course_pda.as_ref(), student.pubkey().as_ref()],
+            sollearning::ID
+        ).0;
+        let completion_pda = Pubkey::find_program_address(
+            &[COURSE_COMPLETION_SEED, student.pubkey().as_ref(), COURSE_ID.as_bytes()],
+            sollearning::ID
+        ).0;
+
+        let verifiers_account = ctx.banks_client
+            .get_account(verifiers_pda)
+            .await
+            .expect("Course verifiers must exist");
+        let course_verifiers = CourseVerifiers::try_deserialize(
+            &mut verifiers_account.data.as_slice()
+        ).expect("Must deserialize");
+        assert_eq!(course_verifiers.threshold, 2);
+        assert_eq!(course_verifiers.verifiers.len(), 3);
+
+        verify(&mut ctx, &verifiers[0], course_pda, student.pubkey()).await
+            .expect("First verification should succeed");
+
+        let completion = ctx.banks_client
+            .get_account(completion_pda)
+            .await
+            .expect("Account lookup should succeed");
+        assert!(completion.is_none(), "One verification must not mint the reward");
+
+        verify(&mut ctx, &verifiers[2], course_pda, student.pubkey()).await
+            .expect("Second verification should succeed");
+
+        let verification_account = ctx.banks_client
+            .get_account(verification_pda)
+            .await
+            .expect("Verification record must exist");
+        let verification = CompletionVerification::try_deserialize(
+            &mut verification_account.data.as_slice()
+        ).expect("Must deserialize");
+        assert_eq!(verification.verified_by, vec![verifiers[0].pubkey(), verifiers[2].pubkey()]);
+        assert!(verification.completed_at.is_some());
+
+        let completion = ctx.banks_client
+            .get_account(completion_pda)
+            .await
+            .expect("Account lookup should succeed");
+        assert!(completion.is_some(), "The threshold verification must mint the reward");
+    }
+
+    #[tokio::test]
+    async fn verifier_cannot_sign_twice() {
+        let mut ctx = setup_test().await;
+        let educator = Keypair::new();
+        let student = Keypair::new();
+        let verifiers = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let course_pda = setup_verified_course(&mut ctx, &educator, &student, &verifiers).await;
+
+        verify(&mut ctx, &verifiers[1], course_pda, student.pubkey()).await
+            .expect("First verification should succeed");
+
+        let result = verify(&mut ctx, &verifiers[1], course_pda, student.pubkey()).await;
+
+        assert!(result.is_err(), "A verifier must not count twice");
+    }
+
+    #[tokio::test]
+    async fn unlisted_verifier_is_rejected() {
+        let mut ctx = setup_test().await;
+        let educator = Keypair::new();
+        let student = Keypair::new();
+        let verifiers = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let course_pda = setup_verified_course(&mut ctx, &educator, &student, &verifiers).await;
+
+        let outsider = Keypair::new();
+        let result = verify(&mut ctx, &outsider, course_pda, student.pubkey()).await;
+
+        assert!(result.is_err(), "Only listed verifiers can sign a completion");
+    }
+
+    #[tokio::test]
+    async fn educator_cannot_mint_verified_course_directly() {
+        let mut ctx = setup_test().await;
+        let educator = Keypair::new();
+        let student = Keypair::new();
+        let verifiers = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let course_pda = setup_verified_course(&mut ctx, &educator, &student, &verifiers).await;
+
+        let mint_ix = sollearning::instruction::mint_to_student(
+            educator.pubkey(),
+            student.pubkey(),
+            course_pda,
+            COURSE_ID.to_string(),
+            100
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[mint_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &educator],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Courses with verifiers must be minted through verify_completion");
+    }
+
+    #[tokio::test]
+    async fn educator_cannot_drop_verifiers_alone() {
+        let mut ctx = setup_test().await;
+        let educator = Keypair::new();
+        let student = Keypair::new();
+        let verifiers = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let course_pda = setup_verified_course(&mut ctx, &educator, &student, &verifiers).await;
+
+        let result = update_verifiers(&mut ctx, &educator, course_pda, vec![], 0, &[]).await;
+        assert!(result.is_err(), "Lowering the threshold needs the current verifiers");
+
+        let result = update_verifiers(&mut ctx, &educator, course_pda, vec![], 0, &[&verifiers[0]]).await;
+        assert!(result.is_err(), "One verifier is below the current threshold");
+
+        update_verifiers(&mut ctx, &educator, course_pda, vec![], 0, &[&verifiers[0], &verifiers[2]]).await
+            .expect("A quorum of current verifiers can lower the threshold");
+    }
+
+    #[tokio::test]
+    async fn verified_awards_respect_mint_cooldown() {
+        let mut ctx = setup_test().await;
+        let educator = Keypair::new();
+        let student = Keypair::new();
+        let verifiers = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let course_pda = setup_verified_course(&mut ctx, &educator, &student, &verifiers).await;
+
+        let second_student = Keypair::new();
+        let _ = register_student(&mut ctx, second_student.pubkey()).await;
+
+        verify(&mut ctx, &verifiers[0], course_pda, student.pubkey()).await
+            .expect("First verification should succeed");
+        verify(&mut ctx, &verifiers[1], course_pda, student.pubkey()).await
+            .expect("Threshold verification should mint the reward");
+
+        verify(&mut ctx, &verifiers[0], course_pda, second_student.pubkey()).await
+            .expect("First verification should succeed");
+        let result = verify(&mut ctx, &verifiers[1], course_pda, second_student.pubkey()).await;
+
+        assert!(result.is_err(), "A second award within the educator's mint cooldown must fail");
+    }
+}
@@ -0,0 +1,3 @@
+mod workflow;
+
+pub use workflow::*;
\ No newline at end of file
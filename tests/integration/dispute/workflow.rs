@@ -0,0 +1,368 @@
+#[cfg(test)]
+mod dispute_workflow_tests {
+    use super::*;
+    use crate::common::{
+        setup_test,
+        register_educator,
+        register_student,
+        create_course,
+        mint_tokens_to_student
+    };
+    use sollearning::{
+        state::{Dispute, DisputeKind, DisputeStatus, CourseCompletion, StudentInfo},
+        constants::DISPUTE_SEED
+    };
+
+    const COURSE_ID: &str = "BLOCKCHAIN101";
+
+    fn dispute_pda(course: Pubkey, student: Pubkey, opener: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[DISPUTE_SEED, course.as_ref(), student.as_ref(), opener.as_ref()],
+            &sollearning::ID
+        ).0
+    }
+
+    async fn setup_course(ctx: &mut TestContext, student: &Keypair) -> Pubkey {
+        let educator = Keypair::new();
+        let _ = register_educator(ctx, educator.pubkey()).await;
+        let _ = register_student(ctx, student.pubkey()).await;
+        let (course_pda, _) = create_course(ctx, educator.pubkey(), COURSE_ID).await;
+        course_pda
+    }
+
+    async fn set_reviewer(ctx: &mut TestContext, reviewer: Pubkey) {
+        let ix = sollearning::instruction::set_dispute_config(
+            ctx.authority.pubkey(),
+            reviewer,
+            1
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Dispute config update should succeed");
+    }
+
+    async fn open(
+        ctx: &mut TestContext,
+        opener: &Keypair,
+        course: Pubkey,
+        student: Pubkey,
+        kind: DisputeKind
+    ) -> Result<(), BanksClientError> {
+        let ix = sollearning::instruction::open_dispute(
+            opener.pubkey(),
+            course,
+            student,
+            kind,
+            [5u8; 32]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, opener],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    async fn review(
+        ctx: &mut TestContext,
+        reviewer: &Keypair,
+        dispute: Pubkey,
+        uphold: bool
+    ) -> Result<(), BanksClientError> {
+        let ix = sollearning::instruction::review_dispute(
+            reviewer.pubkey(),
+            dispute,
+            uphold
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, reviewer],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    async fn close(
+        ctx: &mut TestContext,
+        opener: &Keypair,
+        dispute: Pubkey,
+        course: Pubkey
+    ) -> Result<(), BanksClientError> {
+        let ix = sollearning::instruction::close_dispute(
+            opener.pubkey(),
+            dispute,
+            course
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, opener],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    async fn load_dispute(ctx: &mut TestContext, dispute: Pubkey) -> Dispute {
+        let account = ctx.banks_client
+            .get_account(dispute)
+            .await
+            .expect("Dispute must exist");
+
+        Dispute::try_deserialize(&mut account.data.as_slice())
+            .expect("Must deserialize")
+    }
+
+    #[tokio::test]
+    async fn student_disputes_missing_completion_and_is_minted() {
+        let mut ctx = setup_test().await;
+        let student = Keypair::new();
+        let course = setup_course(&mut ctx, &student).await;
+        let reviewer = Keypair::new();
+        set_reviewer(&mut ctx, reviewer.pubkey()).await;
+
+        open(&mut ctx, &student, course, student.pubkey(), DisputeKind::MissingCompletion).await
+            .expect("Student should be able to dispute a missing completion");
+
+        let dispute = dispute_pda(course, student.pubkey(), student.pubkey());
+        review(&mut ctx, &reviewer, dispute, true).await
+            .expect("Reviewer should be able to uphold");
+
+        assert_eq!(load_dispute(&mut ctx, dispute).await.status, DisputeStatus::Upheld);
+
+        let caller = Keypair::new();
+        let settle_ix = sollearning::instruction::settle_dispute_mint(
+            caller.pubkey(),
+            dispute
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[settle_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &caller],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Upheld dispute should settle with a mint");
+
+        let (student_pda, _) = Pubkey::find_program_address(
+            &[b"student", student.pubkey().as_ref()],
+            sollearning::ID
+        );
+        let student_account = ctx.banks_client
+            .get_account(student_pda)
+            .await
+            .expect("Student account must exist");
+        let student_data = StudentInfo::try_deserialize(&mut student_account.data.as_slice())
+            .expect("Must deserialize");
+
+        assert_eq!(student_data.total_earned, 100);
+        assert_eq!(load_dispute(&mut ctx, dispute).await.status, DisputeStatus::Settled);
+    }
+
+    #[tokio::test]
+    async fn third_party_cannot_dispute_missing_completion() {
+        let mut ctx = setup_test().await;
+        let student = Keypair::new();
+        let course = setup_course(&mut ctx, &student).await;
+
+        let outsider = Keypair::new();
+        let result = open(&mut ctx, &outsider, course, student.pubkey(), DisputeKind::MissingCompletion).await;
+
+        assert!(result.is_err(), "Only the student can dispute a missing completion");
+    }
+
+    #[tokio::test]
+    async fn upheld_fraud_dispute_revokes_completion() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+        let (course, _) = create_course(&mut ctx, educator.pubkey(), COURSE_ID).await;
+        let course_completion = mint_tokens_to_student(
+            &mut ctx,
+            educator.pubkey(),
+            student.pubkey(),
+            course,
+            COURSE_ID,
+            100
+        ).await;
+
+        let reviewer = Keypair::new();
+        set_reviewer(&mut ctx, reviewer.pubkey()).await;
+
+        let whistleblower = Keypair::new();
+        open(&mut ctx, &whistleblower, course, student.pubkey(), DisputeKind::FraudulentCompletion).await
+            .expect("Anyone can dispute an awarded completion");
+
+        let dispute = dispute_pda(course, student.pubkey(), whistleblower.pubkey());
+        review(&mut ctx, &reviewer, dispute, true).await
+            .expect("Reviewer should be able to uphold");
+
+        let revoke_ix = sollearning::instruction::revoke_completion(
+            whistleblower.pubkey(),
+            course_completion,
+            Some(dispute),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[revoke_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &whistleblower],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Upheld fraud dispute should allow revocation");
+
+        let completion_account = ctx.banks_client
+            .get_account(course_completion)
+            .await
+            .expect("Course completion must exist");
+        let completion = CourseCompletion::try_deserialize(&mut completion_account.data.as_slice())
+            .expect("Must deserialize");
+
+        assert!(completion.revoked_at.is_some());
+        assert_eq!(load_dispute(&mut ctx, dispute).await.status, DisputeStatus::Settled);
+    }
+
+    #[tokio::test]
+    async fn non_reviewer_cannot_review() {
+        let mut ctx = setup_test().await;
+        let student = Keypair::new();
+        let course = setup_course(&mut ctx, &student).await;
+
+        open(&mut ctx, &student, course, student.pubkey(), DisputeKind::MissingCompletion).await
+            .expect("Dispute should open");
+
+        let outsider = Keypair::new();
+        let result = review(&mut ctx, &outsider, dispute_pda(course, student.pubkey(), student.pubkey()), true).await;
+
+        assert!(result.is_err(), "Only reviewers can review disputes");
+    }
+
+    #[tokio::test]
+    async fn disputes_from_different_openers_do_not_collide() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+        let (course, _) = create_course(&mut ctx, educator.pubkey(), COURSE_ID).await;
+        let _ = mint_tokens_to_student(
+            &mut ctx,
+            educator.pubkey(),
+            student.pubkey(),
+            course,
+            COURSE_ID,
+            100
+        ).await;
+
+        let griefer = Keypair::new();
+        open(&mut ctx, &griefer, course, student.pubkey(), DisputeKind::FraudulentCompletion).await
+            .expect("Anyone can dispute an awarded completion");
+
+        let whistleblower = Keypair::new();
+        open(&mut ctx, &whistleblower, course, student.pubkey(), DisputeKind::FraudulentCompletion).await
+            .expect("An earlier dispute must not block another opener");
+    }
+
+    #[tokio::test]
+    async fn dismissed_dispute_can_be_closed_and_reopened() {
+        let mut ctx = setup_test().await;
+        let student = Keypair::new();
+        let course = setup_course(&mut ctx, &student).await;
+        let reviewer = Keypair::new();
+        set_reviewer(&mut ctx, reviewer.pubkey()).await;
+
+        open(&mut ctx, &student, course, student.pubkey(), DisputeKind::MissingCompletion).await
+            .expect("Dispute should open");
+
+        let dispute = dispute_pda(course, student.pubkey(), student.pubkey());
+        review(&mut ctx, &reviewer, dispute, false).await
+            .expect("Reviewer should be able to dismiss");
+
+        close(&mut ctx, &student, dispute, course).await
+            .expect("Dismissed dispute should close");
+
+        open(&mut ctx, &student, course, student.pubkey(), DisputeKind::MissingCompletion).await
+            .expect("Student should be able to dispute again once the old dispute is closed");
+    }
+
+    #[tokio::test]
+    async fn course_educator_cannot_review() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+        let (course, _) = create_course(&mut ctx, educator.pubkey(), COURSE_ID).await;
+        set_reviewer(&mut ctx, educator.pubkey()).await;
+
+        open(&mut ctx, &student, course, student.pubkey(), DisputeKind::MissingCompletion).await
+            .expect("Dispute should open");
+
+        let dispute = dispute_pda(course, student.pubkey(), student.pubkey());
+        let result = review(&mut ctx, &educator, dispute, false).await;
+
+        assert!(result.is_err(), "The course's educator must not review its own dispute");
+    }
+
+    #[tokio::test]
+    async fn upheld_dispute_closes_once_settlement_is_moot() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+        let (course, _) = create_course(&mut ctx, educator.pubkey(), COURSE_ID).await;
+        let reviewer = Keypair::new();
+        set_reviewer(&mut ctx, reviewer.pubkey()).await;
+
+        open(&mut ctx, &student, course, student.pubkey(), DisputeKind::MissingCompletion).await
+            .expect("Dispute should open");
+
+        let dispute = dispute_pda(course, student.pubkey(), student.pubkey());
+        review(&mut ctx, &reviewer, dispute, true).await
+            .expect("Reviewer should be able to uphold");
+
+        let result = close(&mut ctx, &student, dispute, course).await;
+
+        assert!(result.is_err(), "An upheld dispute stays open while it can be settled");
+
+        // The educator awards the completion directly, so the dispute can no
+        // longer be settled by mint
+        let _ = mint_tokens_to_student(
+            &mut ctx,
+            educator.pubkey(),
+            student.pubkey(),
+            course,
+            COURSE_ID,
+            100
+        ).await;
+
+        close(&mut ctx, &student, dispute, course).await
+            .expect("Upheld dispute should close once its completion exists");
+    }
+}
@@ -0,0 +1,251 @@
+#[cfg(test)]
+mod educator_application_tests {
+    use super::*;
+    use crate::common::setup_test;
+    use sollearning::{
+        state::{EducatorApplication, ProposalInstruction},
+        error::SolLearningError
+    };
+
+    fn application_pda(applicant: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"educator-application", applicant.as_ref()],
+            sollearning::ID
+        ).0
+    }
+
+    fn educator_pda(applicant: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"educator", applicant.as_ref()],
+            sollearning::ID
+        ).0
+    }
+
+    async fn apply(ctx: &mut TestContext, applicant: &Keypair, requested_mint_limit: u64) -> Pubkey {
+        let ix = sollearning::instruction::apply_as_educator(
+            applicant.pubkey(),
+            application_pda(applicant.pubkey()),
+            [7; 32],
+            requested_mint_limit
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&applicant.pubkey()),
+            &[applicant],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Educator application should succeed");
+
+        application_pda(applicant.pubkey())
+    }
+
+    #[tokio::test]
+    async fn submit_educator_application() {
+        let mut ctx = setup_test().await;
+
+        let applicant = Keypair::new();
+        let application = apply(&mut ctx, &applicant, 1000).await;
+
+        let application_account = ctx.banks_client
+            .get_account(application)
+            .await
+            .expect("Application account must exist");
+
+        let application_data = EducatorApplication::try_deserialize(
+            &mut application_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(application_data.applicant, applicant.pubkey());
+        assert_eq!(application_data.requested_mint_limit, 1000);
+    }
+
+    #[tokio::test]
+    async fn authority_approves_application() {
+        let mut ctx = setup_test().await;
+
+        let applicant = Keypair::new();
+        let application = apply(&mut ctx, &applicant, 1000).await;
+
+        let ix = sollearning::instruction::approve_educator_application(
+            ctx.authority.pubkey(),
+            applicant.pubkey(),
+            application,
+            educator_pda(applicant.pubkey()),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Application approval should succeed");
+
+        let educator_account = ctx.banks_client
+            .get_account(educator_pda(applicant.pubkey()))
+            .await
+            .expect("Educator account must exist");
+
+        let educator_data = EducatorAccount::try_deserialize(
+            &mut educator_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(educator_data.mint_limit, 1000);
+        assert!(educator_data.is_active);
+
+        let application_account = ctx.banks_client
+            .get_account(application)
+            .await
+            .expect("Lookup must succeed");
+
+        assert!(application_account.is_none(), "Application must be closed after approval");
+    }
+
+    #[tokio::test]
+    async fn unauthorized_application_approval() {
+        let mut ctx = setup_test().await;
+
+        let applicant = Keypair::new();
+        let application = apply(&mut ctx, &applicant, 1000).await;
+
+        let ix = sollearning::instruction::approve_educator_application(
+            applicant.pubkey(),
+            applicant.pubkey(),
+            application,
+            educator_pda(applicant.pubkey()),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&applicant.pubkey()),
+            &[&applicant],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Applicants must not approve their own application");
+    }
+
+    #[tokio::test]
+    async fn authority_rejects_application() {
+        let mut ctx = setup_test().await;
+
+        let applicant = Keypair::new();
+        let application = apply(&mut ctx, &applicant, 1000).await;
+
+        let ix = sollearning::instruction::reject_educator_application(
+            ctx.authority.pubkey(),
+            applicant.pubkey(),
+            application
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Application rejection should succeed");
+
+        let educator_account = ctx.banks_client
+            .get_account(educator_pda(applicant.pubkey()))
+            .await
+            .expect("Lookup must succeed");
+
+        assert!(educator_account.is_none(), "Rejected applicant must not become an educator");
+    }
+
+    #[tokio::test]
+    async fn governance_approves_application() {
+        let mut ctx = setup_test().await;
+
+        let signers = vec![Keypair::new(), Keypair::new()];
+        let (multisig_pda, _) = Pubkey::find_program_address(&[b"multisig"], sollearning::ID);
+
+        let multisig_ix = sollearning::instruction::create_multisig(
+            ctx.authority.pubkey(),
+            signers.iter().map(|k| k.pubkey()).collect(),
+            1
+        );
+
+        let tx1 = Transaction::new_signed_with_payer(
+            &[multisig_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx1).await
+            .expect("Multisig creation should succeed");
+
+        let applicant = Keypair::new();
+        let application = apply(&mut ctx, &applicant, 1000).await;
+
+        let (proposal_pda, _) = Pubkey::find_program_address(
+            &[b"proposal", multisig_pda.as_ref(), &0_u64.to_le_bytes()],
+            sollearning::ID
+        );
+
+        let create_ix = sollearning::instruction::create_proposal(
+            signers[0].pubkey(),
+            ProposalInstruction::RegisterEducator {
+                educator: applicant.pubkey(),
+                mint_limit: 500
+            },
+            "Approve educator application".to_string(),
+            None
+        );
+
+        let approve_ix = sollearning::instruction::approve_proposal(
+            signers[0].pubkey(),
+            proposal_pda,
+            multisig_pda
+        );
+
+        let (applicant_blocklist, _) = Pubkey::find_program_address(
+            &[b"blocklist", applicant.pubkey().as_ref()],
+            sollearning::ID
+        );
+
+        // Application, educator PDA, applicant and the applicant's blocklist
+        // entry are passed as remaining accounts
+        let execute_ix = sollearning::instruction::execute_proposal(
+            signers[0].pubkey(),
+            proposal_pda,
+            multisig_pda,
+            vec![application, educator_pda(applicant.pubkey()), applicant.pubkey(), applicant_blocklist]
+        );
+
+        let tx2 = Transaction::new_signed_with_payer(
+            &[create_ix, approve_ix, execute_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &signers[0]],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx2).await
+            .expect("Governance approval should succeed");
+
+        let educator_account = ctx.banks_client
+            .get_account(educator_pda(applicant.pubkey()))
+            .await
+            .expect("Educator account must exist");
+
+        let educator_data = EducatorAccount::try_deserialize(
+            &mut educator_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(educator_data.mint_limit, 500);
+        assert_eq!(educator_data.authority, multisig_pda);
+    }
+}
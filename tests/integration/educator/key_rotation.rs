@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod educator_key_rotation_tests {
+    use super::*;
+    use crate::common::{setup_test, register_educator};
+    use sollearning::{
+        state::EducatorKeyRotation,
+        constants::EDUCATOR_KEY_ROTATION_DELAY,
+        error::SolLearningError
+    };
+
+    fn key_rotation_pda(educator_pda: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"educator-key-rotation", educator_pda.as_ref()],
+            sollearning::ID
+        ).0
+    }
+
+    async fn request_rotation(
+        ctx: &mut TestContext,
+        educator_pda: Pubkey,
+        new_educator: Pubkey
+    ) -> Pubkey {
+        let rotation_pda = key_rotation_pda(educator_pda);
+
+        let ix = sollearning::instruction::request_educator_key_rotation(
+            ctx.authority.pubkey(),
+            educator_pda,
+            rotation_pda,
+            new_educator,
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Key rotation request should succeed");
+
+        rotation_pda
+    }
+
+    #[tokio::test]
+    async fn successful_key_rotation_request() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let (educator_pda, _) = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let new_educator = Keypair::new();
+        let rotation_pda = request_rotation(&mut ctx, educator_pda, new_educator.pubkey()).await;
+
+        let rotation_account = ctx.banks_client
+            .get_account(rotation_pda)
+            .await
+            .expect("Rotation account must exist");
+
+        let rotation_data = EducatorKeyRotation::try_deserialize(
+            &mut rotation_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(rotation_data.new_educator_address, new_educator.pubkey());
+        assert_eq!(
+            rotation_data.executable_at - rotation_data.requested_at,
+            EDUCATOR_KEY_ROTATION_DELAY
+        );
+    }
+
+    #[tokio::test]
+    async fn unauthorized_key_rotation_request() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let (educator_pda, _) = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let attacker = Keypair::new();
+        let ix = sollearning::instruction::request_educator_key_rotation(
+            attacker.pubkey(),
+            educator_pda,
+            key_rotation_pda(educator_pda),
+            attacker.pubkey(),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &attacker],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Only the program authority can request a key rotation");
+    }
+
+    #[tokio::test]
+    async fn key_rotation_before_timelock() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let (educator_pda, _) = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let new_educator = Keypair::new();
+        let rotation_pda = request_rotation(&mut ctx, educator_pda, new_educator.pubkey()).await;
+
+        let ix = sollearning::instruction::rotate_educator_key(
+            new_educator.pubkey(),
+            educator_pda,
+            rotation_pda,
+            ctx.authority.pubkey()
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &new_educator],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Rotation must not execute before the timelock elapses");
+    }
+
+    #[tokio::test]
+    async fn educator_cancels_key_rotation() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let (educator_pda, _) = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let new_educator = Keypair::new();
+        let rotation_pda = request_rotation(&mut ctx, educator_pda, new_educator.pubkey()).await;
+
+        let ix = sollearning::instruction::cancel_educator_key_rotation(
+            educator.pubkey(),
+            educator_pda,
+            rotation_pda,
+            ctx.authority.pubkey()
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &educator],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Current educator key should be able to cancel the rotation");
+
+        let rotation_account = ctx.banks_client
+            .get_account(rotation_pda)
+            .await
+            .expect("Lookup must succeed");
+
+        assert!(rotation_account.is_none(), "Rotation request must be closed");
+    }
+}
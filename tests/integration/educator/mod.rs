@@ -1,5 +1,13 @@
 mod registration;
 mod operations;
+mod operators;
+mod key_rotation;
+mod applications;
+mod reputation;
 
 pub use registration::*;
-pub use operations::*;
\ No newline at end of file
+pub use operations::*;
+pub use operators::*;
+pub use key_rotation::*;
+pub use applications::*;
+pub use reputation::*;
@@ -0,0 +1,252 @@
+#[cfg(test)]
+mod educator_operator_tests {
+    use super::*;
+    use crate::common::{
+        setup_test,
+        register_educator,
+        register_student,
+        create_course
+    };
+    use sollearning::{
+        state::EducatorOperator,
+        constants::{
+            OPERATOR_PERMISSION_CREATE_COURSE,
+            OPERATOR_PERMISSION_VERIFY_COMPLETION
+        },
+        error::SolLearningError
+    };
+
+    async fn grant_operator(
+        ctx: &mut TestContext,
+        educator: &Keypair,
+        operator: Pubkey,
+        permissions: u8,
+        mint_allowance: u64,
+        expires_at: i64
+    ) -> Pubkey {
+        let (educator_pda, _) = Pubkey::find_program_address(
+            &[b"educator", educator.pubkey().as_ref()],
+            sollearning::ID
+        );
+
+        let (operator_pda, _) = Pubkey::find_program_address(
+            &[b"educator-operator", educator_pda.as_ref(), operator.as_ref()],
+            sollearning::ID
+        );
+
+        let ix = sollearning::instruction::grant_operator(
+            educator.pubkey(),
+            educator_pda,
+            operator,
+            permissions,
+            mint_allowance,
+            expires_at
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, educator],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Operator grant should succeed");
+
+        operator_pda
+    }
+
+    #[tokio::test]
+    async fn operator_creates_course() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let (educator_pda, _) = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let operator = Keypair::new();
+        let operator_pda = grant_operator(
+            &mut ctx,
+            &educator,
+            operator.pubkey(),
+            OPERATOR_PERMISSION_CREATE_COURSE,
+            0,
+            i64::MAX
+        ).await;
+
+        let ix = sollearning::instruction::create_course(
+            operator.pubkey(),
+            educator_pda,
+            Some(operator_pda),
+            "TA-COURSE".to_string(),
+            "Course created by assistant".to_string(),
+            100,
+            [0; 32]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &operator],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Operator with create permission should create a course");
+    }
+
+    #[tokio::test]
+    async fn operator_without_permission_cannot_mint() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let (educator_pda, _) = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let (course_pda, _) = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        let operator = Keypair::new();
+        let operator_pda = grant_operator(
+            &mut ctx,
+            &educator,
+            operator.pubkey(),
+            OPERATOR_PERMISSION_CREATE_COURSE,
+            0,
+            i64::MAX
+        ).await;
+
+        let ix = sollearning::instruction::mint_to_student(
+            operator.pubkey(),
+            educator_pda,
+            Some(operator_pda),
+            student.pubkey(),
+            course_pda,
+            course_id.to_string(),
+            100
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &operator],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Operator without verify permission must not mint");
+    }
+
+    #[tokio::test]
+    async fn operator_mint_allowance_is_enforced() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let (educator_pda, _) = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let (course_pda, _) = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        let operator = Keypair::new();
+        let operator_pda = grant_operator(
+            &mut ctx,
+            &educator,
+            operator.pubkey(),
+            OPERATOR_PERMISSION_VERIFY_COMPLETION,
+            50,
+            i64::MAX
+        ).await;
+
+        let ix = sollearning::instruction::mint_to_student(
+            operator.pubkey(),
+            educator_pda,
+            Some(operator_pda),
+            student.pubkey(),
+            course_pda,
+            course_id.to_string(),
+            100
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &operator],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Minting beyond the operator allowance must fail");
+
+        let operator_account = ctx.banks_client
+            .get_account(operator_pda)
+            .await
+            .expect("Operator account must exist");
+
+        let operator_data = EducatorOperator::try_deserialize(
+            &mut operator_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(operator_data.total_minted, 0);
+    }
+
+    #[tokio::test]
+    async fn revoked_operator_cannot_create_course() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let (educator_pda, _) = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let operator = Keypair::new();
+        let operator_pda = grant_operator(
+            &mut ctx,
+            &educator,
+            operator.pubkey(),
+            OPERATOR_PERMISSION_CREATE_COURSE,
+            0,
+            i64::MAX
+        ).await;
+
+        let revoke_ix = sollearning::instruction::revoke_operator(
+            educator.pubkey(),
+            educator_pda,
+            operator_pda
+        );
+
+        let tx1 = Transaction::new_signed_with_payer(
+            &[revoke_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &educator],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx1).await
+            .expect("Operator revocation should succeed");
+
+        let ix = sollearning::instruction::create_course(
+            operator.pubkey(),
+            educator_pda,
+            Some(operator_pda),
+            "TA-COURSE".to_string(),
+            "Course created by assistant".to_string(),
+            100,
+            [0; 32]
+        );
+
+        let tx2 = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &operator],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx2).await;
+
+        assert!(result.is_err(), "Revoked operator must not create courses");
+    }
+}
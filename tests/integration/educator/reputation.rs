@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod educator_reputation_tests {
+    use super::*;
+    use crate::common::{
+        setup_test,
+        register_educator,
+        register_student,
+        create_course,
+        mint_tokens_to_student,
+        create_root_multisig,
+        execute_governance_instruction
+    };
+    use sollearning::{
+        state::{EducatorAccount, EducatorReputation, ReputationTier, ProposalInstruction},
+        constants::EDUCATOR_REPUTATION_SEED
+    };
+
+    const COURSE_ID: &str = "BLOCKCHAIN101";
+
+    fn reputation_pda(educator_pda: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[EDUCATOR_REPUTATION_SEED, educator_pda.as_ref()],
+            sollearning::ID
+        ).0
+    }
+
+    async fn set_reputation_tiers(ctx: &mut TestContext, tiers: Vec<ReputationTier>) -> Result<(), BanksClientError> {
+        let signer = create_root_multisig(ctx).await;
+
+        execute_governance_instruction(
+            ctx,
+            &signer,
+            0,
+            ProposalInstruction::SetReputationTiers { tiers },
+            vec![]
+        ).await
+    }
+
+    // Registers an educator and mints one completion; returns the educator PDA
+    async fn educator_with_completion(ctx: &mut TestContext) -> Pubkey {
+        let educator = Keypair::new();
+        let student = Keypair::new();
+        let (educator_pda, _) = register_educator(ctx, educator.pubkey()).await;
+        let _ = register_student(ctx, student.pubkey()).await;
+        let (course_pda, _) = create_course(ctx, educator.pubkey(), COURSE_ID).await;
+
+        mint_tokens_to_student(ctx, educator.pubkey(), student.pubkey(), course_pda, COURSE_ID, 100).await;
+
+        educator_pda
+    }
+
+    #[tokio::test]
+    async fn mint_records_completion_and_unique_student() {
+        let mut ctx = setup_test().await;
+        let educator_pda = educator_with_completion(&mut ctx).await;
+
+        let reputation_account = ctx.banks_client
+            .get_account(reputation_pda(educator_pda))
+            .await
+            .expect("Reputation account must exist");
+
+        let reputation = EducatorReputation::try_deserialize(
+            &mut reputation_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(reputation.educator, educator_pda);
+        assert_eq!(reputation.completions, 1);
+        assert_eq!(reputation.unique_students, 1);
+        assert_eq!(reputation.disputes_lost, 0);
+    }
+
+    #[tokio::test]
+    async fn reputation_tier_sets_mint_limit() {
+        let mut ctx = setup_test().await;
+        set_reputation_tiers(&mut ctx, vec![
+            ReputationTier { mint_limit: 500, ..Default::default() },
+            ReputationTier { min_completions: 1, min_unique_students: 1, mint_limit: 2_000, ..Default::default() },
+            ReputationTier { min_completions: 50, min_unique_students: 25, mint_limit: 10_000, ..Default::default() },
+        ]).await.expect("Reputation tier setup should succeed");
+
+        let educator_pda = educator_with_completion(&mut ctx).await;
+
+        let educator_account = ctx.banks_client
+            .get_account(educator_pda)
+            .await
+            .expect("Educator account must exist");
+
+        let educator = EducatorAccount::try_deserialize(
+            &mut educator_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(educator.mint_limit, 2_000);
+    }
+
+    #[tokio::test]
+    async fn reputation_tiers_must_increase() {
+        let mut ctx = setup_test().await;
+
+        let result = set_reputation_tiers(&mut ctx, vec![
+            ReputationTier { mint_limit: 2_000, ..Default::default() },
+            ReputationTier { min_completions: 10, mint_limit: 500, ..Default::default() },
+        ]).await;
+
+        assert!(result.is_err(), "Tiers must be ordered by increasing mint limit");
+    }
+
+    #[tokio::test]
+    async fn refresh_requires_configured_tiers() {
+        let mut ctx = setup_test().await;
+        let educator_pda = educator_with_completion(&mut ctx).await;
+
+        let ix = sollearning::instruction::refresh_educator_tier(
+            ctx.payer.pubkey(),
+            educator_pda
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Refreshing without tiers should fail");
+    }
+}
@@ -1,3 +1,7 @@
 mod pause_scenarios;
+mod pause_reasons;
+mod pause_coverage;
 
-pub use pause_scenarios::*;
\ No newline at end of file
+pub use pause_scenarios::*;
+pub use pause_reasons::*;
+pub use pause_coverage::*;
\ No newline at end of file
@@ -0,0 +1,258 @@
+#[cfg(test)]
+mod pause_coverage_tests {
+    use super::*;
+    use crate::common::{setup_test, create_root_multisig, execute_governance_instruction};
+    use sollearning::{
+        state::{PauseReason, ProposalInstruction},
+        constants::{
+            GOVERNANCE_SCOPE_CONFIG,
+            PAUSE_FLAG_GOVERNANCE,
+            PAUSE_FLAG_STUDENT_REGISTER,
+            PAUSE_REASON_SECURITY_INCIDENT
+        }
+    };
+
+    async fn toggle_flags(ctx: &mut TestContext, flags: u32) {
+        let ix = sollearning::instruction::emergency_toggle_granular(
+            ctx.authority.pubkey(),
+            flags,
+            true
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Granular pause should succeed");
+    }
+
+    async fn pause_program(ctx: &mut TestContext) {
+        let ix = sollearning::instruction::emergency_toggle(
+            ctx.authority.pubkey(),
+            true,
+            Some(PauseReason {
+                code: PAUSE_REASON_SECURITY_INCIDENT,
+                text_hash: [1u8; 32],
+                resume_after: None,
+            })
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Program pause should succeed");
+    }
+
+    async fn create_single_signer_multisig(ctx: &mut TestContext) {
+        let ix = sollearning::instruction::create_multisig(
+            ctx.authority.pubkey(),
+            vec![ctx.authority.pubkey()],
+            1
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Multisig creation should succeed");
+    }
+
+    async fn propose(ctx: &mut TestContext, instruction: ProposalInstruction) -> Result<(), BanksClientError> {
+        let ix = sollearning::instruction::create_proposal(
+            ctx.authority.pubkey(),
+            instruction,
+            "Proposal while paused".to_string(),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    async fn register(ctx: &mut TestContext, student: &Keypair) -> Result<(), BanksClientError> {
+        let (student_pda, _) = Pubkey::find_program_address(
+            &[b"student", student.pubkey().as_ref()],
+            sollearning::ID
+        );
+
+        let ix = sollearning::instruction::register_student(
+            student.pubkey(),
+            student_pda
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, student],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    #[tokio::test]
+    async fn full_pause_blocks_student_registration() {
+        let mut ctx = setup_test().await;
+        pause_program(&mut ctx).await;
+
+        let result = register(&mut ctx, &Keypair::new()).await;
+
+        assert!(result.is_err(), "Student registration should fail while paused");
+    }
+
+    #[tokio::test]
+    async fn student_registration_flag_blocks_registration() {
+        let mut ctx = setup_test().await;
+        toggle_flags(&mut ctx, PAUSE_FLAG_STUDENT_REGISTER).await;
+
+        let result = register(&mut ctx, &Keypair::new()).await;
+
+        assert!(result.is_err(), "Student registration should fail while its flag is set");
+    }
+
+    #[tokio::test]
+    async fn governance_flag_blocks_regular_proposals() {
+        let mut ctx = setup_test().await;
+        create_single_signer_multisig(&mut ctx).await;
+        toggle_flags(&mut ctx, PAUSE_FLAG_GOVERNANCE).await;
+
+        let result = propose(&mut ctx, ProposalInstruction::RegisterEducator {
+            educator: Keypair::new().pubkey(),
+            mint_limit: 1_000,
+        }).await;
+
+        assert!(result.is_err(), "Regular proposals should fail while governance is paused");
+    }
+
+    #[tokio::test]
+    async fn recovery_proposals_allowed_while_paused() {
+        let mut ctx = setup_test().await;
+        create_single_signer_multisig(&mut ctx).await;
+        pause_program(&mut ctx).await;
+
+        propose(&mut ctx, ProposalInstruction::ChangeAuthority {
+            new_authority: Keypair::new().pubkey(),
+        }).await.expect("Recovery proposals should stay available while paused");
+    }
+
+    #[tokio::test]
+    async fn governance_flag_blocks_committee_creation() {
+        let mut ctx = setup_test().await;
+        let root_signer = create_root_multisig(&mut ctx).await;
+        toggle_flags(&mut ctx, PAUSE_FLAG_GOVERNANCE).await;
+
+        let (committee_pda, _) = Pubkey::find_program_address(&[b"multisig", b"treasury"], sollearning::ID);
+
+        let result = execute_governance_instruction(
+            &mut ctx,
+            &root_signer,
+            0,
+            ProposalInstruction::CreateCommittee {
+                label: "treasury".to_string(),
+                signers: vec![Keypair::new().pubkey()],
+                threshold: 1,
+                scope: GOVERNANCE_SCOPE_CONFIG
+            },
+            vec![committee_pda]
+        ).await;
+
+        assert!(result.is_err(), "Committees cannot be created while governance is paused");
+    }
+
+    #[tokio::test]
+    async fn governance_flag_blocks_community_proposals() {
+        let mut ctx = setup_test().await;
+
+        let setup_ixs = vec![
+            sollearning::instruction::set_voting_config(
+                ctx.authority.pubkey(),
+                1_000,
+                5_000,
+                86400,
+                GOVERNANCE_SCOPE_CONFIG
+            ),
+            sollearning::instruction::create_voter_stake(ctx.authority.pubkey()),
+            sollearning::instruction::deposit_vote_stake(
+                ctx.authority.pubkey(),
+                get_associated_token_address(&ctx.authority.pubkey(), &token_mint_pubkey()),
+                1_000
+            ),
+        ];
+
+        let tx1 = Transaction::new_signed_with_payer(
+            &setup_ixs,
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx1).await
+            .expect("Voting setup should succeed");
+
+        toggle_flags(&mut ctx, PAUSE_FLAG_GOVERNANCE).await;
+
+        let ix = sollearning::instruction::create_community_proposal(
+            ctx.authority.pubkey(),
+            ProposalInstruction::SetEducatorReward {
+                educator_reward_bps: 500,
+                educator_reward_budget: 1_000
+            },
+            "Reward educators".to_string()
+        );
+
+        let tx2 = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx2).await;
+
+        assert!(result.is_err(), "Community proposals should fail while governance is paused");
+    }
+
+    #[tokio::test]
+    async fn governance_flag_blocks_config_setters() {
+        let mut ctx = setup_test().await;
+        toggle_flags(&mut ctx, PAUSE_FLAG_GOVERNANCE).await;
+
+        let ix = sollearning::instruction::set_voting_config(
+            ctx.authority.pubkey(),
+            1_000,
+            5_000,
+            86400,
+            GOVERNANCE_SCOPE_CONFIG
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Governance settings cannot change while governance is paused");
+    }
+}
@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod pause_reason_tests {
+    use super::*;
+    use crate::common::{setup_test, create_root_multisig, execute_governance_instruction};
+    use sollearning::{
+        state::{PauseReason, PauseRecord, ProposalInstruction},
+        constants::{PAUSE_REASON_MAINTENANCE, PAUSE_RECORD_SEED}
+    };
+
+    const RESUME_AFTER: i64 = 4_000_000_000;
+    const ONE_DAY: i64 = 86400;
+
+    fn pause_record_pda() -> Pubkey {
+        Pubkey::find_program_address(&[PAUSE_RECORD_SEED], &sollearning::ID).0
+    }
+
+    async fn get_pause_record(ctx: &mut TestContext) -> PauseRecord {
+        let pause_record_account = ctx.banks_client
+            .get_account(pause_record_pda())
+            .await
+            .expect("Pause record must exist");
+
+        PauseRecord::try_deserialize(&mut pause_record_account.data.as_slice())
+            .expect("Must deserialize")
+    }
+
+    async fn pause_program(ctx: &mut TestContext, reason: Option<PauseReason>) -> Result<(), BanksClientError> {
+        let pause_ix = sollearning::instruction::emergency_toggle(
+            ctx.authority.pubkey(),
+            true,
+            reason
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[pause_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    #[tokio::test]
+    async fn pause_stores_reason_record() {
+        let mut ctx = setup_test().await;
+
+        let reason = PauseReason {
+            code: PAUSE_REASON_MAINTENANCE,
+            text_hash: [7u8; 32],
+            resume_after: None,
+        };
+        pause_program(&mut ctx, Some(reason)).await
+            .expect("Pause with a reason should succeed");
+
+        let (pause_record_pda, _) = Pubkey::find_program_address(
+            &[PAUSE_RECORD_SEED],
+            &sollearning::ID
+        );
+
+        let pause_record_account = ctx.banks_client
+            .get_account(pause_record_pda)
+            .await
+            .expect("Pause record must exist");
+
+        let pause_record = PauseRecord::try_deserialize(
+            &mut pause_record_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(pause_record.reason_code, PAUSE_REASON_MAINTENANCE);
+        assert_eq!(pause_record.reason_hash, [7u8; 32]);
+        assert_eq!(pause_record.paused_by, ctx.authority.pubkey());
+        assert_eq!(pause_record.resume_after, None);
+        assert_eq!(pause_record.resumed_at, None);
+    }
+
+    #[tokio::test]
+    async fn pause_without_reason_fails() {
+        let mut ctx = setup_test().await;
+
+        let result = pause_program(&mut ctx, None).await;
+
+        assert!(result.is_err(), "Pausing without a reason should fail");
+    }
+
+    #[tokio::test]
+    async fn resume_if_due_rejects_indefinite_pause() {
+        let mut ctx = setup_test().await;
+
+        pause_program(&mut ctx, Some(PauseReason {
+            code: PAUSE_REASON_MAINTENANCE,
+            text_hash: [7u8; 32],
+            resume_after: None,
+        })).await.expect("Pause should succeed");
+
+        let caller = Keypair::new();
+        let resume_ix = sollearning::instruction::resume_if_due(caller.pubkey());
+
+        let tx = Transaction::new_signed_with_payer(
+            &[resume_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &caller],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "A pause without a resume time is never due");
+    }
+
+    #[tokio::test]
+    async fn repause_keeps_later_resume_time() {
+        let mut ctx = setup_test().await;
+
+        pause_program(&mut ctx, Some(PauseReason {
+            code: PAUSE_REASON_MAINTENANCE,
+            text_hash: [7u8; 32],
+            resume_after: Some(RESUME_AFTER + ONE_DAY),
+        })).await.expect("Pause should succeed");
+
+        pause_program(&mut ctx, Some(PauseReason {
+            code: PAUSE_REASON_MAINTENANCE,
+            text_hash: [8u8; 32],
+            resume_after: Some(RESUME_AFTER),
+        })).await.expect("Repeated pause should succeed");
+
+        let pause_record = get_pause_record(&mut ctx).await;
+
+        assert_eq!(pause_record.reason_hash, [8u8; 32]);
+        assert_eq!(pause_record.resume_after, Some(RESUME_AFTER + ONE_DAY));
+    }
+
+    #[tokio::test]
+    async fn governance_extends_pause() {
+        let mut ctx = setup_test().await;
+        let signer = create_root_multisig(&mut ctx).await;
+
+        pause_program(&mut ctx, Some(PauseReason {
+            code: PAUSE_REASON_MAINTENANCE,
+            text_hash: [7u8; 32],
+            resume_after: Some(RESUME_AFTER),
+        })).await.expect("Pause should succeed");
+
+        execute_governance_instruction(
+            &mut ctx,
+            &signer,
+            0,
+            ProposalInstruction::ExtendPause { resume_after: Some(RESUME_AFTER + ONE_DAY) },
+            vec![pause_record_pda()]
+        ).await.expect("Governance should extend the pause");
+
+        let pause_record = get_pause_record(&mut ctx).await;
+
+        assert_eq!(pause_record.resume_after, Some(RESUME_AFTER + ONE_DAY));
+
+        let result = execute_governance_instruction(
+            &mut ctx,
+            &signer,
+            1,
+            ProposalInstruction::ExtendPause { resume_after: Some(RESUME_AFTER) },
+            vec![pause_record_pda()]
+        ).await;
+
+        assert!(result.is_err(), "An extension cannot bring the resume time forward");
+    }
+}
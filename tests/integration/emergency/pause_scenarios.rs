@@ -3,11 +3,13 @@ mod emergency_pause_tests {
     use super::*;
     use crate::common::setup_test;
     use sollearning::{
+        state::PauseReason,
         error::SolLearningError,
         constants::{
             PAUSE_FLAG_MINT, 
             PAUSE_FLAG_TRANSFER, 
-            PAUSE_FLAG_ALL
+            PAUSE_FLAG_ALL,
+            PAUSE_REASON_SECURITY_INCIDENT
         }
     };
 
@@ -17,7 +19,12 @@ mod emergency_pause_tests {
 
         let pause_ix = sollearning::instruction::emergency_toggle(
             ctx.authority.pubkey(),
-            true // Pause
+            true, // Pause
+            Some(PauseReason {
+                code: PAUSE_REASON_SECURITY_INCIDENT,
+                text_hash: [1u8; 32],
+                resume_after: None,
+            })
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -87,6 +94,61 @@ mod emergency_pause_tests {
         assert_eq!(program_state.pause_flags, PAUSE_FLAG_MINT | PAUSE_FLAG_TRANSFER);
     }
 
+    #[tokio::test]
+    async fn resume_keeps_granular_flags() {
+        let mut ctx = setup_test().await;
+
+        let ixs = vec![
+            sollearning::instruction::emergency_toggle_granular(
+                ctx.authority.pubkey(),
+                PAUSE_FLAG_MINT,
+                true
+            ),
+            sollearning::instruction::emergency_toggle(
+                ctx.authority.pubkey(),
+                true,
+                Some(PauseReason {
+                    code: PAUSE_REASON_SECURITY_INCIDENT,
+                    text_hash: [1u8; 32],
+                    resume_after: None,
+                })
+            ),
+            sollearning::instruction::emergency_toggle(
+                ctx.authority.pubkey(),
+                false,
+                None
+            ),
+        ];
+
+        let tx = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Pause and resume should succeed");
+
+        let program_state_pda = Pubkey::find_program_address(
+            &[b"program-state"],
+            sollearning::ID
+        ).0;
+
+        let program_state_account = ctx.banks_client
+            .get_account(program_state_pda)
+            .await
+            .expect("Program state account must exist");
+
+        let program_state = ProgramState::try_deserialize(
+            &mut program_state_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        // Resuming only clears the flags the pause itself set
+        assert!(!program_state.paused);
+        assert_eq!(program_state.pause_flags, PAUSE_FLAG_MINT);
+    }
+
     #[tokio::test]
     async fn emergency_pause_by_unauthorized_authority() {
         let mut ctx = setup_test().await;
@@ -95,7 +157,12 @@ mod emergency_pause_tests {
 
         let pause_ix = sollearning::instruction::emergency_toggle(
             unauthorized_authority.pubkey(),
-            true // Pause
+            true, // Pause
+            Some(PauseReason {
+                code: PAUSE_REASON_SECURITY_INCIDENT,
+                text_hash: [1u8; 32],
+                resume_after: None,
+            })
         );
 
         let tx = Transaction::new_signed_with_payer(
@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod multisig_committee_tests {
+    use super::*;
+    use crate::common::{setup_test, create_root_multisig, execute_governance_instruction};
+    use sollearning::{
+        state::{Multisig, ProposalInstruction},
+        constants::{GOVERNANCE_SCOPE_EDUCATORS, GOVERNANCE_SCOPE_MEMBERSHIP, GOVERNANCE_SCOPE_TREASURY},
+        error::SolLearningError
+    };
+
+    fn committee_pda(label: &str) -> Pubkey {
+        Pubkey::find_program_address(&[b"multisig", label.as_bytes()], sollearning::ID).0
+    }
+
+    // Committees are created by root multisig proposals, one per committee
+    async fn create_committee(
+        ctx: &mut TestContext,
+        root_signer: &Keypair,
+        proposal_index: u64,
+        label: &str,
+        member: &Keypair,
+        scope: u32
+    ) -> Pubkey {
+        execute_governance_instruction(
+            ctx,
+            root_signer,
+            proposal_index,
+            ProposalInstruction::CreateCommittee {
+                label: label.to_string(),
+                signers: vec![member.pubkey()],
+                threshold: 1,
+                scope
+            },
+            vec![committee_pda(label)]
+        ).await.expect("Committee creation should succeed");
+
+        committee_pda(label)
+    }
+
+    fn committee_proposal(
+        member: &Keypair,
+        committee: Pubkey,
+        instruction: ProposalInstruction
+    ) -> Instruction {
+        let (proposal_pda, _) = Pubkey::find_program_address(
+            &[b"proposal", committee.as_ref(), &0_u64.to_le_bytes()],
+            sollearning::ID
+        );
+
+        sollearning::instruction::create_proposal(
+            member.pubkey(),
+            committee,
+            proposal_pda,
+            instruction,
+            "Committee proposal".to_string(),
+            None
+        )
+    }
+
+    #[tokio::test]
+    async fn independent_committees_coexist() {
+        let mut ctx = setup_test().await;
+
+        let root_signer = create_root_multisig(&mut ctx).await;
+        let curriculum_member = Keypair::new();
+        let finance_member = Keypair::new();
+
+        let curriculum = create_committee(
+            &mut ctx, &root_signer, 0, "curriculum", &curriculum_member, GOVERNANCE_SCOPE_EDUCATORS
+        ).await;
+        let finance = create_committee(
+            &mut ctx, &root_signer, 1, "finance", &finance_member, GOVERNANCE_SCOPE_TREASURY
+        ).await;
+
+        assert_ne!(curriculum, finance);
+
+        let committee_account = ctx.banks_client
+            .get_account(curriculum)
+            .await
+            .expect("Committee account must exist");
+
+        let committee_data = Multisig::try_deserialize(
+            &mut committee_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(committee_data.label, "curriculum");
+        assert_eq!(committee_data.scope, GOVERNANCE_SCOPE_EDUCATORS);
+    }
+
+    #[tokio::test]
+    async fn committee_proposes_within_scope() {
+        let mut ctx = setup_test().await;
+
+        let root_signer = create_root_multisig(&mut ctx).await;
+        let member = Keypair::new();
+        let curriculum = create_committee(
+            &mut ctx, &root_signer, 0, "curriculum", &member, GOVERNANCE_SCOPE_EDUCATORS
+        ).await;
+
+        let ix = committee_proposal(&member, curriculum, ProposalInstruction::RegisterEducator {
+            educator: Keypair::new().pubkey(),
+            mint_limit: 1000
+        });
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &member],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("In-scope proposal should succeed");
+    }
+
+    #[tokio::test]
+    async fn committee_proposal_outside_scope_rejected() {
+        let mut ctx = setup_test().await;
+
+        let root_signer = create_root_multisig(&mut ctx).await;
+        let member = Keypair::new();
+        let curriculum = create_committee(
+            &mut ctx, &root_signer, 0, "curriculum", &member, GOVERNANCE_SCOPE_EDUCATORS
+        ).await;
+
+        let ix = committee_proposal(&member, curriculum, ProposalInstruction::ChangeAuthority {
+            new_authority: member.pubkey()
+        });
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &member],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Curriculum committee must not change the program authority");
+    }
+
+    #[tokio::test]
+    async fn committee_cannot_create_committees() {
+        let mut ctx = setup_test().await;
+
+        let root_signer = create_root_multisig(&mut ctx).await;
+        let member = Keypair::new();
+        let membership = create_committee(
+            &mut ctx, &root_signer, 0, "membership", &member, GOVERNANCE_SCOPE_MEMBERSHIP
+        ).await;
+
+        let ix = committee_proposal(&member, membership, ProposalInstruction::CreateCommittee {
+            label: "finance".to_string(),
+            signers: vec![member.pubkey()],
+            threshold: 1,
+            scope: GOVERNANCE_SCOPE_TREASURY
+        });
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &member],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Only full-scope governance can create committees");
+    }
+}
@@ -127,4 +127,28 @@ mod multisig_creation_tests {
             );
         }
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn root_multisig_creation_by_non_admin() {
+        let mut ctx = setup_test().await;
+
+        let outsider = Keypair::new();
+
+        let ix = sollearning::instruction::create_multisig(
+            outsider.pubkey(),
+            vec![outsider.pubkey()],
+            1
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &outsider],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Only the admin can create the root multisig");
+    }
+}
@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod multisig_membership_tests {
+    use super::*;
+    use crate::common::setup_test;
+    use sollearning::{
+        state::{Multisig, ProposalInstruction},
+        instructions::multisig::create_multisig_struct::calculate_multisig_space,
+        error::SolLearningError
+    };
+
+    fn multisig_pda() -> Pubkey {
+        Pubkey::find_program_address(&[b"multisig"], sollearning::ID).0
+    }
+
+    fn proposal_pda(index: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"proposal", multisig_pda().as_ref(), &index.to_le_bytes()],
+            sollearning::ID
+        ).0
+    }
+
+    async fn create_multisig(ctx: &mut TestContext, signers: Vec<Pubkey>, threshold: u8) {
+        let ix = sollearning::instruction::create_multisig(
+            ctx.authority.pubkey(),
+            signers,
+            threshold
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Multisig creation should succeed");
+    }
+
+    // The authority proposes (which counts as its approval) and executes
+    async fn propose(ctx: &mut TestContext, instruction: ProposalInstruction) {
+        let ix = sollearning::instruction::create_proposal(
+            ctx.authority.pubkey(),
+            instruction,
+            "Membership change".to_string(),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Proposal creation should succeed");
+    }
+
+    async fn execute(ctx: &mut TestContext, index: u64) -> Result<(), BanksClientError> {
+        let ix = sollearning::instruction::execute_proposal(
+            ctx.authority.pubkey(),
+            proposal_pda(index),
+            multisig_pda(),
+            vec![]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    #[tokio::test]
+    async fn add_signer_resizes_multisig() {
+        let mut ctx = setup_test().await;
+        let new_signer = Keypair::new().pubkey();
+
+        create_multisig(&mut ctx, vec![ctx.authority.pubkey()], 1).await;
+        propose(&mut ctx, ProposalInstruction::AddSigner { signer: new_signer }).await;
+        execute(&mut ctx, 0).await
+            .expect("AddSigner execution should succeed");
+
+        let multisig_account = ctx.banks_client
+            .get_account(multisig_pda())
+            .await
+            .expect("Multisig account must exist");
+
+        let multisig = Multisig::try_deserialize(&mut multisig_account.data.as_slice())
+            .expect("Must deserialize");
+
+        assert_eq!(multisig.signers, vec![ctx.authority.pubkey(), new_signer]);
+        assert_eq!(multisig.membership_version, 1);
+        assert_eq!(multisig_account.data.len(), calculate_multisig_space(&multisig.signers, ""));
+    }
+
+    #[tokio::test]
+    async fn remove_last_signer_fails() {
+        let mut ctx = setup_test().await;
+
+        create_multisig(&mut ctx, vec![ctx.authority.pubkey()], 1).await;
+        propose(&mut ctx, ProposalInstruction::RemoveSigner { signer: ctx.authority.pubkey() }).await;
+
+        let result = execute(&mut ctx, 0).await;
+
+        assert!(result.is_err(), "The last signer cannot be removed");
+    }
+
+    #[tokio::test]
+    async fn remove_signer_below_threshold_fails() {
+        let mut ctx = setup_test().await;
+        let other_signer = Keypair::new();
+
+        create_multisig(&mut ctx, vec![ctx.authority.pubkey(), other_signer.pubkey()], 2).await;
+        propose(&mut ctx, ProposalInstruction::RemoveSigner { signer: other_signer.pubkey() }).await;
+
+        let approve_ix = sollearning::instruction::approve_proposal(
+            other_signer.pubkey(),
+            proposal_pda(0),
+            multisig_pda()
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[approve_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &other_signer],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Approval should succeed");
+
+        let result = execute(&mut ctx, 0).await;
+
+        assert!(result.is_err(), "Removal must not leave fewer signers than the threshold");
+    }
+
+    #[tokio::test]
+    async fn membership_change_invalidates_open_proposals() {
+        let mut ctx = setup_test().await;
+
+        create_multisig(&mut ctx, vec![ctx.authority.pubkey()], 1).await;
+        propose(&mut ctx, ProposalInstruction::TogglePause { paused: true }).await;
+        propose(&mut ctx, ProposalInstruction::AddSigner { signer: Keypair::new().pubkey() }).await;
+
+        execute(&mut ctx, 1).await
+            .expect("AddSigner execution should succeed");
+
+        let result = execute(&mut ctx, 0).await;
+
+        assert!(result.is_err(), "Approvals from the old signer set must not count");
+    }
+}
@@ -1,5 +1,9 @@
 mod creation;
 mod proposal_flow;
+mod committees;
+mod membership;
 
 pub use creation::*;
-pub use proposal_flow::*;
\ No newline at end of file
+pub use proposal_flow::*;
+pub use committees::*;
+pub use membership::*;
@@ -64,7 +64,8 @@ mod multisig_proposal_tests {
         let create_proposal_ix = sollearning::instruction::create_proposal(
             signers[0].pubkey(),
             proposal_instruction.clone(),
-            "Change program authority".to_string()
+            "Change program authority".to_string(),
+            None
         );
 
         let tx1 = Transaction::new_signed_with_payer(
@@ -127,7 +128,8 @@ mod multisig_proposal_tests {
         let create_proposal_ix = sollearning::instruction::create_proposal(
             signers[0].pubkey(),
             proposal_instruction.clone(),
-            "Change program authority".to_string()
+            "Change program authority".to_string(),
+            None
         );
 
         let tx1 = Transaction::new_signed_with_payer(
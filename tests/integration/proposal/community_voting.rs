@@ -0,0 +1,253 @@
+#[cfg(test)]
+mod community_voting_tests {
+    use super::*;
+    use crate::common::setup_test;
+    use sollearning::{
+        state::{CommunityProposal, VoterStake, ProposalInstruction, ProposalStatus},
+        constants::{GOVERNANCE_SCOPE_CONFIG, BPS_DENOMINATOR},
+        error::SolLearningError
+    };
+
+    const STAKE: u64 = 1_000_000_000_000;
+    const QUORUM: u64 = 500_000_000_000;
+    const THRESHOLD_BPS: u16 = 5_000;
+    const VOTING_PERIOD: i64 = 86400;
+
+    struct Voting {
+        voter_stake_pda: Pubkey,
+        proposal_pda: Pubkey,
+    }
+
+    fn authority_token_account(ctx: &TestContext) -> Pubkey {
+        get_associated_token_address(&ctx.authority.pubkey(), &token_mint_pubkey())
+    }
+
+    // Enables community voting for config instructions, stakes part of the
+    // authority's initial supply and opens one TogglePause proposal
+    async fn setup_community_proposal(ctx: &mut TestContext) -> Voting {
+        let (voter_stake_pda, _) = Pubkey::find_program_address(
+            &[b"voter-stake", ctx.authority.pubkey().as_ref()],
+            sollearning::ID
+        );
+        let (proposal_pda, _) = Pubkey::find_program_address(
+            &[b"community-proposal", &0_u64.to_le_bytes()],
+            sollearning::ID
+        );
+
+        let setup_ixs = vec![
+            sollearning::instruction::set_voting_config(
+                ctx.authority.pubkey(),
+                QUORUM,
+                THRESHOLD_BPS,
+                VOTING_PERIOD,
+                GOVERNANCE_SCOPE_CONFIG
+            ),
+            sollearning::instruction::create_voter_stake(ctx.authority.pubkey()),
+            sollearning::instruction::deposit_vote_stake(
+                ctx.authority.pubkey(),
+                authority_token_account(ctx),
+                STAKE
+            ),
+        ];
+
+        let tx1 = Transaction::new_signed_with_payer(
+            &setup_ixs,
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx1).await
+            .expect("Voting setup should succeed");
+
+        let create_ix = sollearning::instruction::create_community_proposal(
+            ctx.authority.pubkey(),
+            ProposalInstruction::TogglePause { paused: true },
+            "Pause the program".to_string()
+        );
+
+        let tx2 = Transaction::new_signed_with_payer(
+            &[create_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx2).await
+            .expect("Community proposal creation should succeed");
+
+        Voting { voter_stake_pda, proposal_pda }
+    }
+
+    async fn cast_vote(ctx: &mut TestContext, proposal_pda: Pubkey, approve: bool) -> Result<(), BanksClientError> {
+        let ix = sollearning::instruction::cast_vote(
+            ctx.authority.pubkey(),
+            proposal_pda,
+            approve
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    async fn get_community_proposal(ctx: &mut TestContext, proposal_pda: Pubkey) -> CommunityProposal {
+        let proposal_account = ctx.banks_client
+            .get_account(proposal_pda)
+            .await
+            .expect("Community proposal account must exist");
+
+        CommunityProposal::try_deserialize(&mut proposal_account.data.as_slice())
+            .expect("Must deserialize")
+    }
+
+    #[tokio::test]
+    async fn community_voting_disabled_by_default() {
+        let mut ctx = setup_test().await;
+
+        let setup_ixs = vec![
+            sollearning::instruction::create_voter_stake(ctx.authority.pubkey()),
+            sollearning::instruction::deposit_vote_stake(
+                ctx.authority.pubkey(),
+                authority_token_account(&ctx),
+                STAKE
+            ),
+            sollearning::instruction::create_community_proposal(
+                ctx.authority.pubkey(),
+                ProposalInstruction::TogglePause { paused: true },
+                "Pause the program".to_string()
+            ),
+        ];
+
+        let tx = Transaction::new_signed_with_payer(
+            &setup_ixs,
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Community proposals require a voting config");
+    }
+
+    #[tokio::test]
+    async fn vote_counts_staked_weight_and_locks_stake() {
+        let mut ctx = setup_test().await;
+        let voting = setup_community_proposal(&mut ctx).await;
+
+        cast_vote(&mut ctx, voting.proposal_pda, true).await
+            .expect("Vote should succeed");
+
+        let proposal = get_community_proposal(&mut ctx, voting.proposal_pda).await;
+
+        assert_eq!(proposal.yes_weight, STAKE);
+        assert_eq!(proposal.no_weight, 0);
+        assert_eq!(proposal.status, ProposalStatus::Active);
+        assert_eq!(proposal.voting_ends_at - proposal.created_at, VOTING_PERIOD);
+
+        let stake_account = ctx.banks_client
+            .get_account(voting.voter_stake_pda)
+            .await
+            .expect("Voter stake account must exist");
+
+        let stake = VoterStake::try_deserialize(&mut stake_account.data.as_slice())
+            .expect("Must deserialize");
+
+        assert_eq!(stake.locked_until, proposal.voting_ends_at);
+    }
+
+    #[tokio::test]
+    async fn second_vote_from_same_voter_fails() {
+        let mut ctx = setup_test().await;
+        let voting = setup_community_proposal(&mut ctx).await;
+
+        cast_vote(&mut ctx, voting.proposal_pda, true).await
+            .expect("First vote should succeed");
+
+        let result = cast_vote(&mut ctx, voting.proposal_pda, false).await;
+
+        assert!(result.is_err(), "The vote record must prevent double voting");
+    }
+
+    #[tokio::test]
+    async fn withdraw_locked_until_voting_ends() {
+        let mut ctx = setup_test().await;
+        let voting = setup_community_proposal(&mut ctx).await;
+
+        cast_vote(&mut ctx, voting.proposal_pda, true).await
+            .expect("Vote should succeed");
+
+        let withdraw_ix = sollearning::instruction::withdraw_vote_stake(
+            ctx.authority.pubkey(),
+            authority_token_account(&ctx),
+            STAKE
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[withdraw_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Stake must stay locked while its vote is open");
+    }
+
+    #[tokio::test]
+    async fn finalize_during_voting_fails() {
+        let mut ctx = setup_test().await;
+        let voting = setup_community_proposal(&mut ctx).await;
+
+        cast_vote(&mut ctx, voting.proposal_pda, true).await
+            .expect("Vote should succeed");
+
+        let ix = sollearning::instruction::finalize_community_proposal(
+            ctx.payer.pubkey(),
+            voting.proposal_pda,
+            vec![]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Finalization must wait for the voting period to end");
+    }
+
+    #[tokio::test]
+    async fn threshold_above_denominator_rejected() {
+        let mut ctx = setup_test().await;
+
+        let ix = sollearning::instruction::set_voting_config(
+            ctx.authority.pubkey(),
+            QUORUM,
+            (BPS_DENOMINATOR + 1) as u16,
+            VOTING_PERIOD,
+            GOVERNANCE_SCOPE_CONFIG
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Thresholds above 100% must be rejected");
+    }
+}
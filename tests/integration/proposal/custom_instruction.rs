@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod proposal_custom_instruction_tests {
+    use super::*;
+    use crate::common::{setup_test, register_student};
+    use sollearning::{
+        state::{ProposalInstruction, ProposalAccountMeta},
+        error::SolLearningError
+    };
+
+    async fn setup_single_signer_multisig(ctx: &mut TestContext) -> (Keypair, Pubkey) {
+        let signer = Keypair::new();
+        let (multisig_pda, _) = Pubkey::find_program_address(&[b"multisig"], sollearning::ID);
+
+        let ix = sollearning::instruction::create_multisig(
+            ctx.authority.pubkey(),
+            vec![signer.pubkey()],
+            1
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Multisig creation should succeed");
+
+        (signer, multisig_pda)
+    }
+
+    fn to_proposal_metas(metas: &[AccountMeta]) -> Vec<ProposalAccountMeta> {
+        metas.iter().map(|meta| ProposalAccountMeta {
+            pubkey: meta.pubkey,
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        }).collect()
+    }
+
+    #[tokio::test]
+    async fn custom_instruction_signed_by_program_state_rejected() {
+        let mut ctx = setup_test().await;
+        let (signer, _) = setup_single_signer_multisig(&mut ctx).await;
+
+        let recipient = Keypair::new();
+        let _ = register_student(&mut ctx, recipient.pubkey()).await;
+        let recipient_token_account = get_associated_token_address(
+            &recipient.pubkey(),
+            &token_mint_pubkey()
+        );
+
+        let (program_state_pda, _) = Pubkey::find_program_address(&[b"program-state"], sollearning::ID);
+
+        // The program state PDA is the mint authority and must never sign for governance
+        let mint_ix = spl_token::instruction::mint_to(
+            &spl_token::ID,
+            &token_mint_pubkey(),
+            &recipient_token_account,
+            &program_state_pda,
+            &[],
+            1_000
+        ).expect("Must build mint instruction");
+
+        let create_ix = sollearning::instruction::create_proposal(
+            signer.pubkey(),
+            ProposalInstruction::Custom {
+                program_id: spl_token::ID,
+                accounts: to_proposal_metas(&mint_ix.accounts),
+                data: mint_ix.data.clone(),
+            },
+            "Mint treasury grant".to_string(),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[create_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &signer],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Custom instructions cannot be signed by the program state PDA");
+    }
+
+    #[tokio::test]
+    async fn custom_instruction_with_foreign_signer_rejected() {
+        let mut ctx = setup_test().await;
+        let (signer, _) = setup_single_signer_multisig(&mut ctx).await;
+
+        let victim = Keypair::new();
+        let ix = sollearning::instruction::create_proposal(
+            signer.pubkey(),
+            ProposalInstruction::Custom {
+                program_id: system_program::ID,
+                accounts: vec![ProposalAccountMeta {
+                    pubkey: victim.pubkey(),
+                    is_signer: true,
+                    is_writable: true,
+                }],
+                data: vec![],
+            },
+            "Spend from an arbitrary wallet".to_string(),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &signer],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Only the governance PDA may be requested as a signer");
+    }
+
+    #[tokio::test]
+    async fn custom_instruction_into_own_program_rejected() {
+        let mut ctx = setup_test().await;
+        let (signer, _) = setup_single_signer_multisig(&mut ctx).await;
+
+        let ix = sollearning::instruction::create_proposal(
+            signer.pubkey(),
+            ProposalInstruction::Custom {
+                program_id: sollearning::ID,
+                accounts: vec![],
+                data: vec![0; 8],
+            },
+            "Call back into the program".to_string(),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &signer],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Custom instructions must target other programs");
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod proposal_expiration_tests {
+    use super::*;
+    use crate::common::setup_test;
+    use sollearning::{
+        state::{Proposal, ProposalInstruction, ProposalStatus},
+        error::SolLearningError
+    };
+
+    // Single-signer multisig with one open TogglePause proposal
+    async fn setup_open_proposal(ctx: &mut TestContext) -> (Pubkey, Pubkey) {
+        let (multisig_pda, _) = Pubkey::find_program_address(&[b"multisig"], sollearning::ID);
+        let (proposal_pda, _) = Pubkey::find_program_address(
+            &[b"proposal", multisig_pda.as_ref(), &0_u64.to_le_bytes()],
+            sollearning::ID
+        );
+
+        let setup_ixs = vec![
+            sollearning::instruction::create_multisig(
+                ctx.authority.pubkey(),
+                vec![ctx.authority.pubkey()],
+                1
+            ),
+            sollearning::instruction::create_proposal(
+                ctx.authority.pubkey(),
+                ProposalInstruction::TogglePause { paused: true },
+                "Pause the program".to_string(),
+                None
+            ),
+        ];
+
+        let tx = Transaction::new_signed_with_payer(
+            &setup_ixs,
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Proposal setup should succeed");
+
+        (multisig_pda, proposal_pda)
+    }
+
+    #[tokio::test]
+    async fn expire_before_deadline_fails() {
+        let mut ctx = setup_test().await;
+        let (multisig_pda, proposal_pda) = setup_open_proposal(&mut ctx).await;
+
+        let ix = sollearning::instruction::expire_proposal(
+            ctx.payer.pubkey(),
+            multisig_pda,
+            proposal_pda
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "A proposal cannot be expired before PROPOSAL_EXPIRATION_TIME");
+
+        let proposal_account = ctx.banks_client
+            .get_account(proposal_pda)
+            .await
+            .expect("Proposal account must exist");
+
+        let proposal = Proposal::try_deserialize(&mut proposal_account.data.as_slice())
+            .expect("Must deserialize");
+
+        assert_eq!(proposal.status, ProposalStatus::Active);
+    }
+
+    #[tokio::test]
+    async fn expire_executed_proposal_fails() {
+        let mut ctx = setup_test().await;
+        let (multisig_pda, proposal_pda) = setup_open_proposal(&mut ctx).await;
+        let (pause_record_pda, _) = Pubkey::find_program_address(&[b"pause-record"], sollearning::ID);
+
+        let execute_ix = sollearning::instruction::execute_proposal(
+            ctx.authority.pubkey(),
+            proposal_pda,
+            multisig_pda,
+            vec![pause_record_pda]
+        );
+
+        let tx1 = Transaction::new_signed_with_payer(
+            &[execute_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx1).await
+            .expect("Execution should succeed");
+
+        let expire_ix = sollearning::instruction::expire_proposal(
+            ctx.payer.pubkey(),
+            multisig_pda,
+            proposal_pda
+        );
+
+        let tx2 = Transaction::new_signed_with_payer(
+            &[expire_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx2).await;
+
+        assert!(result.is_err(), "Only active proposals can expire");
+    }
+}
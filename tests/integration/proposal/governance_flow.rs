@@ -1,9 +1,10 @@
 #[cfg(test)]
 mod proposal_governance_tests {
     use super::*;
-    use crate::common::setup_test;
+    use crate::common::{setup_test, register_educator, create_root_multisig, execute_governance_instruction};
     use sollearning::{
-        state::{ProposalInstruction, ProposalStatus},
+        state::{ProposalInstruction, ProposalStatus, PendingAuthorityTransfer, PauseRecord, EducatorAccount},
+        constants::PAUSE_REASON_GOVERNANCE,
         error::SolLearningError
     };
 
@@ -44,7 +45,8 @@ mod proposal_governance_tests {
     async fn execute_change_authority_proposal() {
         let (mut ctx, signers, multisig_pda) = setup_multisig().await;
 
-        let new_authority = Keypair::new().pubkey();
+        let new_authority_keypair = Keypair::new();
+        let new_authority = new_authority_keypair.pubkey();
         let proposal_instruction = ProposalInstruction::ChangeAuthority { 
             new_authority 
         };
@@ -62,7 +64,8 @@ mod proposal_governance_tests {
         let create_proposal_ix = sollearning::instruction::create_proposal(
             signers[0].pubkey(),
             proposal_instruction.clone(),
-            "Change program authority".to_string()
+            "Change program authority".to_string(),
+            None
         );
 
         let tx1 = Transaction::new_signed_with_payer(
@@ -98,11 +101,17 @@ mod proposal_governance_tests {
         ctx.banks_client.process_transaction(tx2).await
             .expect("Proposal approvals should succeed");
 
-        // Execute proposal
+        // Execute proposal; this only opens the pending transfer
+        let (pending_transfer_pda, _) = Pubkey::find_program_address(
+            &[b"authority-transfer"],
+            sollearning::ID
+        );
+
         let execute_proposal_ix = sollearning::instruction::execute_proposal(
             signers[0].pubkey(),
             proposal_pda,
-            multisig_pda
+            multisig_pda,
+            vec![pending_transfer_pda]
         );
 
         let tx3 = Transaction::new_signed_with_payer(
@@ -115,6 +124,23 @@ mod proposal_governance_tests {
         ctx.banks_client.process_transaction(tx3).await
             .expect("Proposal execution should succeed");
 
+        // The new authority must accept before anything changes
+        let accept_ix = sollearning::instruction::accept_authority_transfer(
+            new_authority,
+            pending_transfer_pda,
+            signers[0].pubkey()
+        );
+
+        let tx4 = Transaction::new_signed_with_payer(
+            &[accept_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &new_authority_keypair],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx4).await
+            .expect("Authority transfer acceptance should succeed");
+
         // Verify program state
         let program_state_pda = Pubkey::find_program_address(
             &[b"program-state"],
@@ -167,7 +193,8 @@ mod proposal_governance_tests {
         let create_proposal_ix = sollearning::instruction::create_proposal(
             signers[0].pubkey(),
             proposal_instruction.clone(),
-            "Change program authority".to_string()
+            "Change program authority".to_string(),
+            None
         );
 
         let tx1 = Transaction::new_signed_with_payer(
@@ -215,4 +242,177 @@ mod proposal_governance_tests {
         
         assert!(result.is_err(), "Proposal execution with insufficient approvals should fail");
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn governance_transfer_replaces_pending_and_resists_admin_cancel() {
+        let (mut ctx, signers, multisig_pda) = setup_multisig().await;
+
+        let (pending_transfer_pda, _) = Pubkey::find_program_address(
+            &[b"authority-transfer"],
+            sollearning::ID
+        );
+
+        // The admin holds the slot with a transfer of its own
+        let admin_pick = Keypair::new().pubkey();
+        let propose_ix = sollearning::instruction::propose_authority_transfer(
+            ctx.authority.pubkey(),
+            pending_transfer_pda,
+            admin_pick
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[propose_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Admin transfer proposal should succeed");
+
+        let new_authority = Keypair::new().pubkey();
+        let (proposal_pda, _) = Pubkey::find_program_address(
+            &[b"proposal", multisig_pda.as_ref(), &0_u64.to_le_bytes()],
+            sollearning::ID
+        );
+
+        let create_proposal_ix = sollearning::instruction::create_proposal(
+            signers[0].pubkey(),
+            ProposalInstruction::ChangeAuthority { new_authority },
+            "Replace compromised authority".to_string(),
+            None
+        );
+        let approve_ix1 = sollearning::instruction::approve_proposal(signers[1].pubkey(), proposal_pda, multisig_pda);
+        let approve_ix2 = sollearning::instruction::approve_proposal(signers[2].pubkey(), proposal_pda, multisig_pda);
+        let execute_ix = sollearning::instruction::execute_proposal(
+            signers[0].pubkey(),
+            proposal_pda,
+            multisig_pda,
+            vec![pending_transfer_pda]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[create_proposal_ix, approve_ix1, approve_ix2, execute_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &signers[0], &signers[1], &signers[2]],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Governance transfer should replace the pending one");
+
+        let pending_account = ctx.banks_client
+            .get_account(pending_transfer_pda)
+            .await
+            .expect("Pending transfer must exist");
+
+        let pending_data = PendingAuthorityTransfer::try_deserialize(
+            &mut pending_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(pending_data.new_authority, new_authority);
+        assert!(pending_data.by_governance);
+
+        let cancel_ix = sollearning::instruction::cancel_authority_transfer(
+            ctx.authority.pubkey(),
+            pending_transfer_pda,
+            ctx.authority.pubkey()
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[cancel_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "The admin cannot cancel a governance-initiated transfer");
+    }
+
+    #[tokio::test]
+    async fn toggle_pause_proposal_pauses_and_resumes() {
+        let mut ctx = setup_test().await;
+        let signer = create_root_multisig(&mut ctx).await;
+
+        let (program_state_pda, _) = Pubkey::find_program_address(&[b"program-state"], sollearning::ID);
+        let (pause_record_pda, _) = Pubkey::find_program_address(&[b"pause-record"], sollearning::ID);
+
+        execute_governance_instruction(
+            &mut ctx,
+            &signer,
+            0,
+            ProposalInstruction::TogglePause { paused: true },
+            vec![pause_record_pda]
+        ).await.expect("Pause proposal should execute");
+
+        let program_state_account = ctx.banks_client
+            .get_account(program_state_pda)
+            .await
+            .expect("Program state account must exist");
+        let program_state = ProgramState::try_deserialize(&mut program_state_account.data.as_slice())
+            .expect("Must deserialize");
+
+        assert!(program_state.paused);
+
+        let pause_record_account = ctx.banks_client
+            .get_account(pause_record_pda)
+            .await
+            .expect("Pause record must exist");
+        let pause_record = PauseRecord::try_deserialize(&mut pause_record_account.data.as_slice())
+            .expect("Must deserialize");
+
+        assert_eq!(pause_record.reason_code, PAUSE_REASON_GOVERNANCE);
+        assert_eq!(pause_record.resume_after, None);
+
+        // TogglePause is a recovery instruction, so it runs while paused
+        execute_governance_instruction(
+            &mut ctx,
+            &signer,
+            1,
+            ProposalInstruction::TogglePause { paused: false },
+            vec![pause_record_pda]
+        ).await.expect("Resume proposal should execute");
+
+        let program_state_account = ctx.banks_client
+            .get_account(program_state_pda)
+            .await
+            .expect("Program state account must exist");
+        let program_state = ProgramState::try_deserialize(&mut program_state_account.data.as_slice())
+            .expect("Must deserialize");
+
+        assert!(!program_state.paused);
+    }
+
+    #[tokio::test]
+    async fn update_educator_status_proposal_applies() {
+        let mut ctx = setup_test().await;
+        let signer = create_root_multisig(&mut ctx).await;
+
+        let educator = Keypair::new();
+        let (educator_pda, _) = register_educator(&mut ctx, educator.pubkey()).await;
+
+        execute_governance_instruction(
+            &mut ctx,
+            &signer,
+            0,
+            ProposalInstruction::UpdateEducatorStatus {
+                educator: educator.pubkey(),
+                is_active: false,
+                mint_limit: Some(500)
+            },
+            vec![educator_pda]
+        ).await.expect("Educator status proposal should execute");
+
+        let educator_account = ctx.banks_client
+            .get_account(educator_pda)
+            .await
+            .expect("Educator account must exist");
+        let educator_data = EducatorAccount::try_deserialize(&mut educator_account.data.as_slice())
+            .expect("Must deserialize");
+
+        assert!(!educator_data.is_active);
+        assert_eq!(educator_data.mint_limit, 500);
+    }
+}
@@ -1,3 +1,13 @@
 mod governance_flow;
+mod timelock;
+mod custom_instruction;
+mod community_voting;
+mod expiration;
+mod proposal_rules;
 
-pub use governance_flow::*;
\ No newline at end of file
+pub use governance_flow::*;
+pub use timelock::*;
+pub use custom_instruction::*;
+pub use community_voting::*;
+pub use expiration::*;
+pub use proposal_rules::*;
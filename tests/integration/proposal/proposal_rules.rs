@@ -0,0 +1,171 @@
+#[cfg(test)]
+mod proposal_rules_tests {
+    use super::*;
+    use crate::common::setup_test;
+    use sollearning::{
+        state::{Proposal, ProposalInstruction},
+        constants::PROPOSAL_EXPIRATION_TIME,
+        error::SolLearningError
+    };
+
+    const CHANGE_AUTHORITY_KIND: u8 = 0;
+    const ONE_DAY: i64 = 86400;
+
+    fn multisig_pda() -> Pubkey {
+        Pubkey::find_program_address(&[b"multisig"], sollearning::ID).0
+    }
+
+    fn proposal_pda(index: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"proposal", multisig_pda().as_ref(), &index.to_le_bytes()],
+            sollearning::ID
+        ).0
+    }
+
+    // Three signer multisig with a threshold of two, led by the authority.
+    // Returns the second signer, who approves governance settings
+    async fn setup_multisig(ctx: &mut TestContext) -> Keypair {
+        let co_signer = Keypair::new();
+
+        let ix = sollearning::instruction::create_multisig(
+            ctx.authority.pubkey(),
+            vec![ctx.authority.pubkey(), co_signer.pubkey(), Keypair::new().pubkey()],
+            2
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Governance setup should succeed");
+
+        co_signer
+    }
+
+    // Proposal rules are governance settings: the multisig itself has to pass them
+    async fn pass_setting(ctx: &mut TestContext, co_signer: &Keypair, index: u64, instruction: ProposalInstruction) {
+        let setup_ixs = vec![
+            sollearning::instruction::create_proposal(
+                ctx.authority.pubkey(),
+                instruction,
+                "Update proposal rules".to_string(),
+                None
+            ),
+            sollearning::instruction::approve_proposal(
+                co_signer.pubkey(),
+                proposal_pda(index),
+                multisig_pda()
+            ),
+            sollearning::instruction::execute_proposal(
+                ctx.authority.pubkey(),
+                proposal_pda(index),
+                multisig_pda(),
+                vec![]
+            ),
+        ];
+
+        let tx = Transaction::new_signed_with_payer(
+            &setup_ixs,
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority, co_signer],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Settings proposal should pass");
+    }
+
+    async fn create_proposal(ctx: &mut TestContext, expiry: Option<i64>) -> Result<(), BanksClientError> {
+        let ix = sollearning::instruction::create_proposal(
+            ctx.authority.pubkey(),
+            ProposalInstruction::ChangeAuthority { new_authority: Keypair::new().pubkey() },
+            "Change program authority".to_string(),
+            expiry
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+    }
+
+    async fn get_proposal(ctx: &mut TestContext, index: u64) -> Proposal {
+        let proposal_account = ctx.banks_client
+            .get_account(proposal_pda(index))
+            .await
+            .expect("Proposal account must exist");
+
+        Proposal::try_deserialize(&mut proposal_account.data.as_slice())
+            .expect("Must deserialize")
+    }
+
+    #[tokio::test]
+    async fn defaults_are_stored_on_proposal() {
+        let mut ctx = setup_test().await;
+        setup_multisig(&mut ctx).await;
+
+        create_proposal(&mut ctx, None).await
+            .expect("Proposal creation should succeed");
+
+        let proposal = get_proposal(&mut ctx, 0).await;
+
+        assert_eq!(proposal.expires_at - proposal.created_at, PROPOSAL_EXPIRATION_TIME);
+        assert_eq!(proposal.threshold, 2);
+    }
+
+    #[tokio::test]
+    async fn custom_expiry_requires_governed_bounds() {
+        let mut ctx = setup_test().await;
+        setup_multisig(&mut ctx).await;
+
+        let result = create_proposal(&mut ctx, Some(ONE_DAY)).await;
+
+        assert!(result.is_err(), "Only the default expiry is allowed until bounds are set");
+    }
+
+    #[tokio::test]
+    async fn custom_expiry_within_bounds_is_stored() {
+        let mut ctx = setup_test().await;
+        let co_signer = setup_multisig(&mut ctx).await;
+        pass_setting(&mut ctx, &co_signer, 0, ProposalInstruction::SetProposalExpiryBounds {
+            min_proposal_expiry: ONE_DAY,
+            max_proposal_expiry: 14 * ONE_DAY
+        }).await;
+
+        create_proposal(&mut ctx, Some(2 * ONE_DAY)).await
+            .expect("Proposal creation should succeed");
+
+        let proposal = get_proposal(&mut ctx, 1).await;
+
+        assert_eq!(proposal.expires_at - proposal.created_at, 2 * ONE_DAY);
+
+        let result = create_proposal(&mut ctx, Some(15 * ONE_DAY)).await;
+
+        assert!(result.is_err(), "Expiry above the governed maximum must be rejected");
+    }
+
+    #[tokio::test]
+    async fn variant_threshold_requires_all_signers() {
+        let mut ctx = setup_test().await;
+        let co_signer = setup_multisig(&mut ctx).await;
+        pass_setting(&mut ctx, &co_signer, 0, ProposalInstruction::SetProposalThreshold {
+            instruction_kind: CHANGE_AUTHORITY_KIND,
+            threshold_bps: 10_000
+        }).await;
+
+        create_proposal(&mut ctx, None).await
+            .expect("Proposal creation should succeed");
+
+        let proposal = get_proposal(&mut ctx, 1).await;
+
+        assert_eq!(proposal.threshold, 3);
+    }
+}
@@ -0,0 +1,223 @@
+#[cfg(test)]
+mod proposal_timelock_tests {
+    use super::*;
+    use crate::common::{setup_test, create_root_multisig, execute_governance_instruction};
+    use sollearning::{
+        state::{Proposal, ProposalInstruction, ProposalStatus},
+        constants::MAX_PROPOSAL_DELAY,
+        error::SolLearningError
+    };
+
+    const PAUSE_KIND: u8 = 1;
+    const DELAY: i64 = 86400;
+
+    struct Governance {
+        signer: Keypair,
+        guardian: Keypair,
+        multisig_pda: Pubkey,
+        proposal_pda: Pubkey,
+    }
+
+    // Single-signer multisig with a guardian and a one day delay on TogglePause,
+    // set by governance proposals 0 and 1, and one approved TogglePause proposal
+    // waiting in its timelock
+    async fn setup_timelocked_proposal(ctx: &mut TestContext, delay: i64) -> Governance {
+        let signer = Keypair::new();
+        let guardian = Keypair::new();
+
+        let (multisig_pda, _) = Pubkey::find_program_address(&[b"multisig"], sollearning::ID);
+        let (proposal_pda, _) = Pubkey::find_program_address(
+            &[b"proposal", multisig_pda.as_ref(), &2_u64.to_le_bytes()],
+            sollearning::ID
+        );
+
+        let setup_ix = sollearning::instruction::create_multisig(
+            ctx.authority.pubkey(),
+            vec![signer.pubkey()],
+            1
+        );
+
+        let tx1 = Transaction::new_signed_with_payer(
+            &[setup_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx1).await
+            .expect("Governance setup should succeed");
+
+        execute_governance_instruction(
+            ctx,
+            &signer,
+            0,
+            ProposalInstruction::SetGuardian { guardian: guardian.pubkey() },
+            vec![]
+        ).await.expect("Guardian should be set by governance");
+
+        execute_governance_instruction(
+            ctx,
+            &signer,
+            1,
+            ProposalInstruction::SetProposalDelay { instruction_kind: PAUSE_KIND, delay },
+            vec![]
+        ).await.expect("Proposal delay should be set by governance");
+
+        let create_ix = sollearning::instruction::create_proposal(
+            signer.pubkey(),
+            ProposalInstruction::TogglePause { paused: true },
+            "Pause the program".to_string(),
+            None
+        );
+
+        let tx2 = Transaction::new_signed_with_payer(
+            &[create_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &signer],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx2).await
+            .expect("Proposal creation should succeed");
+
+        Governance { signer, guardian, multisig_pda, proposal_pda }
+    }
+
+    async fn get_proposal(ctx: &mut TestContext, proposal_pda: Pubkey) -> Proposal {
+        let proposal_account = ctx.banks_client
+            .get_account(proposal_pda)
+            .await
+            .expect("Proposal account must exist");
+
+        Proposal::try_deserialize(&mut proposal_account.data.as_slice())
+            .expect("Must deserialize")
+    }
+
+    #[tokio::test]
+    async fn proposal_records_executable_at() {
+        let mut ctx = setup_test().await;
+        let governance = setup_timelocked_proposal(&mut ctx, DELAY).await;
+
+        let proposal = get_proposal(&mut ctx, governance.proposal_pda).await;
+
+        assert_eq!(proposal.executable_at - proposal.created_at, DELAY);
+    }
+
+    #[tokio::test]
+    async fn execution_before_timelock_fails() {
+        let mut ctx = setup_test().await;
+        let governance = setup_timelocked_proposal(&mut ctx, DELAY).await;
+
+        let ix = sollearning::instruction::execute_proposal(
+            governance.signer.pubkey(),
+            governance.proposal_pda,
+            governance.multisig_pda,
+            vec![]
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &governance.signer],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Execution must wait for the timelock");
+    }
+
+    #[tokio::test]
+    async fn guardian_vetoes_during_timelock() {
+        let mut ctx = setup_test().await;
+        let governance = setup_timelocked_proposal(&mut ctx, DELAY).await;
+
+        let ix = sollearning::instruction::veto_proposal(
+            governance.guardian.pubkey(),
+            None,
+            governance.multisig_pda,
+            governance.proposal_pda
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &governance.guardian],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Guardian veto should succeed");
+
+        let proposal = get_proposal(&mut ctx, governance.proposal_pda).await;
+
+        assert_eq!(proposal.status, ProposalStatus::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn multisig_signer_cannot_veto() {
+        let mut ctx = setup_test().await;
+        let governance = setup_timelocked_proposal(&mut ctx, DELAY).await;
+
+        let ix = sollearning::instruction::veto_proposal(
+            governance.signer.pubkey(),
+            None,
+            governance.multisig_pda,
+            governance.proposal_pda
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &governance.signer],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Only the guardian or emergency multisig can veto");
+    }
+
+    #[tokio::test]
+    async fn veto_without_timelock_fails() {
+        let mut ctx = setup_test().await;
+        let governance = setup_timelocked_proposal(&mut ctx, 0).await;
+
+        let ix = sollearning::instruction::veto_proposal(
+            governance.guardian.pubkey(),
+            None,
+            governance.multisig_pda,
+            governance.proposal_pda
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &governance.guardian],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Veto window is closed once the proposal is executable");
+    }
+
+    #[tokio::test]
+    async fn delay_above_maximum_rejected() {
+        let mut ctx = setup_test().await;
+        let signer = create_root_multisig(&mut ctx).await;
+
+        let result = execute_governance_instruction(
+            &mut ctx,
+            &signer,
+            0,
+            ProposalInstruction::SetProposalDelay {
+                instruction_kind: PAUSE_KIND,
+                delay: MAX_PROPOSAL_DELAY + 1
+            },
+            vec![]
+        ).await;
+
+        assert!(result.is_err(), "Delays beyond the maximum must be rejected");
+    }
+}
@@ -131,4 +131,46 @@ mod student_rewards_tests {
         
         assert!(result.is_err(), "Minting by unauthorized educator must fail");
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn minting_requires_clawback_delegate() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let (course_pda, _) = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        // The student drops the delegate approved at enrollment
+        let student_token_account = get_associated_token_address(&student.pubkey(), &token_mint_pubkey());
+        let revoke_ix = spl_token::instruction::revoke(
+            &spl_token::ID,
+            &student_token_account,
+            &student.pubkey(),
+            &[]
+        ).unwrap();
+
+        let mint_ix = sollearning::instruction::mint_to_student(
+            educator.pubkey(),
+            student.pubkey(),
+            course_pda,
+            course_id.to_string(),
+            100
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[revoke_ix, mint_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &educator, &student],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Rewards must not be minted without the clawback delegate");
+    }
+}
@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod token_attestation_tests {
+    use super::*;
+    use crate::common::{
+        setup_test,
+        register_educator,
+        create_course,
+        register_student
+    };
+    use sollearning::{
+        state::CompletionAttestation,
+        instructions::token::redeem_attestation::build_attestation_message,
+        error::SolLearningError
+    };
+    use solana_sdk::ed25519_instruction::new_ed25519_instruction;
+
+    fn signed_attestation_ixs(
+        educator: &Keypair,
+        student: &Keypair,
+        course_id: &str,
+        amount: u64,
+        expiry: i64,
+        nonce: u64
+    ) -> Vec<Instruction> {
+        let attestation = CompletionAttestation {
+            course_id: course_id.to_string(),
+            student: student.pubkey(),
+            amount,
+            expiry,
+            nonce,
+        };
+
+        let message = build_attestation_message(&sollearning::ID, &attestation)
+            .expect("Message must serialize");
+
+        let verify_ix = new_ed25519_instruction(educator, &message);
+
+        let redeem_ix = sollearning::instruction::redeem_completion_attestation(
+            student.pubkey(),
+            educator.pubkey(),
+            attestation
+        );
+
+        vec![verify_ix, redeem_ix]
+    }
+
+    #[tokio::test]
+    async fn successful_attestation_redemption() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let student = Keypair::new();
+        let (student_pda, _) = register_student(&mut ctx, student.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let _ = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        let ixs = signed_attestation_ixs(&educator, &student, course_id, 100, i64::MAX, 1);
+
+        // Only the student signs and pays; the educator signed off-chain
+        let tx = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&student.pubkey()),
+            &[&student],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Attestation redemption should succeed");
+
+        let student_account = ctx.banks_client
+            .get_account(student_pda)
+            .await
+            .expect("Student account must exist");
+
+        let student_data = StudentInfo::try_deserialize(
+            &mut student_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(student_data.total_earned, 100);
+    }
+
+    #[tokio::test]
+    async fn attestation_signed_by_other_key() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let _ = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        let impostor = Keypair::new();
+        let mut ixs = signed_attestation_ixs(&educator, &student, course_id, 100, i64::MAX, 1);
+        ixs[0] = signed_attestation_ixs(&impostor, &student, course_id, 100, i64::MAX, 1).remove(0);
+
+        let tx = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&student.pubkey()),
+            &[&student],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Attestation not signed by the educator should fail");
+    }
+
+    #[tokio::test]
+    async fn expired_attestation_redemption() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let _ = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        let ixs = signed_attestation_ixs(&educator, &student, course_id, 100, 0, 1);
+
+        let tx = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&student.pubkey()),
+            &[&student],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Expired attestation should fail");
+    }
+
+    #[tokio::test]
+    async fn attestation_redeemed_twice() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let _ = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        let ixs = signed_attestation_ixs(&educator, &student, course_id, 100, i64::MAX, 1);
+
+        let tx1 = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&student.pubkey()),
+            &[&student],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx1).await
+            .expect("First redemption should succeed");
+
+        let replay_ixs = signed_attestation_ixs(&educator, &student, course_id, 100, i64::MAX, 2);
+
+        let tx2 = Transaction::new_signed_with_payer(
+            &replay_ixs,
+            Some(&student.pubkey()),
+            &[&student],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx2).await;
+
+        assert!(result.is_err(), "Second redemption for the same course should fail");
+    }
+
+    #[tokio::test]
+    async fn attestation_within_mint_cooldown() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let first_student = Keypair::new();
+        let _ = register_student(&mut ctx, first_student.pubkey()).await;
+        let second_student = Keypair::new();
+        let _ = register_student(&mut ctx, second_student.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let _ = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        let ixs = signed_attestation_ixs(&educator, &first_student, course_id, 100, i64::MAX, 1);
+        let tx1 = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&first_student.pubkey()),
+            &[&first_student],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx1).await
+            .expect("First redemption should succeed");
+
+        // Attestations count as educator mints and share MINT_COOLDOWN_PERIOD
+        let ixs = signed_attestation_ixs(&educator, &second_student, course_id, 100, i64::MAX, 2);
+        let tx2 = Transaction::new_signed_with_payer(
+            &ixs,
+            Some(&second_student.pubkey()),
+            &[&second_student],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx2).await;
+
+        assert!(result.is_err(), "Redemption within the educator's mint cooldown should fail");
+    }
+}
@@ -0,0 +1,368 @@
+#[cfg(test)]
+mod token_batch_minting_tests {
+    use super::*;
+    use crate::common::{
+        setup_test,
+        register_educator,
+        create_course,
+        register_student,
+        mint_tokens_to_student
+    };
+    use sollearning::{
+        constants::PAUSE_FLAG_MINT,
+        error::SolLearningError
+    };
+
+    fn student_entry(student_pubkey: Pubkey, course_id: &str) -> Vec<AccountMeta> {
+        let (student_pda, _) = Pubkey::find_program_address(
+            &[b"student", student_pubkey.as_ref()],
+            sollearning::ID
+        );
+
+        let (course_completion_pda, _) = Pubkey::find_program_address(
+            &[
+                b"course-completion",
+                student_pubkey.as_ref(),
+                course_id.as_bytes()
+            ],
+            sollearning::ID
+        );
+
+        let student_token_account = get_associated_token_address(
+            &student_pubkey,
+            &token_mint_pubkey()
+        );
+
+        let (blocklist_pda, _) = Pubkey::find_program_address(
+            &[b"blocklist", student_pubkey.as_ref()],
+            sollearning::ID
+        );
+
+        vec![
+            AccountMeta::new(student_pda, false),
+            AccountMeta::new(student_token_account, false),
+            AccountMeta::new(course_completion_pda, false),
+            AccountMeta::new_readonly(blocklist_pda, false),
+        ]
+    }
+
+    #[tokio::test]
+    async fn successful_batch_minting() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let (course_pda, _) = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        let students: Vec<Keypair> = (0..3).map(|_| Keypair::new()).collect();
+        let mut remaining_accounts = Vec::new();
+        for student in &students {
+            let _ = register_student(&mut ctx, student.pubkey()).await;
+            remaining_accounts.extend(student_entry(student.pubkey(), course_id));
+        }
+
+        let reward_amount = 100;
+        let ix = sollearning::instruction::batch_mint_completions(
+            educator.pubkey(),
+            course_pda,
+            course_id.to_string(),
+            reward_amount,
+            remaining_accounts
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &educator],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Batch minting should succeed");
+
+        for student in &students {
+            let (student_pda, _) = Pubkey::find_program_address(
+                &[b"student", student.pubkey().as_ref()],
+                sollearning::ID
+            );
+
+            let student_account = ctx.banks_client
+                .get_account(student_pda)
+                .await
+                .expect("Student account must exist");
+
+            let student_data = StudentInfo::try_deserialize(
+                &mut student_account.data.as_slice()
+            ).expect("Must deserialize");
+
+            assert_eq!(student_data.total_earned, reward_amount);
+            assert_eq!(student_data.courses_completed, 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_minting_skips_existing_completions() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let (course_pda, _) = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        let completed_student = Keypair::new();
+        let (completed_student_pda, _) = register_student(&mut ctx, completed_student.pubkey()).await;
+        let _ = mint_tokens_to_student(
+            &mut ctx,
+            educator.pubkey(),
+            completed_student.pubkey(),
+            course_pda,
+            course_id,
+            100
+        ).await;
+
+        let new_student = Keypair::new();
+        let _ = register_student(&mut ctx, new_student.pubkey()).await;
+
+        let mut remaining_accounts = student_entry(completed_student.pubkey(), course_id);
+        remaining_accounts.extend(student_entry(new_student.pubkey(), course_id));
+
+        let ix = sollearning::instruction::batch_mint_completions(
+            educator.pubkey(),
+            course_pda,
+            course_id.to_string(),
+            100,
+            remaining_accounts
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &educator],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Batch minting with an already completed student should succeed");
+
+        let student_account = ctx.banks_client
+            .get_account(completed_student_pda)
+            .await
+            .expect("Student account must exist");
+
+        let student_data = StudentInfo::try_deserialize(
+            &mut student_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(student_data.total_earned, 100, "Skipped student must not be minted to twice");
+    }
+
+    #[tokio::test]
+    async fn batch_minting_with_incomplete_entry() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let (course_pda, _) = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        let student = Keypair::new();
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+
+        let mut remaining_accounts = student_entry(student.pubkey(), course_id);
+        remaining_accounts.pop();
+
+        let ix = sollearning::instruction::batch_mint_completions(
+            educator.pubkey(),
+            course_pda,
+            course_id.to_string(),
+            100,
+            remaining_accounts
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &educator],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Batch with an incomplete student entry should fail");
+    }
+
+    #[tokio::test]
+    async fn batch_minting_exceeding_max_batch_size() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let (course_pda, _) = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        let mut remaining_accounts = Vec::new();
+        for _ in 0..(sollearning::constants::MAX_BATCH_MINT_SIZE + 1) {
+            let student = Keypair::new();
+            let _ = register_student(&mut ctx, student.pubkey()).await;
+            remaining_accounts.extend(student_entry(student.pubkey(), course_id));
+        }
+
+        let ix = sollearning::instruction::batch_mint_completions(
+            educator.pubkey(),
+            course_pda,
+            course_id.to_string(),
+            100,
+            remaining_accounts
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &educator],
+            ctx.recent_blockhash
+        );
+
+        let result = ctx.banks_client.process_transaction(tx).await;
+
+        assert!(result.is_err(), "Batch larger than the maximum size should fail");
+    }
+
+    #[tokio::test]
+    async fn batch_minting_stops_at_circuit_breaker() {
+        let mut ctx = setup_test().await;
+
+        let breaker_ix = sollearning::instruction::set_circuit_breakers(
+            ctx.authority.pubkey(),
+            0,
+            0,
+            5_000,
+            2
+        );
+
+        let tx1 = Transaction::new_signed_with_payer(
+            &[breaker_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx1).await
+            .expect("Circuit breaker setup should succeed");
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let (course_pda, _) = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        let students: Vec<Keypair> = (0..3).map(|_| Keypair::new()).collect();
+        let mut remaining_accounts = Vec::new();
+        for student in &students {
+            let _ = register_student(&mut ctx, student.pubkey()).await;
+            remaining_accounts.extend(student_entry(student.pubkey(), course_id));
+        }
+
+        // Every entry is near the default 1,000,000 limit, so the second one
+        // completes the streak and pauses minting
+        let ix = sollearning::instruction::batch_mint_completions(
+            educator.pubkey(),
+            course_pda,
+            course_id.to_string(),
+            600_000,
+            remaining_accounts
+        );
+
+        let tx2 = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &educator],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx2).await
+            .expect("A tripped breaker ends the batch without failing it");
+
+        let (program_state_pda, _) = Pubkey::find_program_address(&[b"program-state"], sollearning::ID);
+        let program_state_account = ctx.banks_client
+            .get_account(program_state_pda)
+            .await
+            .expect("Program state account must exist");
+        let program_state = ProgramState::try_deserialize(&mut program_state_account.data.as_slice())
+            .expect("Must deserialize");
+
+        assert_ne!(program_state.pause_flags & PAUSE_FLAG_MINT, 0, "Minting must be paused");
+
+        let mut earned = Vec::new();
+        for student in &students {
+            let (student_pda, _) = Pubkey::find_program_address(
+                &[b"student", student.pubkey().as_ref()],
+                sollearning::ID
+            );
+
+            let student_account = ctx.banks_client
+                .get_account(student_pda)
+                .await
+                .expect("Student account must exist");
+
+            earned.push(
+                StudentInfo::try_deserialize(&mut student_account.data.as_slice())
+                    .expect("Must deserialize")
+                    .total_earned
+            );
+        }
+
+        assert_eq!(earned, vec![600_000, 0, 0]);
+    }
+
+    #[tokio::test]
+    async fn batch_minting_handles_prefunded_completion() {
+        let mut ctx = setup_test().await;
+
+        let educator = Keypair::new();
+        let _ = register_educator(&mut ctx, educator.pubkey()).await;
+
+        let course_id = "BLOCKCHAIN101";
+        let (course_pda, _) = create_course(&mut ctx, educator.pubkey(), course_id).await;
+
+        let student = Keypair::new();
+        let (student_pda, _) = register_student(&mut ctx, student.pubkey()).await;
+        let entry = student_entry(student.pubkey(), course_id);
+
+        // Lamports sent to the completion PDA before it exists must not block the mint
+        let prefund_ix = system_instruction::transfer(&ctx.payer.pubkey(), &entry[2].pubkey, 1);
+
+        let ix = sollearning::instruction::batch_mint_completions(
+            educator.pubkey(),
+            course_pda,
+            course_id.to_string(),
+            100,
+            entry
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[prefund_ix, ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &educator],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Batch minting should succeed on a pre-funded completion address");
+
+        let student_account = ctx.banks_client
+            .get_account(student_pda)
+            .await
+            .expect("Student account must exist");
+
+        let student_data = StudentInfo::try_deserialize(
+            &mut student_account.data.as_slice()
+        ).expect("Must deserialize");
+
+        assert_eq!(student_data.courses_completed, 1);
+    }
+}
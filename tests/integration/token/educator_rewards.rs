@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod educator_reward_tests {
+    use super::*;
+    use crate::common::{
+        setup_test,
+        register_educator,
+        create_course,
+        register_student,
+        mint_tokens_to_student,
+        create_root_multisig,
+        execute_governance_instruction
+    };
+    use sollearning::{
+        state::{CourseCompletion, DisputeKind, EducatorAccount, ProgramState, ProposalInstruction},
+        constants::DISPUTE_SEED
+    };
+    use anchor_spl::token::TokenAccount;
+
+    const COURSE_ID: &str = "BLOCKCHAIN101";
+
+    async fn set_educator_reward_config(ctx: &mut TestContext, bps: u16, budget: u64) -> Result<(), BanksClientError> {
+        let signer = create_root_multisig(ctx).await;
+
+        execute_governance_instruction(
+            ctx,
+            &signer,
+            0,
+            ProposalInstruction::SetEducatorReward {
+                educator_reward_bps: bps,
+                educator_reward_budget: budget
+            },
+            vec![]
+        ).await
+    }
+
+    // Mints a 100 token completion and returns the educator's rewards_earned
+    async fn mint_and_read_rewards(ctx: &mut TestContext) -> u64 {
+        let educator = Keypair::new();
+        let student = Keypair::new();
+        let (educator_pda, _) = register_educator(ctx, educator.pubkey()).await;
+        let _ = register_student(ctx, student.pubkey()).await;
+        let (course_pda, _) = create_course(ctx, educator.pubkey(), COURSE_ID).await;
+
+        mint_tokens_to_student(ctx, educator.pubkey(), student.pubkey(), course_pda, COURSE_ID, 100).await;
+
+        let educator_account = ctx.banks_client
+            .get_account(educator_pda)
+            .await
+            .expect("Educator account must exist");
+
+        EducatorAccount::try_deserialize(&mut educator_account.data.as_slice())
+            .expect("Must deserialize")
+            .rewards_earned
+    }
+
+    #[tokio::test]
+    async fn educator_earns_reward_rate_on_mint() {
+        let mut ctx = setup_test().await;
+        set_educator_reward_config(&mut ctx, 1_000, 1_000).await
+            .expect("Educator reward setup should succeed");
+
+        assert_eq!(mint_and_read_rewards(&mut ctx).await, 10);
+    }
+
+    #[tokio::test]
+    async fn educator_reward_limited_by_budget() {
+        let mut ctx = setup_test().await;
+        set_educator_reward_config(&mut ctx, 1_000, 4).await
+            .expect("Educator reward setup should succeed");
+
+        assert_eq!(mint_and_read_rewards(&mut ctx).await, 4);
+    }
+
+    #[tokio::test]
+    async fn educator_reward_disabled_by_default() {
+        let mut ctx = setup_test().await;
+
+        assert_eq!(mint_and_read_rewards(&mut ctx).await, 0);
+    }
+
+    #[tokio::test]
+    async fn educator_reward_rate_is_capped() {
+        let mut ctx = setup_test().await;
+
+        let result = set_educator_reward_config(&mut ctx, 5_000, 1_000).await;
+
+        assert!(result.is_err(), "Reward rates above the maximum must be rejected");
+    }
+
+    #[tokio::test]
+    async fn revocation_reverses_educator_reward() {
+        let mut ctx = setup_test().await;
+        set_educator_reward_config(&mut ctx, 1_000, 1_000).await
+            .expect("Educator reward setup should succeed");
+
+        let educator = Keypair::new();
+        let student = Keypair::new();
+        let (educator_pda, _) = register_educator(&mut ctx, educator.pubkey()).await;
+        let _ = register_student(&mut ctx, student.pubkey()).await;
+        let (course_pda, _) = create_course(&mut ctx, educator.pubkey(), COURSE_ID).await;
+        let (program_state_pda, _) = Pubkey::find_program_address(&[b"program-state"], &sollearning::ID);
+
+        let course_completion = mint_tokens_to_student(
+            &mut ctx,
+            educator.pubkey(),
+            student.pubkey(),
+            course_pda,
+            COURSE_ID,
+            100
+        ).await;
+        let educator_token_account = get_associated_token_address(&educator.pubkey(), &token_mint_pubkey());
+
+        let completion_account = ctx.banks_client
+            .get_account(course_completion)
+            .await
+            .expect("Course completion must exist");
+        let completion = CourseCompletion::try_deserialize(&mut completion_account.data.as_slice())
+            .expect("Must deserialize");
+        assert_eq!(completion.educator_reward, 10);
+
+        let program_state_account = ctx.banks_client
+            .get_account(program_state_pda)
+            .await
+            .expect("Program state must exist");
+        let minted_before = ProgramState::try_deserialize(&mut program_state_account.data.as_slice())
+            .expect("Must deserialize")
+            .total_minted;
+
+        // Uphold a fraud dispute so the completion can be revoked right away
+        let reviewer = Keypair::new();
+        let whistleblower = Keypair::new();
+        let config_ix = sollearning::instruction::set_dispute_config(ctx.authority.pubkey(), reviewer.pubkey(), 1);
+        let open_ix = sollearning::instruction::open_dispute(
+            whistleblower.pubkey(),
+            course_pda,
+            student.pubkey(),
+            DisputeKind::FraudulentCompletion,
+            [5u8; 32]
+        );
+        let (dispute, _) = Pubkey::find_program_address(
+            &[DISPUTE_SEED, course_pda.as_ref(), student.pubkey().as_ref(), whistleblower.pubkey().as_ref()],
+            &sollearning::ID
+        );
+        let review_ix = sollearning::instruction::review_dispute(reviewer.pubkey(), dispute, true);
+        let revoke_ix = sollearning::instruction::revoke_completion(
+            whistleblower.pubkey(),
+            course_completion,
+            Some(dispute),
+            None
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[config_ix, open_ix, review_ix, revoke_ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &ctx.authority, &whistleblower, &reviewer],
+            ctx.recent_blockhash
+        );
+
+        ctx.banks_client.process_transaction(tx).await
+            .expect("Upheld fraud dispute should allow revocation");
+
+        let educator_account = ctx.banks_client
+            .get_account(educator_pda)
+            .await
+            .expect("Educator account must exist");
+        let educator_account = EducatorAccount::try_deserialize(&mut educator_account.data.as_slice())
+            .expect("Must deserialize");
+        assert_eq!(educator_account.rewards_earned, 0);
+
+        let program_state_account = ctx.banks_client
+            .get_account(program_state_pda)
+            .await
+            .expect("Program state must exist");
+        let program_state = ProgramState::try_deserialize(&mut program_state_account.data.as_slice())
+            .expect("Must deserialize");
+        assert_eq!(program_state.total_minted, minted_before - 10);
+
+        let educator_token = ctx.banks_client
+            .get_account(educator_token_account)
+            .await
+            .expect("Educator token account must exist");
+        let educator_token = TokenAccount::try_deserialize(&mut educator_token.data.as_slice())
+            .expect("Must deserialize");
+        assert_eq!(educator_token.amount, 0);
+    }
+}
@@ -1,5 +1,13 @@
 mod minting;
 mod transfer;
+mod batch_minting;
+mod attestation;
+mod circuit_breakers;
+mod educator_rewards;
 
 pub use minting::*;
-pub use transfer::*;
\ No newline at end of file
+pub use transfer::*;
+pub use batch_minting::*;
+pub use attestation::*;
+pub use circuit_breakers::*;
+pub use educator_rewards::*;